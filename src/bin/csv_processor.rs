@@ -1,7 +1,6 @@
 use clap::{Arg, Command};
-use doc_loader::{processors::csv::CsvProcessor, ProcessingParams, DocumentProcessor};
+use doc_loader::{processors::csv::CsvProcessor, CsvSchema, ProcessingParams, DocumentProcessor};
 use std::path::Path;
-use serde_json;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
@@ -14,7 +13,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .short('i')
                 .long("input")
                 .value_name("FILE")
-                .help("Input CSV file path")
+                .help("Input CSV or TSV file path")
                 .required(true)
         )
         .arg(
@@ -38,6 +37,26 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .help("Overlap between chunks in characters")
                 .default_value("100")
         )
+        .arg(
+            Arg::new("delimiter")
+                .long("delimiter")
+                .value_name("CHAR")
+                .help("Field delimiter (detected automatically by default, use \"tab\" for tabs)")
+        )
+        .arg(
+            Arg::new("on-error")
+                .long("on-error")
+                .value_name("POLICY")
+                .help("Malformed records: fail, skip or repair")
+                .value_parser(["fail", "skip", "repair"])
+                .default_value("fail")
+        )
+        .arg(
+            Arg::new("schema")
+                .long("schema")
+                .value_name("FILE")
+                .help("Write the inferred column schema as JSON to this file")
+        )
         .arg(
             Arg::new("no-cleaning")
                 .long("no-cleaning")
@@ -61,11 +80,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Parse arguments
     let input_file = matches.get_one::<String>("input").unwrap();
     let output_file = matches.get_one::<String>("output");
+    let schema_file = matches.get_one::<String>("schema");
+    let on_error = matches.get_one::<String>("on-error").unwrap();
     let chunk_size: usize = matches.get_one::<String>("chunk-size").unwrap().parse()?;
     let chunk_overlap: usize = matches.get_one::<String>("chunk-overlap").unwrap().parse()?;
     let text_cleaning = !matches.get_flag("no-cleaning");
     let language_detection = matches.get_flag("detect-language");
     let pretty_print = matches.get_flag("pretty");
+    let delimiter = matches.get_one::<String>("delimiter").map(|delimiter| match delimiter.as_str() {
+        "tab" | "\\t" => "\t".to_string(),
+        other => other.to_string(),
+    });
+    if let Some(delimiter) = &delimiter {
+        if delimiter.chars().count() != 1 || !delimiter.is_ascii() {
+            eprintln!("Error: --delimiter must be a single ASCII character, got {:?}", delimiter);
+            std::process::exit(1);
+        }
+    }
 
     // Validate input file
     let input_path = Path::new(input_file);
@@ -76,10 +107,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     if !input_path.extension()
         .and_then(|ext| ext.to_str())
-        .map(|ext| ext.to_lowercase() == "csv")
+        .map(|ext| matches!(ext.to_lowercase().as_str(), "csv" | "tsv"))
         .unwrap_or(false) 
     {
-        eprintln!("Error: Input file must have .csv extension");
+        eprintln!("Error: Input file must have .csv or .tsv extension");
         std::process::exit(1);
     }
 
@@ -89,7 +120,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         chunk_overlap,
         text_cleaning,
         language_detection,
-        format_specific: serde_json::Value::Null,
+        format_specific: match delimiter {
+            Some(delimiter) => serde_json::json!({ "csv": { "delimiter": delimiter, "on_error": on_error } }),
+            None => serde_json::json!({ "csv": { "on_error": on_error } }),
+        },
     };
 
     // Process the CSV file
@@ -122,6 +156,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
+    if let Some(schema_path) = schema_file {
+        match CsvSchema::from_output(&result) {
+            Some(schema) => {
+                std::fs::write(schema_path, serde_json::to_string_pretty(&schema)?)?;
+                eprintln!("Schema written to: {}", schema_path);
+            }
+            None => eprintln!("Warning: no schema inferred for {}", input_file),
+        }
+    }
+
     // Print summary
     eprintln!("✅ Processing completed successfully!");
     eprintln!("   📄 Document: {}", result.document_metadata.filename);
@@ -137,15 +181,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(total_columns) = csv_meta["total_columns"].as_u64() {
             eprintln!("   📐 Total columns: {}", total_columns);
         }
+        if let Some(delimiter) = csv_meta["dialect"]["delimiter"].as_str() {
+            eprintln!("   🔣 Delimiter: {:?}", delimiter);
+        }
         if let Some(completeness) = csv_meta["data_completeness"].as_f64() {
             eprintln!("   ✅ Data completeness: {:.1}%", completeness * 100.0);
         }
         
-        // Display headers
-        if let Some(headers) = csv_meta["headers"].as_array() {
+        // Display headers with their inferred types
+        if let Some(columns) = csv_meta["schema"]["columns"].as_array() {
             eprintln!("   🏷️  Column headers: {}", 
-                headers.iter()
-                    .filter_map(|h| h.as_str())
+                columns.iter()
+                    .filter_map(|column| Some(format!("{} ({})", column["name"].as_str()?, column["type"].as_str()?)))
                     .collect::<Vec<_>>()
                     .join(", ")
             );
@@ -156,5 +203,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         eprintln!("   🌐 Detected language: {}", language);
     }
 
+    for warning in &result.processing_info.warnings {
+        match warning.line {
+            Some(line) => eprintln!("   ⚠️  Line {}: {}", line, warning.message),
+            None => eprintln!("   ⚠️  {}", warning.message),
+        }
+    }
+
     Ok(())
 }
@@ -1,7 +1,6 @@
 use clap::{Arg, Command};
 use doc_loader::{processors::json::JsonProcessor, ProcessingParams, DocumentProcessor};
 use std::path::Path;
-use serde_json;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
@@ -14,7 +13,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .short('i')
                 .long("input")
                 .value_name("FILE")
-                .help("Input JSON file path")
+                .help("Input JSON or JSON Lines (.jsonl, .ndjson) file path")
                 .required(true)
         )
         .arg(
@@ -38,6 +37,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .help("Overlap between chunks in characters")
                 .default_value("100")
         )
+        .arg(
+            Arg::new("style")
+                .long("style")
+                .value_name("STYLE")
+                .help("Flattening style: paths, values, yaml or markdown")
+                .value_parser(["paths", "values", "yaml", "markdown"])
+                .default_value("paths")
+        )
+        .arg(
+            Arg::new("max-depth")
+                .long("max-depth")
+                .value_name("DEPTH")
+                .help("Maximum nesting depth rendered")
+                .default_value("10")
+        )
+        .arg(
+            Arg::new("drop-keys")
+                .long("drop-keys")
+                .value_name("PATTERNS")
+                .help("Comma-separated keys to drop, * matches any characters (e.g. \"id,_meta,*_url\")")
+        )
         .arg(
             Arg::new("no-cleaning")
                 .long("no-cleaning")
@@ -63,6 +83,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let output_file = matches.get_one::<String>("output");
     let chunk_size: usize = matches.get_one::<String>("chunk-size").unwrap().parse()?;
     let chunk_overlap: usize = matches.get_one::<String>("chunk-overlap").unwrap().parse()?;
+    let style = matches.get_one::<String>("style").unwrap();
+    let max_depth: usize = matches.get_one::<String>("max-depth").unwrap().parse()?;
+    let drop_keys: Vec<&str> = matches.get_one::<String>("drop-keys")
+        .map(|patterns| patterns.split(',').map(str::trim).filter(|pattern| !pattern.is_empty()).collect())
+        .unwrap_or_default();
     let text_cleaning = !matches.get_flag("no-cleaning");
     let language_detection = matches.get_flag("detect-language");
     let pretty_print = matches.get_flag("pretty");
@@ -76,10 +101,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     if !input_path.extension()
         .and_then(|ext| ext.to_str())
-        .map(|ext| ext.to_lowercase() == "json")
+        .map(|ext| matches!(ext.to_lowercase().as_str(), "json" | "jsonl" | "ndjson"))
         .unwrap_or(false) 
     {
-        eprintln!("Error: Input file must have .json extension");
+        eprintln!("Error: Input file must have .json, .jsonl or .ndjson extension");
         std::process::exit(1);
     }
 
@@ -89,7 +114,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         chunk_overlap,
         text_cleaning,
         language_detection,
-        format_specific: serde_json::Value::Null,
+        format_specific: serde_json::json!({
+            "json": { "style": style, "max_depth": max_depth, "drop_keys": drop_keys }
+        }),
     };
 
     // Process the JSON file
@@ -145,10 +172,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 if let Some(length) = json_meta["length"].as_u64() {
                     eprintln!("   📏 Array length: {}", length);
                 }
+            } else if json_type == "lines" {
+                if let Some(record_count) = json_meta["record_count"].as_u64() {
+                    eprintln!("   📏 Records: {}", record_count);
+                }
             }
         }
     }
 
+    for warning in &result.processing_info.warnings {
+        match warning.line {
+            Some(line) => eprintln!("   ⚠️  Line {}: {}", line, warning.message),
+            None => eprintln!("   ⚠️  {}", warning.message),
+        }
+    }
+
     if let Some(language) = result.document_metadata.format_metadata["detected_language"].as_str() {
         eprintln!("   🌐 Detected language: {}", language);
     }
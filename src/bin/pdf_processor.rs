@@ -1,7 +1,6 @@
 use clap::{Arg, Command};
 use doc_loader::{processors::pdf::PdfProcessor, ProcessingParams, DocumentProcessor};
 use std::path::Path;
-use serde_json;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
@@ -1,4 +1,7 @@
+use crate::error::DocLoaderError;
+
 use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
 use chrono::{DateTime, Utc};
 
 /// Structure universelle pour un chunk de document
@@ -60,7 +63,7 @@ pub struct DocumentMetadata {
     pub filename: String,
     /// Chemin complet du fichier
     pub filepath: String,
-    /// Type de document (PDF, TXT, JSON, CSV, DOCX)
+    /// Type de document (PDF, TXT, JSON, CSV, DOCX, PPTX, ODT, ODS, ODP, EPUB, RTF, EML, MBOX, XML, YAML, TOML, code source, IPYNB, SRT, VTT, TEX ou format personnalisé)
     pub document_type: DocumentType,
     /// Taille du fichier en bytes
     pub file_size: u64,
@@ -77,13 +80,31 @@ pub struct DocumentMetadata {
 }
 
 /// Types de documents supportés
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum DocumentType {
     PDF,
     TXT,
     JSON,
     CSV,
     DOCX,
+    PPTX,
+    ODT,
+    ODS,
+    ODP,
+    EPUB,
+    RTF,
+    EML,
+    MBOX,
+    XML,
+    YAML,
+    TOML,
+    Code,
+    IPYNB,
+    SRT,
+    VTT,
+    TEX,
+    /// Format enregistré par l'application (voir `ProcessorRegistry`)
+    Custom(String),
 }
 
 /// Informations sur le traitement effectué
@@ -103,6 +124,18 @@ pub struct ProcessingInfo {
     pub total_content_size: usize,
     /// Paramètres de traitement utilisés
     pub processing_params: ProcessingParams,
+    /// Problèmes tolérés pendant le traitement (lignes ignorées ou réparées)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ProcessingWarning>,
+}
+
+/// Problème toléré pendant le traitement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessingWarning {
+    /// Ligne du document concernée
+    pub line: Option<u64>,
+    /// Raison du problème et traitement appliqué
+    pub message: String,
 }
 
 /// Paramètres de traitement
@@ -157,6 +190,17 @@ impl ProcessingParams {
         self.format_specific = metadata;
         self
     }
+
+    /// Options d'un format lues depuis `format_specific[key]`, valeurs par défaut si elles sont
+    /// absentes. Une option invalide est une erreur : elle ne doit pas rétablir en silence les
+    /// valeurs par défaut (limites de sécurité comprises).
+    pub fn format_options<T: DeserializeOwned + Default>(&self, key: &str) -> Result<T, DocLoaderError> {
+        match self.format_specific.get(key) {
+            None | Some(serde_json::Value::Null) => Ok(T::default()),
+            Some(options) => T::deserialize(options)
+                .map_err(|e| DocLoaderError::InvalidFormat(format!("Invalid format_specific[\"{}\"] options: {}", key, e))),
+        }
+    }
 }
 
 impl DocumentType {
@@ -164,20 +208,55 @@ impl DocumentType {
         match ext.to_lowercase().as_str() {
             "pdf" => Some(DocumentType::PDF),
             "txt" => Some(DocumentType::TXT),
-            "json" => Some(DocumentType::JSON),
-            "csv" => Some(DocumentType::CSV),
+            "json" | "jsonl" | "ndjson" => Some(DocumentType::JSON),
+            "csv" | "tsv" => Some(DocumentType::CSV),
             "docx" => Some(DocumentType::DOCX),
+            "pptx" => Some(DocumentType::PPTX),
+            "odt" => Some(DocumentType::ODT),
+            "ods" => Some(DocumentType::ODS),
+            "odp" => Some(DocumentType::ODP),
+            "epub" => Some(DocumentType::EPUB),
+            "rtf" => Some(DocumentType::RTF),
+            "eml" => Some(DocumentType::EML),
+            "mbox" => Some(DocumentType::MBOX),
+            "xml" => Some(DocumentType::XML),
+            "yaml" | "yml" => Some(DocumentType::YAML),
+            "toml" => Some(DocumentType::TOML),
+            "ipynb" => Some(DocumentType::IPYNB),
+            "srt" => Some(DocumentType::SRT),
+            "vtt" => Some(DocumentType::VTT),
+            "tex" | "ltx" => Some(DocumentType::TEX),
+            "rs" | "py" | "pyw" | "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "go"
+            | "java" | "kt" | "kts" | "scala" | "swift" | "c" | "h" | "cpp" | "cc" | "cxx"
+            | "hpp" | "hh" | "hxx" | "cs" | "php" | "rb" | "sh" | "bash" => Some(DocumentType::Code),
             _ => None,
         }
     }
 
-    pub fn to_string(&self) -> &'static str {
+    pub fn to_string(&self) -> &str {
         match self {
             DocumentType::PDF => "PDF",
             DocumentType::TXT => "TXT",
             DocumentType::JSON => "JSON",
             DocumentType::CSV => "CSV",
             DocumentType::DOCX => "DOCX",
+            DocumentType::PPTX => "PPTX",
+            DocumentType::ODT => "ODT",
+            DocumentType::ODS => "ODS",
+            DocumentType::ODP => "ODP",
+            DocumentType::EPUB => "EPUB",
+            DocumentType::RTF => "RTF",
+            DocumentType::EML => "EML",
+            DocumentType::MBOX => "MBOX",
+            DocumentType::XML => "XML",
+            DocumentType::YAML => "YAML",
+            DocumentType::TOML => "TOML",
+            DocumentType::Code => "CODE",
+            DocumentType::IPYNB => "IPYNB",
+            DocumentType::SRT => "SRT",
+            DocumentType::VTT => "VTT",
+            DocumentType::TEX => "TEX",
+            DocumentType::Custom(name) => name,
         }
     }
 }
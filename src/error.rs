@@ -32,6 +32,21 @@ pub enum DocLoaderError {
     
     #[error("Processing error: {0}")]
     Processing(String),
+    
+    #[error("XML parsing error: {0}")]
+    XmlParsing(String),
+    
+    #[error("Archive error: {0}")]
+    Archive(String),
+    
+    #[error("Decompression error: {0}")]
+    Decompression(String),
+    
+    #[error("YAML parsing error: {0}")]
+    YamlParsing(String),
+    
+    #[error("TOML parsing error: {0}")]
+    TomlParsing(String),
 }
 
 /// Result type alias for doc_loader operations
@@ -42,3 +57,21 @@ impl From<lopdf::Error> for DocLoaderError {
         DocLoaderError::PdfParsing(err.to_string())
     }
 }
+
+impl From<quick_xml::Error> for DocLoaderError {
+    fn from(err: quick_xml::Error) -> Self {
+        DocLoaderError::XmlParsing(err.to_string())
+    }
+}
+
+impl From<yaml_rust2::ScanError> for DocLoaderError {
+    fn from(err: yaml_rust2::ScanError) -> Self {
+        DocLoaderError::YamlParsing(err.to_string())
+    }
+}
+
+impl From<toml_edit::TomlError> for DocLoaderError {
+    fn from(err: toml_edit::TomlError) -> Self {
+        DocLoaderError::TomlParsing(err.to_string())
+    }
+}
@@ -8,6 +8,17 @@
 //! - JSON documents
 //! - CSV files
 //! - DOCX documents
+//! - PPTX presentations
+//! - OpenDocument files (ODT, ODS, ODP)
+//! - EPUB e-books
+//! - RTF documents
+//! - Emails (.eml) and mbox mailboxes
+//! - XML documents
+//! - YAML and TOML configuration files
+//! - Jupyter notebooks (markdown and code cells in order)
+//! - SRT and WebVTT subtitles and transcripts
+//! - LaTeX sources (section hierarchy, math kept inline)
+//! - Source code (Rust, Python, JavaScript/TypeScript, Go, Java, C/C++, ...)
 //! 
 //! Each processor extracts content and metadata, then formats everything into a universal JSON
 //! structure ready for vector stores and RAG systems.
@@ -16,7 +27,7 @@
 //! 
 //! - **Universal JSON Output**: Consistent format across all document types
 //! - **Intelligent Text Processing**: Smart chunking, cleaning, and metadata extraction
-//! - **Modular Architecture**: Each document type has its specialized processor
+//! - **Modular Architecture**: Each document type has its specialized processor, custom formats can be registered
 //! - **Vector Store Ready**: Optimized output for embedding and indexing
 //! 
 //! ## Example
@@ -46,13 +57,16 @@ pub mod core;
 pub use error::{DocLoaderError, Result};
 pub use core::{
     UniversalOutput, DocumentChunk, ChunkMetadata, DocumentMetadata, 
-    ProcessingParams, DocumentType, ProcessingInfo
+    ProcessingParams, DocumentType, ProcessingInfo, ProcessingWarning
 };
 pub use processors::{UniversalProcessor, DocumentProcessor};
+pub use processors::detect::detect_type;
+pub use processors::registry::ProcessorRegistry;
+pub use processors::profile::CsvSchema;
 
 // Re-export key utility functions
 pub use utils::{
-    clean_text, chunk_text, extract_text_metadata, detect_language
+    clean_text, chunk_text, extract_text_metadata, detect_language, html_to_text
 };
 
 // Python bindings module
@@ -69,9 +83,13 @@ mod tests {
 
     #[test]
     fn test_universal_processor_creation() {
-        let _processor = UniversalProcessor::new();
+        let processor = UniversalProcessor::new();
         // Test that processor is created successfully
         assert!(!UniversalProcessor::supported_extensions().is_empty());
+        assert_eq!(processor.processor(&DocumentType::CSV).unwrap().supported_type(), DocumentType::CSV);
+        #[allow(deprecated)]
+        let legacy = processor.get_processor(&DocumentType::PDF);
+        assert_eq!(legacy.supported_type(), DocumentType::PDF);
     }
 
     #[test]
@@ -80,8 +98,26 @@ mod tests {
         assert!(extensions.contains(&"pdf"));
         assert!(extensions.contains(&"txt"));
         assert!(extensions.contains(&"json"));
+        assert!(extensions.contains(&"jsonl"));
         assert!(extensions.contains(&"csv"));
+        assert!(extensions.contains(&"tsv"));
         assert!(extensions.contains(&"docx"));
+        assert!(extensions.contains(&"pptx"));
+        assert!(extensions.contains(&"odt"));
+        assert!(extensions.contains(&"ods"));
+        assert!(extensions.contains(&"odp"));
+        assert!(extensions.contains(&"epub"));
+        assert!(extensions.contains(&"rtf"));
+        assert!(extensions.contains(&"eml"));
+        assert!(extensions.contains(&"mbox"));
+        assert!(extensions.contains(&"xml"));
+        assert!(extensions.contains(&"yaml"));
+        assert!(extensions.contains(&"toml"));
+        assert!(extensions.contains(&"ipynb"));
+        assert!(extensions.contains(&"vtt"));
+        assert!(extensions.contains(&"tex"));
+        assert!(extensions.contains(&"rs"));
+        assert!(extensions.contains(&"py"));
     }
 
     #[test]
@@ -98,8 +134,25 @@ mod tests {
         assert_eq!(DocumentType::from_extension("pdf"), Some(DocumentType::PDF));
         assert_eq!(DocumentType::from_extension("txt"), Some(DocumentType::TXT));
         assert_eq!(DocumentType::from_extension("json"), Some(DocumentType::JSON));
+        assert_eq!(DocumentType::from_extension("ndjson"), Some(DocumentType::JSON));
         assert_eq!(DocumentType::from_extension("csv"), Some(DocumentType::CSV));
+        assert_eq!(DocumentType::from_extension("tsv"), Some(DocumentType::CSV));
         assert_eq!(DocumentType::from_extension("docx"), Some(DocumentType::DOCX));
+        assert_eq!(DocumentType::from_extension("pptx"), Some(DocumentType::PPTX));
+        assert_eq!(DocumentType::from_extension("odt"), Some(DocumentType::ODT));
+        assert_eq!(DocumentType::from_extension("ods"), Some(DocumentType::ODS));
+        assert_eq!(DocumentType::from_extension("odp"), Some(DocumentType::ODP));
+        assert_eq!(DocumentType::from_extension("epub"), Some(DocumentType::EPUB));
+        assert_eq!(DocumentType::from_extension("rtf"), Some(DocumentType::RTF));
+        assert_eq!(DocumentType::from_extension("eml"), Some(DocumentType::EML));
+        assert_eq!(DocumentType::from_extension("mbox"), Some(DocumentType::MBOX));
+        assert_eq!(DocumentType::from_extension("xml"), Some(DocumentType::XML));
+        assert_eq!(DocumentType::from_extension("yml"), Some(DocumentType::YAML));
+        assert_eq!(DocumentType::from_extension("toml"), Some(DocumentType::TOML));
+        assert_eq!(DocumentType::from_extension("go"), Some(DocumentType::Code));
+        assert_eq!(DocumentType::from_extension("ipynb"), Some(DocumentType::IPYNB));
+        assert_eq!(DocumentType::from_extension("srt"), Some(DocumentType::SRT));
+        assert_eq!(DocumentType::from_extension("tex"), Some(DocumentType::TEX));
         assert_eq!(DocumentType::from_extension("unknown"), None);
     }
 
@@ -110,6 +163,7 @@ mod tests {
         assert_eq!(DocumentType::JSON.to_string(), "JSON");
         assert_eq!(DocumentType::CSV.to_string(), "CSV");
         assert_eq!(DocumentType::DOCX.to_string(), "DOCX");
+        assert_eq!(DocumentType::PPTX.to_string(), "PPTX");
     }
 
     #[test]
@@ -1,5 +1,6 @@
 use clap::{Arg, Command};
 use doc_loader::{UniversalProcessor, ProcessingParams};
+use doc_loader::processors::archive::ArchiveKind;
 use std::path::Path;
 
 #[tokio::main]
@@ -87,6 +88,38 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Processing file: {}", input_file);
     let processor = UniversalProcessor::new();
     
+    // Archives: one result per supported member, written as a JSON array
+    if ArchiveKind::from_name(input_file).is_some() {
+        let results = match processor.process_archive(input_path, Some(params)) {
+            Ok(outputs) => outputs,
+            Err(e) => {
+                eprintln!("Error processing archive: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let json_output = if pretty_print {
+            serde_json::to_string_pretty(&results)?
+        } else {
+            serde_json::to_string(&results)?
+        };
+
+        match output_file {
+            Some(output_path) => {
+                std::fs::write(output_path, json_output)?;
+                println!("Results written to: {}", output_path);
+            }
+            None => {
+                println!("{}", json_output);
+            }
+        }
+
+        eprintln!("✅ Archive processed successfully!");
+        eprintln!("   📦 Documents extracted: {}", results.len());
+        eprintln!("   🧩 Chunks extracted: {}", results.iter().map(|r| r.processing_info.total_chunks).sum::<usize>());
+        return Ok(());
+    }
+    
     let result = match processor.process_file(input_path, Some(params)) {
         Ok(output) => output,
         Err(e) => {
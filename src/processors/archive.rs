@@ -0,0 +1,532 @@
+//! Ingestion récursive des archives ZIP et TAR (`.tar`, `.tar.gz`, `.tgz`, `.tar.xz`, ...).
+//!
+//! Chaque membre dans un format supporté (reconnu sur son contenu, l'extension n'étant qu'un
+//! indice) est confié au processeur correspondant ; les archives imbriquées sont ouvertes à
+//! leur tour, dans la limite de `max_depth`.
+
+use crate::core::{DocumentType, ProcessingParams, UniversalOutput};
+use crate::error::DocLoaderError;
+use crate::processors::UniversalProcessor;
+use crate::processors::compression::Compression;
+use crate::processors::detect;
+
+use std::io::{Cursor, Read};
+use std::path::Path;
+use chrono::{DateTime, TimeZone, Utc};
+use serde::Deserialize;
+use serde_json::json;
+
+/// Taille à partir de laquelle le taux de compression est contrôlé
+const RATIO_CHECK_THRESHOLD: u64 = 1024 * 1024;
+
+/// Limites de sécurité, lues depuis `params.format_specific["archive"]`
+///
+/// ```json
+/// { "archive": { "max_depth": 3, "max_entries": 10000, "max_total_size": 1073741824 } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ArchiveOptions {
+    /// Niveaux d'archives imbriquées ouverts sous l'archive principale
+    pub max_depth: usize,
+    /// Nombre maximal d'entrées lues, toutes archives confondues
+    pub max_entries: usize,
+    /// Taille décompressée maximale d'un membre (les membres plus grands sont ignorés)
+    pub max_entry_size: u64,
+    /// Taille décompressée cumulée maximale (au-delà, l'archive est rejetée)
+    pub max_total_size: u64,
+    /// Taux de compression maximal accepté (décompressé / compressé)
+    pub max_compression_ratio: u64,
+}
+
+impl Default for ArchiveOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            max_entries: 10_000,
+            max_entry_size: 100 * 1024 * 1024,
+            max_total_size: 1024 * 1024 * 1024,
+            max_compression_ratio: 100,
+        }
+    }
+}
+
+impl ArchiveOptions {
+    pub fn from_params(params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        params.format_options("archive")
+    }
+}
+
+/// Format d'archive reconnu
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArchiveKind {
+    Zip,
+    Tar,
+    /// TAR compressé (`.tar.gz`, `.tgz`, `.tar.bz2`, `.tar.xz`, `.tar.zst`)
+    CompressedTar(Compression),
+}
+
+impl ArchiveKind {
+    /// Format d'archive déduit du nom de fichier
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        let short_forms = [
+            (".tgz", Compression::Gzip),
+            (".tbz2", Compression::Bzip2),
+            (".txz", Compression::Xz),
+            (".tzst", Compression::Zstd),
+        ];
+
+        if name.ends_with(".zip") {
+            Some(ArchiveKind::Zip)
+        } else if name.ends_with(".tar") {
+            Some(ArchiveKind::Tar)
+        } else if let Some((_, compression)) = short_forms.iter().find(|(suffix, _)| name.ends_with(suffix)) {
+            Some(ArchiveKind::CompressedTar(*compression))
+        } else {
+            match Compression::from_name(&name) {
+                Some((compression, inner)) if inner.ends_with(".tar") => Some(ArchiveKind::CompressedTar(compression)),
+                _ => None,
+            }
+        }
+    }
+
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            ArchiveKind::Zip => "zip",
+            ArchiveKind::Tar => "tar",
+            ArchiveKind::CompressedTar(Compression::Gzip) => "tar.gz",
+            ArchiveKind::CompressedTar(Compression::Bzip2) => "tar.bz2",
+            ArchiveKind::CompressedTar(Compression::Xz) => "tar.xz",
+            ArchiveKind::CompressedTar(Compression::Zstd) => "tar.zst",
+        }
+    }
+}
+
+/// Chemin d'un membre normalisé, `None` s'il sort de l'archive (chemin absolu, `..`, lecteur Windows)
+pub fn sanitize_member_path(name: &str) -> Option<String> {
+    let name = name.replace('\\', "/");
+    if name.starts_with('/') {
+        return None;
+    }
+
+    let mut components = Vec::new();
+    for component in name.split('/') {
+        match component {
+            "" | "." => continue,
+            ".." => return None,
+            c if c.contains(':') => return None,
+            c => components.push(c),
+        }
+    }
+    if components.is_empty() { None } else { Some(components.join("/")) }
+}
+
+/// Parcours d'une archive et de ses archives imbriquées
+struct ArchiveWalker<'a> {
+    processor: &'a UniversalProcessor,
+    params: &'a ProcessingParams,
+    options: ArchiveOptions,
+    entry_count: usize,
+    total_size: u64,
+    /// Une limite de sécurité a été dépassée : l'erreur interrompt tout le parcours
+    limit_exceeded: bool,
+    outputs: Vec<UniversalOutput>,
+}
+
+/// Destination d'un membre d'archive
+enum MemberTarget {
+    /// Document, éventuellement compressé (`data.csv.gz`), avec le type indiqué par son extension
+    Document(Option<DocumentType>, Option<Compression>),
+    Archive(ArchiveKind),
+}
+
+impl ArchiveWalker<'_> {
+    fn walk(&mut self, content: &[u8], label: &str, kind: ArchiveKind, depth: usize) -> Result<(), DocLoaderError> {
+        match kind {
+            ArchiveKind::Zip => self.walk_zip(content, label, depth),
+            ArchiveKind::Tar => self.walk_tar(content, label, depth, None),
+            ArchiveKind::CompressedTar(compression) => {
+                self.walk_tar(compression.decoder(content)?, label, depth, Some(content.len() as u64))
+            },
+        }
+    }
+
+    fn walk_zip(&mut self, content: &[u8], label: &str, depth: usize) -> Result<(), DocLoaderError> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(content))
+            .map_err(|e| DocLoaderError::Archive(format!("Failed to open {}: {}", label, e)))?;
+
+        for index in 0..archive.len() {
+            let entry = archive.by_index(index)
+                .map_err(|e| DocLoaderError::Archive(format!("Failed to read entry {} of {}: {}", index, label, e)))?;
+            if !entry.is_file() {
+                continue;
+            }
+
+            let name = entry.name().to_string();
+            let compressed_size = entry.compressed_size();
+            let modified = entry.last_modified().and_then(|dt| Utc.with_ymd_and_hms(
+                dt.year() as i32, dt.month() as u32, dt.day() as u32,
+                dt.hour() as u32, dt.minute() as u32, dt.second() as u32,
+            ).single());
+
+            let Some((member, target)) = self.member_target(&name, label, depth) else { continue };
+            let Some(bytes) = self.read_member(entry, label, &member)? else { continue };
+
+            // Contrôle sur la taille réellement décompressée, pas sur celle déclarée
+            if bytes.len() as u64 > RATIO_CHECK_THRESHOLD
+                && bytes.len() as u64 / compressed_size.max(1) > self.options.max_compression_ratio
+            {
+                return Err(self.limit_error(format!(
+                    "Entry {}!/{} has a suspicious compression ratio (possible zip bomb)", label, member
+                )));
+            }
+
+            self.dispatch(&bytes, label, &member, target, modified, depth)?;
+        }
+        Ok(())
+    }
+
+    fn walk_tar<R: Read>(&mut self, reader: R, label: &str, depth: usize, compressed_size: Option<u64>) -> Result<(), DocLoaderError> {
+        let mut archive = tar::Archive::new(reader);
+        let mut archive_size: u64 = 0;
+
+        let entries = archive.entries()
+            .map_err(|e| DocLoaderError::Archive(format!("Failed to open {}: {}", label, e)))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| DocLoaderError::Archive(format!("Failed to read entry of {}: {}", label, e)))?;
+
+            // Liens symboliques et physiques ignorés : ils pourraient désigner des fichiers hors de l'archive
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+
+            let name = entry.path()
+                .map_err(|e| DocLoaderError::Archive(format!("Invalid entry path in {}: {}", label, e)))?
+                .to_string_lossy()
+                .to_string();
+            let modified = entry.header().mtime().ok()
+                .and_then(|mtime| DateTime::<Utc>::from_timestamp(mtime as i64, 0));
+
+            let Some((member, target)) = self.member_target(&name, label, depth) else { continue };
+            let Some(bytes) = self.read_member(entry, label, &member)? else { continue };
+
+            // Le flux compressé est contrôlé globalement : la taille compressée d'un membre n'est pas connue
+            archive_size += bytes.len() as u64;
+            if let Some(compressed_size) = compressed_size {
+                if archive_size > RATIO_CHECK_THRESHOLD
+                    && archive_size / compressed_size.max(1) > self.options.max_compression_ratio
+                {
+                    return Err(self.limit_error(format!(
+                        "{} has a suspicious compression ratio (possible compression bomb)", label
+                    )));
+                }
+            }
+
+            self.dispatch(&bytes, label, &member, target, modified, depth)?;
+        }
+        Ok(())
+    }
+
+    /// Chemin normalisé et destination d'un membre, `None` s'il doit être ignoré
+    fn member_target(&mut self, name: &str, label: &str, depth: usize) -> Option<(String, MemberTarget)> {
+        let Some(member) = sanitize_member_path(name) else {
+            log::warn!("Skipping archive entry with unsafe path {}!/{}", label, name);
+            return None;
+        };
+
+        if let Some(kind) = ArchiveKind::from_name(&member) {
+            if depth >= self.options.max_depth {
+                log::warn!("Skipping nested archive {}!/{}: maximum depth reached", label, member);
+                return None;
+            }
+            return Some((member, MemberTarget::Archive(kind)));
+        }
+
+        let (compression, inner_name) = match Compression::from_name(&member) {
+            Some((compression, inner_name)) => (Some(compression), inner_name),
+            None => (None, member.clone()),
+        };
+        let hint = Path::new(&inner_name)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| self.processor.registry().type_for_extension(ext));
+        Some((member, MemberTarget::Document(hint, compression)))
+    }
+
+    /// Lit un membre en respectant les limites de nombre et de taille
+    fn read_member(&mut self, reader: impl Read, label: &str, member: &str) -> Result<Option<Vec<u8>>, DocLoaderError> {
+        self.entry_count += 1;
+        if self.entry_count > self.options.max_entries {
+            return Err(self.limit_error(format!(
+                "{} has more than {} entries", label, self.options.max_entries
+            )));
+        }
+
+        let mut bytes = Vec::new();
+        reader.take(self.options.max_entry_size + 1).read_to_end(&mut bytes)
+            .map_err(|e| DocLoaderError::Archive(format!("Failed to read {}!/{}: {}", label, member, e)))?;
+        self.add_size(bytes.len() as u64, label)?;
+
+        if bytes.len() as u64 > self.options.max_entry_size {
+            log::warn!("Skipping {}!/{}: larger than {} bytes", label, member, self.options.max_entry_size);
+            return Ok(None);
+        }
+        Ok(Some(bytes))
+    }
+
+    /// Erreur de limite de sécurité, propagée jusqu'à l'archive principale
+    fn limit_error(&mut self, message: String) -> DocLoaderError {
+        self.limit_exceeded = true;
+        DocLoaderError::Archive(message)
+    }
+
+    /// Ajoute une taille décompressée au total, en respectant `max_total_size`
+    fn add_size(&mut self, size: u64, label: &str) -> Result<(), DocLoaderError> {
+        self.total_size += size;
+        if self.total_size > self.options.max_total_size {
+            return Err(self.limit_error(format!(
+                "{} exceeds the maximum total uncompressed size of {} bytes (possible zip bomb)",
+                label, self.options.max_total_size
+            )));
+        }
+        Ok(())
+    }
+
+    /// Traite un membre : archive imbriquée ou document
+    fn dispatch(
+        &mut self,
+        bytes: &[u8],
+        label: &str,
+        member: &str,
+        target: MemberTarget,
+        modified: Option<DateTime<Utc>>,
+        depth: usize,
+    ) -> Result<(), DocLoaderError> {
+        let filepath = format!("{}!/{}", label, member);
+        let bytes_len = bytes.len();
+
+        let (hint, compression) = match target {
+            MemberTarget::Archive(kind) => {
+                return match self.walk(bytes, &filepath, kind, depth + 1) {
+                    // Une archive imbriquée corrompue est ignorée comme un document illisible,
+                    // les membres déjà traités sont conservés
+                    Err(e) if !self.limit_exceeded => {
+                        log::warn!("Skipping nested archive {}: {}", filepath, e);
+                        Ok(())
+                    },
+                    result => result,
+                };
+            },
+            MemberTarget::Document(hint, compression) => (hint, compression),
+        };
+
+        // Membre compressé : décompressé dans les mêmes limites qu'une entrée d'archive ;
+        // la signature prévaut sur le suffixe, comme pour un fichier
+        let mut filename = member.rsplit('/').next().unwrap_or(member).to_string();
+        if compression.is_some() {
+            filename = Compression::from_name(&filename).map_or(filename, |(_, inner)| inner);
+        }
+        let decompressed;
+        let bytes = match compression.and(Compression::from_magic(bytes)) {
+            Some(compression) => {
+                decompressed = match compression.decompress(bytes, self.options.max_entry_size) {
+                    Ok(decompressed) => decompressed,
+                    Err(e) => {
+                        log::warn!("Failed to process {}: {}", filepath, e);
+                        return Ok(());
+                    },
+                };
+                self.add_size(decompressed.len() as u64, label)?;
+                &decompressed[..]
+            },
+            None => bytes,
+        };
+
+        // Le contenu fait foi, comme pour un fichier : membres sans extension ou mal nommés compris
+        let registry = self.processor.registry();
+        let head = &bytes[..bytes.len().min(detect::HEAD_SIZE)];
+        let Some(document_type) = registry.sniff(head).or_else(|| detect::detect_type_with_hint(bytes, hint)) else {
+            return Ok(());
+        };
+        match registry.processor(&document_type).and_then(|processor| processor.process_content_with_registry(bytes, &filename, self.params, registry)) {
+            Ok(mut output) => {
+                output.document_metadata.filepath = filepath;
+                if modified.is_some() {
+                    output.document_metadata.modified_at = modified;
+                }
+                if let Some(format_metadata) = output.document_metadata.format_metadata.as_object_mut() {
+                    format_metadata.insert("archive".to_string(), json!({
+                        "archive_path": label,
+                        "member_path": member,
+                        "depth": depth
+                    }));
+                    if let Some(compression) = compression {
+                        format_metadata.insert("compression".to_string(), json!({
+                            "type": compression.to_string(),
+                            "inner_filename": filename,
+                            "compressed_size": bytes_len,
+                            "decompressed_size": bytes.len()
+                        }));
+                    }
+                }
+                self.outputs.push(output);
+            },
+            // Un membre illisible n'empêche pas de traiter le reste de l'archive
+            Err(e) => log::warn!("Failed to process {}: {}", filepath, e),
+        }
+        Ok(())
+    }
+}
+
+/// Traite une archive en mémoire ; `label` préfixe le `filepath` de chaque membre (`label!/chemin`)
+pub(crate) fn process_archive(
+    processor: &UniversalProcessor,
+    content: &[u8],
+    label: &str,
+    kind: ArchiveKind,
+    params: &ProcessingParams,
+) -> Result<Vec<UniversalOutput>, DocLoaderError> {
+    let mut walker = ArchiveWalker {
+        processor,
+        params,
+        options: ArchiveOptions::from_params(params)?,
+        entry_count: 0,
+        total_size: 0,
+        limit_exceeded: false,
+        outputs: Vec::new(),
+    };
+    walker.walk(content, label, kind, 0)?;
+    Ok(walker.outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    fn build_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_sanitize_member_path() {
+        assert_eq!(sanitize_member_path("./docs//a.txt").as_deref(), Some("docs/a.txt"));
+        assert_eq!(sanitize_member_path("docs\\b.txt").as_deref(), Some("docs/b.txt"));
+        assert_eq!(sanitize_member_path("../etc/passwd"), None);
+        assert_eq!(sanitize_member_path("/etc/passwd"), None);
+        assert_eq!(sanitize_member_path("C:/Windows/win.ini"), None);
+        assert_eq!(ArchiveKind::from_name("dump.TGZ"), Some(ArchiveKind::CompressedTar(Compression::Gzip)));
+        assert_eq!(ArchiveKind::from_name("dump.tar.xz"), Some(ArchiveKind::CompressedTar(Compression::Xz)));
+        assert_eq!(ArchiveKind::from_name("data.csv.gz"), None);
+    }
+
+    #[test]
+    fn test_zip_recursive_ingestion() {
+        let inner = build_zip(&[("c.txt", b"Nested text")]);
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(b"Compressed log").unwrap();
+        let log = gzip.finish().unwrap();
+        let outer = build_zip(&[
+            ("a.txt", b"Plain text"),
+            ("dir/b.json", br#"{"name": "b"}"#),
+            ("../evil.txt", b"escaped"),
+            ("image.bin", b"\x00\x01"),
+            ("inner.zip", &inner),
+            ("logs/app.txt.gz", &log),
+            ("NOTES", b"Extensionless notes"),
+            ("export.dat", br#"{"rows": 2}"#),
+        ]);
+
+        let outputs = UniversalProcessor::new()
+            .process_archive_content(&outer, "dump.zip", None)
+            .unwrap();
+        let paths: Vec<&str> = outputs.iter().map(|o| o.document_metadata.filepath.as_str()).collect();
+        assert_eq!(paths, vec![
+            "memory://dump.zip!/a.txt",
+            "memory://dump.zip!/dir/b.json",
+            "memory://dump.zip!/inner.zip!/c.txt",
+            "memory://dump.zip!/logs/app.txt.gz",
+            "memory://dump.zip!/NOTES",
+            "memory://dump.zip!/export.dat",
+        ]);
+        assert_eq!(outputs[1].document_metadata.filename, "b.json");
+        assert_eq!(outputs[4].document_metadata.document_type, DocumentType::TXT);
+        assert_eq!(outputs[5].document_metadata.document_type, DocumentType::JSON);
+        assert_eq!(outputs[2].document_metadata.format_metadata["archive"]["depth"], 1);
+        assert_eq!(outputs[3].chunks[0].content, "Compressed log");
+        assert_eq!(outputs[3].document_metadata.format_metadata["compression"]["type"], "gzip");
+
+        // Sans archive imbriquée autorisée
+        let params = ProcessingParams::default().with_format_specific(json!({ "archive": { "max_depth": 0 } }));
+        let outputs = UniversalProcessor::new().process_archive_content(&outer, "dump.zip", Some(params)).unwrap();
+        assert_eq!(outputs.len(), 5);
+    }
+
+    #[test]
+    fn test_corrupt_nested_archive_skipped() {
+        let corrupt = build_zip(&[
+            ("a.txt", b"First"),
+            ("broken.zip", b"not a zip archive"),
+            ("b.txt", b"Second"),
+        ]);
+        let outputs = UniversalProcessor::new().process_archive_content(&corrupt, "dump.zip", None).unwrap();
+        let paths: Vec<&str> = outputs.iter().map(|o| o.document_metadata.filepath.as_str()).collect();
+        assert_eq!(paths, vec!["memory://dump.zip!/a.txt", "memory://dump.zip!/b.txt"]);
+
+        // Les erreurs d'ouverture désignent l'archive fautive
+        let error = UniversalProcessor::new().process_archive_content(b"not a zip archive", "broken.zip", None).unwrap_err();
+        assert!(error.to_string().contains("Failed to open memory://broken.zip: invalid Zip archive"), "{}", error);
+
+        // Une limite dépassée dans une archive imbriquée interrompt toujours le traitement
+        let inner = build_zip(&[("c.txt", b"Nested"), ("d.txt", b"Nested")]);
+        let outer = build_zip(&[("a.txt", b"First"), ("inner.zip", &inner)]);
+        let params = ProcessingParams::default().with_format_specific(json!({ "archive": { "max_entries": 3 } }));
+        let result = UniversalProcessor::new().process_archive_content(&outer, "dump.zip", Some(params));
+        assert!(matches!(result, Err(DocLoaderError::Archive(message)) if message.contains("more than 3 entries")));
+    }
+
+    #[test]
+    fn test_tar_gz_ingestion() {
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default()));
+        let content = b"Report body";
+        let mut header = tar::Header::new_gnu();
+        header.set_size(content.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(1_700_000_000);
+        header.set_cksum();
+        builder.append_data(&mut header, "reports/q3.txt", &content[..]).unwrap();
+        let archive = builder.into_inner().unwrap().finish().unwrap();
+
+        let outputs = UniversalProcessor::new()
+            .process_archive_content(&archive, "dump.tar.gz", None)
+            .unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].document_metadata.filepath, "memory://dump.tar.gz!/reports/q3.txt");
+        assert_eq!(outputs[0].document_metadata.modified_at.unwrap().timestamp(), 1_700_000_000);
+    }
+
+    #[test]
+    fn test_zip_bomb_rejected() {
+        let zeros = vec![0u8; 4 * 1024 * 1024];
+        let bomb = build_zip(&[("zeros.txt", &zeros)]);
+
+        let result = UniversalProcessor::new().process_archive_content(&bomb, "bomb.zip", None);
+        assert!(matches!(result, Err(DocLoaderError::Archive(message)) if message.contains("compression ratio")));
+
+        let params = ProcessingParams::default().with_format_specific(json!({ "archive": { "max_total_size": 1024 } }));
+        let result = UniversalProcessor::new().process_archive_content(&bomb, "bomb.zip", Some(params));
+        assert!(matches!(result, Err(DocLoaderError::Archive(message)) if message.contains("total uncompressed size")));
+
+        // Une limite mal saisie est une erreur, pas un retour silencieux aux valeurs par défaut
+        let params = ProcessingParams::default().with_format_specific(json!({ "archive": { "max_depth": "0", "max_entries": 1 } }));
+        let result = UniversalProcessor::new().process_archive_content(&bomb, "bomb.zip", Some(params));
+        assert!(matches!(result, Err(DocLoaderError::InvalidFormat(message)) if message.contains("[\"archive\"]") && message.contains("invalid type")));
+    }
+}
@@ -0,0 +1,839 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::utils::{chunk_text, extract_text_metadata, normalize_line_breaks};
+
+use std::path::Path;
+use std::fs;
+use std::sync::LazyLock;
+use chrono::Utc;
+use regex::Regex;
+use serde_json::json;
+
+/// Façon dont les éléments de premier niveau sont délimités
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Syntax {
+    /// Blocs délimités par des accolades (Rust, C, Java, JavaScript, Go, ...)
+    Braces,
+    /// Blocs délimités par l'indentation (Python) ou par `end` (Ruby)
+    Indentation,
+}
+
+/// Description d'un langage de programmation reconnu
+#[derive(Debug)]
+pub struct Language {
+    pub name: &'static str,
+    pub extensions: &'static [&'static str],
+    pub syntax: Syntax,
+    line_comments: &'static [&'static str],
+    block_comments: bool,
+    /// Chaînes entre backticks (gabarits JavaScript, chaînes brutes Go, shell)
+    backtick_strings: bool,
+    /// `'a'` est un caractère mais `'a` une durée de vie (Rust)
+    rust_lifetimes: bool,
+}
+
+const fn braces(name: &'static str, extensions: &'static [&'static str]) -> Language {
+    Language {
+        name,
+        extensions,
+        syntax: Syntax::Braces,
+        line_comments: &["//"],
+        block_comments: true,
+        backtick_strings: false,
+        rust_lifetimes: false,
+    }
+}
+
+/// Langages reconnus par le processeur de code
+pub const LANGUAGES: &[Language] = &[
+    Language { rust_lifetimes: true, ..braces("rust", &["rs"]) },
+    Language { backtick_strings: true, ..braces("javascript", &["js", "jsx", "mjs", "cjs"]) },
+    Language { backtick_strings: true, ..braces("typescript", &["ts", "tsx"]) },
+    Language { backtick_strings: true, ..braces("go", &["go"]) },
+    braces("java", &["java"]),
+    braces("kotlin", &["kt", "kts"]),
+    braces("scala", &["scala"]),
+    braces("swift", &["swift"]),
+    braces("c", &["c", "h"]),
+    braces("cpp", &["cpp", "cc", "cxx", "hpp", "hh", "hxx"]),
+    braces("csharp", &["cs"]),
+    Language { line_comments: &["//", "#"], ..braces("php", &["php"]) },
+    Language {
+        line_comments: &["#"],
+        block_comments: false,
+        backtick_strings: true,
+        ..braces("shell", &["sh", "bash"])
+    },
+    Language {
+        syntax: Syntax::Indentation,
+        line_comments: &["#"],
+        block_comments: false,
+        ..braces("python", &["py", "pyw"])
+    },
+    Language {
+        syntax: Syntax::Indentation,
+        line_comments: &["#"],
+        block_comments: false,
+        ..braces("ruby", &["rb"])
+    },
+];
+
+/// Langage associé à une extension de fichier
+pub fn language_for_extension(extension: &str) -> Option<&'static Language> {
+    let extension = extension.to_lowercase();
+    LANGUAGES.iter().find(|language| language.extensions.contains(&extension.as_str()))
+}
+
+/// Élément de premier niveau (fonction, classe, bloc impl, ...) ou groupe d'instructions
+#[derive(Debug, Clone)]
+pub struct CodeItem {
+    /// Nature de l'élément (`function`, `struct`, `class`, `impl`, ...), `None` pour des instructions
+    pub kind: Option<&'static str>,
+    pub symbol: Option<String>,
+    /// Lignes de début et de fin (à partir de 1, incluses)
+    pub start_line: usize,
+    pub end_line: usize,
+    /// Commentaire de documentation rattaché à l'élément
+    pub doc: Option<String>,
+}
+
+/// Analyseur lexical minimal : suit la profondeur des délimiteurs hors chaînes et commentaires
+struct Lexer<'a> {
+    language: &'a Language,
+    depth: i64,
+    state: LexState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LexState {
+    Code,
+    BlockComment,
+    Str(char),
+}
+
+impl<'a> Lexer<'a> {
+    fn new(language: &'a Language) -> Self {
+        Self { language, depth: 0, state: LexState::Code }
+    }
+
+    /// Avance d'une ligne et retourne son dernier caractère significatif (hors commentaires)
+    fn feed(&mut self, line: &str) -> Option<char> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut last = None;
+        let mut k = 0;
+
+        while k < chars.len() {
+            let c = chars[k];
+            let next = chars.get(k + 1).copied();
+
+            match self.state {
+                LexState::BlockComment => {
+                    if c == '*' && next == Some('/') {
+                        self.state = LexState::Code;
+                        k += 1;
+                    }
+                },
+                LexState::Str(delimiter) => {
+                    if c == '\\' && delimiter != '`' {
+                        k += 1;
+                    } else if c == delimiter {
+                        self.state = LexState::Code;
+                        last = Some(c);
+                    }
+                },
+                LexState::Code => {
+                    let rest: String = chars[k..].iter().take(2).collect();
+                    if self.language.line_comments.iter().any(|prefix| rest.starts_with(prefix)) {
+                        break;
+                    }
+                    if self.language.block_comments && c == '/' && next == Some('*') {
+                        self.state = LexState::BlockComment;
+                        k += 2;
+                        continue;
+                    }
+
+                    match c {
+                        '"' => self.state = LexState::Str('"'),
+                        '`' if self.language.backtick_strings => self.state = LexState::Str('`'),
+                        '\'' if self.language.rust_lifetimes => {
+                            // Caractère littéral (`'x'`, `'\n'`) ; sinon durée de vie
+                            if next == Some('\\') {
+                                if let Some(end) = chars[k + 2..].iter().position(|ch| *ch == '\'') {
+                                    k += end + 2;
+                                }
+                            } else if chars.get(k + 2) == Some(&'\'') {
+                                k += 2;
+                            }
+                        },
+                        '\'' => self.state = LexState::Str('\''),
+                        '{' | '(' | '[' => self.depth += 1,
+                        '}' | ')' | ']' => self.depth -= 1,
+                        _ => {},
+                    }
+                    if !c.is_whitespace() {
+                        last = Some(c);
+                    }
+                },
+            }
+            k += 1;
+        }
+
+        // Les chaînes simples ne traversent pas les lignes (sauf backticks et `"` en Rust/Go)
+        if self.state == LexState::Str('\'') {
+            self.state = LexState::Code;
+        }
+        last
+    }
+}
+
+/// Caractères de fin de ligne indiquant que l'instruction continue
+const CONTINUATION_CHARS: &[char] = &[',', '(', '[', '=', '+', '-', '.', '&', '|', ':', '\\', '?'];
+
+/// Découpe un fichier source en éléments de premier niveau
+fn split_items(source: &str, language: &Language) -> Vec<CodeItem> {
+    let lines: Vec<&str> = source.lines().collect();
+    match language.syntax {
+        Syntax::Braces => split_brace_items(&lines, language),
+        Syntax::Indentation => split_indented_items(&lines, language),
+    }
+}
+
+/// Ligne de commentaire, d'attribut (`#[...]`) ou de décorateur (`@...`) précédant un élément
+fn is_preamble_line(trimmed: &str, language: &Language) -> bool {
+    language.line_comments.iter().any(|prefix| trimmed.starts_with(prefix))
+        || (language.block_comments && (trimmed.starts_with("/*") || trimmed.starts_with('*')))
+        || (language.rust_lifetimes && trimmed.starts_with("#["))
+        || (trimmed.starts_with('@') && !trimmed.starts_with("@interface"))
+}
+
+fn split_brace_items(lines: &[&str], language: &Language) -> Vec<CodeItem> {
+    let mut items = Vec::new();
+    let mut lexer = Lexer::new(language);
+    let mut preamble: Option<usize> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let trimmed = lines[i].trim();
+
+        // Une ligne vide détache les commentaires de l'élément suivant
+        if trimmed.is_empty() {
+            if let Some(start) = preamble.take() {
+                items.push(make_item(lines, start, i - 1, i, language));
+            }
+            i += 1;
+            continue;
+        }
+
+        if is_preamble_line(trimmed, language) {
+            preamble.get_or_insert(i);
+            lexer.feed(lines[i]);
+            // Commentaire bloc sur plusieurs lignes
+            while lexer.state == LexState::BlockComment && i + 1 < lines.len() {
+                i += 1;
+                lexer.feed(lines[i]);
+            }
+            i += 1;
+            continue;
+        }
+
+        // Début d'un élément : consommer jusqu'au retour à la profondeur 0
+        let code_start = i;
+        let start = preamble.take().unwrap_or(i);
+        let mut end = i;
+        loop {
+            let last = lexer.feed(lines[end]);
+            if lexer.depth < 0 {
+                lexer.depth = 0;
+            }
+
+            let next_line = lines[end + 1..].iter().map(|line| line.trim()).find(|line| !line.is_empty());
+            let complete = lexer.depth == 0 && lexer.state == LexState::Code && match last {
+                Some('}') | Some(';') => !next_line.is_some_and(|next| {
+                    next.starts_with("else") || next.starts_with("catch") || next.starts_with("finally")
+                        || next.starts_with('.') || next.starts_with(')')
+                }),
+                Some(c) if CONTINUATION_CHARS.contains(&c) => false,
+                _ => !next_line.is_some_and(|next| {
+                    next.starts_with('{') || next.starts_with("where") || next.starts_with('.')
+                        || next.starts_with("->") || next.starts_with(':')
+                }),
+            };
+
+            if complete || end + 1 >= lines.len() {
+                break;
+            }
+            end += 1;
+        }
+
+        items.push(make_item(lines, start, end, code_start, language));
+        i = end + 1;
+    }
+
+    if let Some(start) = preamble {
+        items.push(make_item(lines, start, lines.len() - 1, lines.len(), language));
+    }
+    items
+}
+
+fn split_indented_items(lines: &[&str], language: &Language) -> Vec<CodeItem> {
+    let is_item_start = |line: &str| match language.name {
+        "ruby" => RUBY_ITEM.is_match(line),
+        _ => PYTHON_ITEM.is_match(line) || line.starts_with('@'),
+    };
+    let is_top_level = |line: &str| !line.is_empty() && !line.starts_with(char::is_whitespace);
+
+    let mut items = Vec::new();
+    let mut preamble: Option<usize> = None;
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            if let Some(start) = preamble.take() {
+                items.push(make_item(lines, start, i - 1, i, language));
+            }
+            i += 1;
+            continue;
+        }
+
+        if is_top_level(line) && trimmed.starts_with('#') {
+            preamble.get_or_insert(i);
+            i += 1;
+            continue;
+        }
+
+        let start = preamble.take().unwrap_or(i);
+        let item = is_item_start(line);
+        let mut in_triple_quote = false;
+        let mut end = i;
+
+        loop {
+            let current = lines[end];
+            if current.matches("\"\"\"").count() % 2 == 1 || current.matches("'''").count() % 2 == 1 {
+                in_triple_quote = !in_triple_quote;
+            }
+            if language.name == "ruby" && end > i && current.trim_end() == "end" {
+                break;
+            }
+
+            let Some(next) = lines.get(end + 1) else { break };
+            let next_trimmed = next.trim();
+            let continues = in_triple_quote
+                || next_trimmed.is_empty() && item
+                || next.starts_with(char::is_whitespace) && !next_trimmed.is_empty()
+                || next_trimmed.starts_with([')', ']', '}'])
+                || (language.name == "ruby" && item && next.trim_end() == "end")
+                || (current.starts_with('@') && end == i || lines[i..=end].iter().all(|l| l.starts_with('@')));
+            if !continues {
+                break;
+            }
+            end += 1;
+        }
+
+        // Les lignes vides finales ne font pas partie de l'élément
+        while end > i && lines[end].trim().is_empty() {
+            end -= 1;
+        }
+
+        let code_start = (i..=end).find(|&k| !lines[k].starts_with('@')).unwrap_or(i);
+        items.push(make_item(lines, start, end, code_start, language));
+        i = end + 1;
+    }
+
+    if let Some(start) = preamble {
+        items.push(make_item(lines, start, lines.len() - 1, lines.len(), language));
+    }
+    items
+}
+
+/// Construit un élément : symbole tiré de la première ligne de code, documentation des lignes précédentes
+fn make_item(lines: &[&str], start: usize, end: usize, code_start: usize, language: &Language) -> CodeItem {
+    let (kind, symbol) = if code_start <= end {
+        symbol_of(lines[code_start].trim(), language)
+    } else {
+        (None, None)
+    };
+
+    let mut doc = doc_comment(&lines[start..code_start.min(end + 1)], language);
+    if doc.is_none() && language.name == "python" && kind.is_some() {
+        doc = python_docstring(&lines[code_start..=end]);
+    }
+
+    CodeItem {
+        kind: if kind.is_none() && code_start > end { Some("comment") } else { kind },
+        symbol,
+        start_line: start + 1,
+        end_line: end + 1,
+        doc,
+    }
+}
+
+/// Texte des commentaires précédant un élément, sans marqueurs ni attributs
+fn doc_comment(lines: &[&str], language: &Language) -> Option<String> {
+    let text: Vec<String> = lines.iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.starts_with("#[") && !line.starts_with('@'))
+        .filter(|line| is_preamble_line(line, language))
+        .map(|line| {
+            let line = line.trim_start_matches("///").trim_start_matches("//!").trim_start_matches("//")
+                .trim_start_matches("/**").trim_start_matches("/*").trim_start_matches('#');
+            let line = line.trim_end_matches("*/").trim_start_matches('*');
+            line.trim().to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    if text.is_empty() { None } else { Some(text.join("\n")) }
+}
+
+/// Docstring Python : première instruction du corps si c'est une chaîne triple
+fn python_docstring(lines: &[&str]) -> Option<String> {
+    let signature_end = lines.iter().position(|line| line.trim_end().ends_with(':'))?;
+    let body: Vec<&str> = lines[signature_end + 1..].to_vec();
+    let first = body.iter().position(|line| !line.trim().is_empty())?;
+    let text = body[first..].join("\n");
+    let text = text.trim_start();
+
+    let quote = if text.starts_with("\"\"\"") { "\"\"\"" } else if text.starts_with("'''") { "'''" } else { return None };
+    let inner = &text[3..];
+    let end = inner.find(quote)?;
+    let doc = inner[..end].lines().map(str::trim).collect::<Vec<_>>().join("\n");
+    let doc = doc.trim();
+    if doc.is_empty() { None } else { Some(doc.to_string()) }
+}
+
+static RUST_ITEM: LazyLock<Regex> = LazyLock::new(|| Regex::new(
+    r#"^(?:pub(?:\([^)]*\))?\s+)?(?:(?:async|const|unsafe|default|extern(?:\s+"[^"]*")?)\s+)*(fn|struct|enum|union|trait|mod|type|static|const|macro_rules!)\s*([A-Za-z_][A-Za-z0-9_]*)"#
+).unwrap());
+static RUST_IMPL: LazyLock<Regex> = LazyLock::new(|| Regex::new(
+    r"^(?:unsafe\s+)?impl\b(?:\s*<[^{]*?>)?\s+([^{]+?)\s*(?:\{|\bwhere\b|$)"
+).unwrap());
+static PYTHON_ITEM: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:async\s+)?(def|class)\s+([A-Za-z_]\w*)").unwrap());
+static RUBY_ITEM: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(def|class|module)\s+(?:self\.)?([A-Za-z_][\w:.?!=]*)").unwrap());
+static JS_ITEM: LazyLock<Regex> = LazyLock::new(|| Regex::new(
+    r"^(?:export\s+)?(?:default\s+)?(?:declare\s+)?(?:abstract\s+)?(?:async\s+)?(function\*?|class|interface|type|enum|namespace|const|let|var)\s+([A-Za-z_$][\w$]*)"
+).unwrap());
+static GO_FUNC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^func\s+(\([^)]*\)\s*)?([A-Za-z_]\w*)").unwrap());
+static GO_DECL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(type|var|const)\s+([A-Za-z_]\w*)(?:\s+(struct|interface)\b)?").unwrap());
+static SHELL_FUNC: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:function\s+)?([A-Za-z_][\w-]*)\s*\(\)").unwrap());
+static KEYWORD_ITEM: LazyLock<Regex> = LazyLock::new(|| Regex::new(
+    r"\b(class|interface|enum|record|struct|object|trait|protocol|extension|namespace|fun|func|function|def)\s+([A-Za-z_]\w*)"
+).unwrap());
+static C_FUNCTION: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"([A-Za-z_~][\w:~]*)\s*\(").unwrap());
+
+/// Mots-clés suivis d'une parenthèse qui ne sont pas des noms de fonctions
+const NOT_FUNCTIONS: &[&str] = &["if", "for", "while", "switch", "return", "sizeof", "catch", "defined", "elif"];
+
+/// Nature normalisée d'un mot-clé de déclaration
+fn kind_of(keyword: &str) -> &'static str {
+    match keyword {
+        "fn" | "def" | "fun" | "func" | "function" | "function*" => "function",
+        "struct" => "struct",
+        "enum" => "enum",
+        "union" => "union",
+        "trait" => "trait",
+        "interface" => "interface",
+        "class" => "class",
+        "record" => "record",
+        "object" => "object",
+        "protocol" => "protocol",
+        "extension" => "extension",
+        "type" => "type",
+        "mod" | "module" | "namespace" => "module",
+        "static" => "static",
+        "let" | "var" => "variable",
+        "macro_rules!" => "macro",
+        _ => "constant",
+    }
+}
+
+/// Nature et nom du symbole déclaré par une ligne
+fn symbol_of(line: &str, language: &Language) -> (Option<&'static str>, Option<String>) {
+    let keyword_match = |regex: &Regex| regex.captures(line)
+        .map(|caps| (Some(kind_of(&caps[1])), Some(caps[2].to_string())));
+
+    let found = match language.name {
+        "rust" => keyword_match(&RUST_ITEM).or_else(|| RUST_IMPL.captures(line)
+            .map(|caps| (Some("impl"), Some(caps[1].trim().to_string())))),
+        "python" => keyword_match(&PYTHON_ITEM),
+        "ruby" => keyword_match(&RUBY_ITEM),
+        "javascript" | "typescript" => keyword_match(&JS_ITEM),
+        "go" => GO_FUNC.captures(line)
+            .map(|caps| (Some(if caps.get(1).is_some() { "method" } else { "function" }), Some(caps[2].to_string())))
+            .or_else(|| GO_DECL.captures(line).map(|caps| {
+                let kind = match (&caps[1], caps.get(3).map(|m| m.as_str())) {
+                    ("type", Some(inner)) => kind_of(inner),
+                    (keyword, _) => kind_of(keyword),
+                };
+                (Some(kind), Some(caps[2].to_string()))
+            })),
+        "shell" => SHELL_FUNC.captures(line).map(|caps| (Some("function"), Some(caps[1].to_string()))),
+        _ => keyword_match(&KEYWORD_ITEM),
+    };
+
+    found.or_else(|| {
+        // Signature de fonction de style C (`int main(void)`, `public void run()`)
+        if matches!(language.syntax, Syntax::Braces) && !line.trim_end().ends_with(';') {
+            C_FUNCTION.captures_iter(line)
+                .map(|caps| caps[1].to_string())
+                .find(|name| !NOT_FUNCTIONS.contains(&name.as_str()))
+                .map(|name| (Some("function"), Some(name)))
+        } else {
+            None
+        }
+    }).unwrap_or((None, None))
+}
+
+/// Segment à émettre : un ou plusieurs éléments consécutifs
+struct Segment {
+    items: Vec<CodeItem>,
+}
+
+impl Segment {
+    fn start_line(&self) -> usize {
+        self.items[0].start_line
+    }
+
+    fn end_line(&self) -> usize {
+        self.items[self.items.len() - 1].end_line
+    }
+}
+
+pub struct CodeProcessor;
+
+impl CodeProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Langage déduit de l'extension du nom de fichier
+    fn language_of(&self, filename: &str) -> Result<&'static Language, DocLoaderError> {
+        Path::new(filename)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(language_for_extension)
+            .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!("Unknown source code extension: {}", filename)))
+    }
+
+    /// Lit un fichier source
+    fn read_source_file(&self, file_path: &Path) -> Result<String, DocLoaderError> {
+        let bytes = fs::read(file_path)?;
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
+    /// Construit la sortie universelle : un chunk par élément de premier niveau.
+    ///
+    /// Le nettoyage de texte n'est pas appliqué : il détruirait l'indentation.
+    fn build_output(
+        &self,
+        source: &str,
+        language: &Language,
+        document_metadata: impl FnOnce(serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let source = normalize_line_breaks(source);
+        let lines: Vec<&str> = source.lines().collect();
+        let mut line_starts = Vec::with_capacity(lines.len() + 1);
+        let mut offset = 0;
+        for line in &lines {
+            line_starts.push(offset);
+            offset += line.len() + 1;
+        }
+        line_starts.push(source.len() + 1);
+
+        let items = split_items(&source, language);
+        let slice = |start_line: usize, end_line: usize| -> (usize, &str) {
+            let start = line_starts[start_line - 1];
+            let end = (line_starts[end_line] - 1).min(source.len());
+            (start, &source[start..end])
+        };
+
+        // Regrouper les instructions sans symbole consécutives (imports, constantes, ...)
+        let mut segments: Vec<Segment> = Vec::new();
+        for item in &items {
+            if item.symbol.is_none() {
+                if let Some(last) = segments.last_mut() {
+                    let (_, text) = slice(last.start_line(), item.end_line);
+                    if last.items.iter().all(|i| i.symbol.is_none()) && text.len() <= params.max_chunk_size {
+                        last.items.push(item.clone());
+                        continue;
+                    }
+                }
+            }
+            segments.push(Segment { items: vec![item.clone()] });
+        }
+
+        let mut chunks = Vec::new();
+        for segment in &segments {
+            let (segment_offset, segment_text) = slice(segment.start_line(), segment.end_line());
+            let item = &segment.items[0];
+            let kind = if segment.items.len() > 1 { Some("statements") } else { item.kind.or(Some("statements")) };
+
+            // Un élément trop long est découpé entre deux lignes
+            let mut parts: Vec<(usize, usize)> = Vec::new();
+            let mut part_start = segment.start_line();
+            for line in segment.start_line()..=segment.end_line() {
+                let (_, text) = slice(part_start, line);
+                if text.len() > params.max_chunk_size && line > part_start {
+                    parts.push((part_start, line - 1));
+                    part_start = line;
+                }
+            }
+            parts.push((part_start, segment.end_line()));
+
+            for (part_index, &(start_line, end_line)) in parts.iter().enumerate() {
+                let (part_offset, part_text) = slice(start_line, end_line);
+
+                // Une ligne isolée plus longue que la taille maximale est découpée en caractères
+                let pieces = if part_text.len() > params.max_chunk_size {
+                    chunk_text(part_text, params.max_chunk_size, params.chunk_overlap)
+                } else {
+                    vec![part_text.to_string()]
+                };
+
+                for piece in pieces {
+                    if piece.trim().is_empty() {
+                        continue;
+                    }
+                    let text_meta = extract_text_metadata(&piece);
+                    let start_offset = part_offset + part_text.find(&piece).unwrap_or(0);
+                    let index = chunks.len();
+
+                    chunks.push(DocumentChunk {
+                        id: format!("code_chunk_{}", index),
+                        content: piece.clone(),
+                        chunk_index: index,
+                        position: ChunkPosition {
+                            page: None,
+                            line: Some(start_line as u32),
+                            start_offset: Some(start_offset),
+                            end_offset: Some(start_offset + piece.len()),
+                        },
+                        metadata: ChunkMetadata {
+                            size: piece.len(),
+                            language: None, // Langue naturelle non pertinente pour du code
+                            confidence: Some(if segment_text.is_empty() { 0.0 } else { 0.9 }), // Découpage heuristique
+                            format_specific: Some(json!({
+                                "language": language.name,
+                                "symbol": item.symbol,
+                                "symbol_kind": kind,
+                                "start_line": start_line,
+                                "end_line": end_line,
+                                "part_index": part_index,
+                                "part_count": parts.len(),
+                                "doc_comment": item.doc,
+                                "item_offset": segment_offset,
+                                "estimated_tokens": text_meta.estimated_tokens
+                            })),
+                        },
+                    });
+                }
+            }
+        }
+
+        let symbols: Vec<serde_json::Value> = items.iter()
+            .filter(|item| item.symbol.is_some())
+            .map(|item| json!({
+                "name": item.symbol,
+                "kind": item.kind,
+                "start_line": item.start_line,
+                "end_line": item.end_line,
+                "documented": item.doc.is_some()
+            }))
+            .collect();
+
+        let text_meta = extract_text_metadata(&source);
+        let format_metadata = json!({
+            "code_metadata": {
+                "language": language.name,
+                "line_count": lines.len(),
+                "item_count": items.len(),
+                "symbol_count": symbols.len(),
+                "symbols": symbols
+            },
+            "total_characters": text_meta.character_count,
+            "estimated_tokens": text_meta.estimated_tokens
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "CodeProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: source.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+impl DocumentProcessor for CodeProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::Code
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Déterminer le langage et lire le fichier
+        let language = self.language_of(&file_path.to_string_lossy())?;
+        let source = self.read_source_file(file_path)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&source, language, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::Code,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Déterminer le langage depuis le nom de fichier
+        let language = self.language_of(filename)?;
+        let source = String::from_utf8_lossy(content);
+
+        Ok(self.build_output(&source, language, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::Code,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for CodeProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUST_SOURCE: &str = r#"//! Module de démonstration
+
+use std::fmt;
+use std::collections::HashMap;
+
+/// Un point du plan
+#[derive(Debug, Clone)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}) {{", self.x, self.y)
+    }
+}
+
+/// Calcule la distance
+pub fn distance(a: &Point, b: &Point) -> f64
+where
+{
+    let c = '}';
+    0.0
+}
+"#;
+
+    #[test]
+    fn test_code_processor_creation() {
+        let processor = CodeProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "CODE");
+        assert_eq!(language_for_extension("PY").unwrap().name, "python");
+    }
+
+    #[test]
+    fn test_split_rust_items() {
+        let language = language_for_extension("rs").unwrap();
+        let items = split_items(RUST_SOURCE, language);
+        let symbols: Vec<(Option<&str>, Option<&str>, usize, usize)> = items.iter()
+            .map(|item| (item.kind, item.symbol.as_deref(), item.start_line, item.end_line))
+            .collect();
+
+        assert_eq!(symbols, vec![
+            (Some("comment"), None, 1, 1),
+            (None, None, 3, 3),
+            (None, None, 4, 4),
+            (Some("struct"), Some("Point"), 6, 11),
+            (Some("impl"), Some("fmt::Display for Point"), 13, 17),
+            (Some("function"), Some("distance"), 19, 25),
+        ]);
+        assert_eq!(items[3].doc.as_deref(), Some("Un point du plan"));
+    }
+
+    #[test]
+    fn test_split_python_items() {
+        let source = "import os\n\n\n@dataclass\nclass User:\n    \"\"\"A user.\"\"\"\n\n    name: str\n\n\n# Helper\ndef greet(user):\n    return f\"hi {user.name}\"\n";
+        let language = language_for_extension("py").unwrap();
+        let items = split_items(source, language);
+
+        assert_eq!(items.len(), 3);
+        assert_eq!((items[1].kind, items[1].symbol.as_deref()), (Some("class"), Some("User")));
+        assert_eq!((items[1].start_line, items[1].end_line), (4, 8));
+        assert_eq!(items[1].doc.as_deref(), Some("A user."));
+        assert_eq!((items[2].symbol.as_deref(), items[2].start_line), (Some("greet"), 11));
+        assert_eq!(items[2].doc.as_deref(), Some("Helper"));
+    }
+
+    #[test]
+    fn test_process_content() {
+        let processor = CodeProcessor::new();
+        let output = processor.process_content(RUST_SOURCE.as_bytes(), "point.rs", &ProcessingParams::default()).unwrap();
+
+        assert_eq!(output.document_metadata.format_metadata["code_metadata"]["symbol_count"], 3);
+        let chunk = output.chunks.iter()
+            .find(|chunk| chunk.metadata.format_specific.as_ref().unwrap()["symbol"] == "distance")
+            .unwrap();
+        assert!(chunk.content.starts_with("/// Calcule la distance"));
+        assert_eq!(chunk.position.line, Some(19));
+
+        let imports = &output.chunks[0];
+        assert_eq!(imports.content, "//! Module de démonstration\n\nuse std::fmt;\nuse std::collections::HashMap;");
+        assert_eq!(imports.metadata.format_specific.as_ref().unwrap()["symbol_kind"], "statements");
+
+        assert!(processor.process_content(b"x", "notes.unknown", &ProcessingParams::default()).is_err());
+    }
+}
@@ -0,0 +1,436 @@
+//! Décompression transparente des entrées gzip, bzip2, xz et zstd (`data.csv.gz`, `logs.txt.xz`, ...).
+//!
+//! Le format est détecté par suffixe et confirmé par la signature (un fichier `.gz` sans signature
+//! est traité tel quel) ; le contenu est décompressé en flux et confié au processeur du format
+//! détecté sur ses premiers octets, l'extension interne servant d'indice.
+
+use crate::core::{DocumentType, ProcessingParams, UniversalOutput};
+use crate::error::DocLoaderError;
+use crate::processors::{detect, UniversalProcessor};
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, Cursor, Read, Take};
+use std::path::Path;
+use serde::Deserialize;
+use serde_json::json;
+
+/// Options de décompression, lues depuis `params.format_specific["compression"]`
+///
+/// ```json
+/// { "compression": { "max_decompressed_size": 1073741824 } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CompressionOptions {
+    /// Taille décompressée maximale (protection contre les bombes de décompression), y compris
+    /// pour les CSV et JSON Lines lus en flux
+    pub max_decompressed_size: u64,
+}
+
+impl Default for CompressionOptions {
+    fn default() -> Self {
+        Self {
+            max_decompressed_size: 1024 * 1024 * 1024,
+        }
+    }
+}
+
+impl CompressionOptions {
+    pub fn from_params(params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        params.format_options("compression")
+    }
+}
+
+/// Format de compression d'un flux
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Compression {
+    Gzip,
+    Bzip2,
+    Xz,
+    Zstd,
+}
+
+impl Compression {
+    /// Compression indiquée par le suffixe, avec le nom interne (`data.csv.gz` → `data.csv`)
+    pub fn from_name(name: &str) -> Option<(Self, String)> {
+        let lower = name.to_lowercase();
+        [
+            (".gz", Compression::Gzip),
+            (".gzip", Compression::Gzip),
+            (".bz2", Compression::Bzip2),
+            (".xz", Compression::Xz),
+            (".zst", Compression::Zstd),
+            (".zstd", Compression::Zstd),
+        ]
+        .into_iter()
+        .find(|(suffix, _)| lower.ends_with(suffix) && lower.len() > suffix.len())
+        .map(|(suffix, compression)| (compression, name[..name.len() - suffix.len()].to_string()))
+    }
+
+    /// Compression reconnue par sa signature
+    pub fn from_magic(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0x1f, 0x8b]) {
+            Some(Compression::Gzip)
+        } else if bytes.len() >= 4 && bytes.starts_with(b"BZh") && (b'1'..=b'9').contains(&bytes[3]) {
+            Some(Compression::Bzip2)
+        } else if bytes.starts_with(&[0xfd, b'7', b'z', b'X', b'Z', 0x00]) {
+            Some(Compression::Xz)
+        } else if bytes.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Some(Compression::Zstd)
+        } else {
+            None
+        }
+    }
+
+    pub fn to_string(&self) -> &'static str {
+        match self {
+            Compression::Gzip => "gzip",
+            Compression::Bzip2 => "bzip2",
+            Compression::Xz => "xz",
+            Compression::Zstd => "zstd",
+        }
+    }
+
+    /// Décodeur en flux ; les flux concaténés (gzip, bzip2, xz multi-membres) sont lus en entier
+    pub fn decoder<'a, R: Read + 'a>(&self, reader: R) -> Result<Box<dyn Read + 'a>, DocLoaderError> {
+        Ok(match self {
+            Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            Compression::Bzip2 => Box::new(bzip2::read::MultiBzDecoder::new(reader)),
+            Compression::Xz => Box::new(liblzma::read::XzDecoder::new_multi_decoder(reader)),
+            Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(reader)
+                .map_err(|e| DocLoaderError::Decompression(format!("Failed to initialise zstd decoder: {}", e)))?),
+        })
+    }
+
+    /// Décompresse un flux en mémoire, sans dépasser `max_size` octets
+    pub fn decompress(&self, reader: impl Read, max_size: u64) -> Result<Vec<u8>, DocLoaderError> {
+        let mut bytes = Vec::new();
+        self.decoder(reader)?
+            .take(max_size + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| DocLoaderError::Decompression(format!("Invalid {} stream: {}", self.to_string(), e)))?;
+
+        if bytes.len() as u64 > max_size {
+            return Err(DocLoaderError::Decompression(format!(
+                "Decompressed {} stream exceeds {} bytes (possible decompression bomb)", self.to_string(), max_size
+            )));
+        }
+        Ok(bytes)
+    }
+}
+
+/// Lit en mémoire un flux décompressé, sans dépasser `max_decompressed_size` octets
+pub fn read_decompressed(reader: &mut dyn Read, filename: &str, params: &ProcessingParams) -> Result<Vec<u8>, DocLoaderError> {
+    let mut reader = BoundedReader::new(reader, params)?;
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    reader.check(filename)?;
+    Ok(bytes)
+}
+
+/// Flux décompressé borné à `max_decompressed_size` octets : la lecture s'arrête un octet
+/// au-delà de la limite, et `check` signale alors le dépassement
+pub(crate) struct BoundedReader<R> {
+    reader: Take<R>,
+    max_size: u64,
+}
+
+impl<R: Read> BoundedReader<R> {
+    pub(crate) fn new(reader: R, params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        let max_size = CompressionOptions::from_params(params)?.max_decompressed_size;
+        Ok(Self { reader: reader.take(max_size.saturating_add(1)), max_size })
+    }
+
+    /// Nombre d'octets lus jusqu'ici
+    pub(crate) fn bytes_read(&self) -> u64 {
+        self.max_size.saturating_add(1) - self.reader.limit()
+    }
+
+    /// Nombre d'octets lus, ou une erreur si le flux dépasse la limite
+    pub(crate) fn check(&self, filename: &str) -> Result<u64, DocLoaderError> {
+        let bytes_read = self.bytes_read();
+        if bytes_read > self.max_size {
+            return Err(DocLoaderError::Decompression(format!(
+                "Decompressed content of {} exceeds {} bytes (possible decompression bomb)", filename, self.max_size
+            )));
+        }
+        Ok(bytes_read)
+    }
+}
+
+impl<R: Read> Read for BoundedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.reader.read(buf)
+    }
+}
+
+/// Flux décompressé : compte les octets produits et retient l'erreur du décodeur, pour la
+/// signaler même si le processeur la transforme en erreur d'entrée-sortie
+struct DecompressedReader<'a> {
+    decoder: Box<dyn Read + 'a>,
+    compression: Compression,
+    size: u64,
+    error: Option<String>,
+}
+
+impl Read for DecompressedReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.decoder.read(buf) {
+            Ok(read) => {
+                self.size += read as u64;
+                Ok(read)
+            },
+            Err(e) => {
+                let message = format!("Invalid {} stream: {}", self.compression.to_string(), e);
+                self.error = Some(message.clone());
+                Err(io::Error::new(e.kind(), message))
+            },
+        }
+    }
+}
+
+/// Compression d'un fichier : suffixe confirmé par la signature, ou signature seule si l'extension est inconnue
+pub fn detect_compression(name: &str, head: &[u8]) -> Option<(Compression, String)> {
+    let magic = Compression::from_magic(head);
+    match Compression::from_name(name) {
+        // La signature prévaut sur un suffixe trompeur (`.gz` contenant du zstd) ; sans signature,
+        // le fichier n'est pas compressé
+        Some((_, inner)) => magic.map(|compression| (compression, inner)),
+        None => {
+            let known_extension = Path::new(name)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(DocumentType::from_extension)
+                .is_some();
+            if known_extension { None } else { magic.map(|compression| (compression, name.to_string())) }
+        },
+    }
+}
+
+/// Décompresse un fichier en flux et le confie au processeur du format détecté sur ses premiers octets
+pub(crate) fn process_compressed_file(
+    processor: &UniversalProcessor,
+    file_path: &Path,
+    compression: Compression,
+    inner_name: &str,
+    params: &ProcessingParams,
+) -> Result<UniversalOutput, DocLoaderError> {
+    let inner_filename = Path::new(inner_name)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(inner_name)
+        .to_string();
+    let registry = processor.registry();
+    let hint = Path::new(&inner_filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(|ext| registry.type_for_extension(ext));
+
+    // Les options sont validées même si le format est ensuite lu en flux
+    CompressionOptions::from_params(params)?;
+    let mut stream = DecompressedReader {
+        decoder: compression.decoder(BufReader::new(File::open(file_path)?))?,
+        compression,
+        size: 0,
+        error: None,
+    };
+    let mut head = Vec::with_capacity(detect::HEAD_SIZE);
+    let read = (&mut stream).take(detect::HEAD_SIZE as u64).read_to_end(&mut head);
+    if let Some(message) = stream.error.take() {
+        return Err(DocLoaderError::Decompression(message));
+    }
+    read?;
+
+    // Comme pour un fichier ordinaire, le contenu fait foi et l'extension interne n'est qu'un
+    // indice : `logs.gz` ou `blob` (sans extension) sont reconnus après décompression
+    let doc_type = registry.sniff(&head)
+        .or_else(|| detect::detect_stream_type(&head, hint))
+        .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!(
+            "Could not detect the format inside {} file: {}", compression.to_string(), inner_filename
+        )))?;
+
+    // Le début déjà lu est rejoué devant le reste du flux
+    let result = registry.processor(&doc_type)?
+        .process_decompressed(&mut Cursor::new(head).chain(&mut stream), &inner_filename, params, registry);
+    if let Some(message) = stream.error.take() {
+        return Err(DocLoaderError::Decompression(message));
+    }
+    let mut output = result?;
+
+    // Le document reste celui du disque ; le contenu décompressé est décrit dans les métadonnées
+    let file_metadata = fs::metadata(file_path)?;
+    let metadata = &mut output.document_metadata;
+    metadata.filename = file_path.file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(&inner_filename)
+        .to_string();
+    metadata.filepath = file_path.to_string_lossy().to_string();
+    metadata.file_size = file_metadata.len();
+    metadata.created_at = file_metadata.created().ok().map(|t| t.into());
+    metadata.modified_at = file_metadata.modified().ok().map(|t| t.into());
+    if let Some(format_metadata) = metadata.format_metadata.as_object_mut() {
+        format_metadata.insert("compression".to_string(), json!({
+            "type": compression.to_string(),
+            "inner_filename": inner_filename,
+            "compressed_size": file_metadata.len(),
+            "decompressed_size": stream.size
+        }));
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const CSV: &[u8] = b"name,city\nAda,London\nAlan,Wilmslow\n";
+
+    fn compress(compression: Compression, content: &[u8]) -> Vec<u8> {
+        match compression {
+            Compression::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(content).unwrap();
+                encoder.finish().unwrap()
+            },
+            Compression::Bzip2 => {
+                let mut encoder = bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+                encoder.write_all(content).unwrap();
+                encoder.finish().unwrap()
+            },
+            Compression::Xz => {
+                let mut encoder = liblzma::write::XzEncoder::new(Vec::new(), 6);
+                encoder.write_all(content).unwrap();
+                encoder.finish().unwrap()
+            },
+            Compression::Zstd => zstd::stream::encode_all(content, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_detect_compression() {
+        assert_eq!(Compression::from_name("data.csv.GZ"), Some((Compression::Gzip, "data.csv".to_string())));
+        assert_eq!(Compression::from_name("logs.txt.zst"), Some((Compression::Zstd, "logs.txt".to_string())));
+        assert_eq!(Compression::from_name(".gz"), None);
+
+        // La signature l'emporte sur le suffixe ; une extension connue sans suffixe n'est pas sondée
+        let zstd = compress(Compression::Zstd, CSV);
+        assert_eq!(detect_compression("data.csv.gz", &zstd), Some((Compression::Zstd, "data.csv".to_string())));
+        assert_eq!(detect_compression("data.csv", &zstd), None);
+        assert_eq!(detect_compression("export", &zstd), Some((Compression::Zstd, "export".to_string())));
+
+        // Un suffixe sans signature correspondante désigne un fichier non compressé
+        assert_eq!(detect_compression("plain.txt.gz", b"hello"), None);
+    }
+
+    #[test]
+    fn test_round_trip_all_formats() {
+        for compression in [Compression::Gzip, Compression::Bzip2, Compression::Xz, Compression::Zstd] {
+            let compressed = compress(compression, CSV);
+            assert_eq!(Compression::from_magic(&compressed), Some(compression));
+            assert_eq!(compression.decompress(&compressed[..], 1024).unwrap(), CSV);
+        }
+    }
+
+    #[test]
+    fn test_decompression_limit() {
+        let compressed = compress(Compression::Gzip, &vec![b'a'; 10_000]);
+        let result = Compression::Gzip.decompress(&compressed[..], 1000);
+        assert!(matches!(result, Err(DocLoaderError::Decompression(message)) if message.contains("exceeds")));
+
+        let params = ProcessingParams::default().with_format_specific(json!({ "compression": { "max_decompressed_size": "1 GiB" } }));
+        assert!(matches!(CompressionOptions::from_params(&params), Err(DocLoaderError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_process_compressed_file() {
+        let dir = std::env::temp_dir().join(format!("doc_loader_compression_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("people.csv.xz");
+        fs::write(&path, compress(Compression::Xz, CSV)).unwrap();
+
+        let output = UniversalProcessor::new().process_file(&path, None);
+        fs::remove_dir_all(&dir).unwrap();
+        let output = output.unwrap();
+
+        assert_eq!(output.document_metadata.document_type, DocumentType::CSV);
+        assert_eq!(output.document_metadata.filename, "people.csv.xz");
+        let compression = &output.document_metadata.format_metadata["compression"];
+        assert_eq!(compression["type"], "xz");
+        assert_eq!(compression["inner_filename"], "people.csv");
+        assert_eq!(compression["decompressed_size"], CSV.len());
+    }
+
+    #[test]
+    fn test_inner_format_detection() {
+        let dir = std::env::temp_dir().join(format!("doc_loader_compression_detect_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let processor = UniversalProcessor::new();
+        let process = |name: &str, content: &[u8]| {
+            let path = dir.join(name);
+            fs::write(&path, content).unwrap();
+            processor.process_file(&path, None).unwrap()
+        };
+
+        // Sans extension interne, le format est reconnu sur le contenu décompressé
+        let logs = process("logs.gz", &compress(Compression::Gzip, b"{\"events\": [{\"level\": \"info\"}, {\"level\": \"warn\"}]}"));
+        let blob = process("blob", &compress(Compression::Gzip, CSV));
+        // Un `.gz` en clair est traité selon son extension interne
+        let plain = process("plain.txt.gz", b"Hello, world");
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(logs.document_metadata.document_type, DocumentType::JSON);
+        assert!(logs.chunks[0].content.contains("warn"));
+        assert_eq!(logs.document_metadata.format_metadata["compression"]["inner_filename"], "logs");
+        assert_eq!(blob.document_metadata.document_type, DocumentType::CSV);
+        assert_eq!(plain.document_metadata.document_type, DocumentType::TXT);
+        assert!(plain.document_metadata.format_metadata.get("compression").is_none());
+        assert!(plain.chunks[0].content.contains("Hello, world"));
+    }
+
+    #[test]
+    fn test_streamed_formats() {
+        let dir = std::env::temp_dir().join(format!("doc_loader_compression_stream_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let processor = UniversalProcessor::new();
+        let params = ProcessingParams::default().with_format_specific(json!({ "compression": { "max_decompressed_size": 64 } }));
+        let process = |name: &str, content: &[u8]| {
+            let path = dir.join(name);
+            fs::write(&path, compress(Compression::Gzip, content)).unwrap();
+            processor.process_file(&path, Some(params.clone()))
+        };
+
+        // CSV et JSON Lines sont lus en flux, dans la même limite que les contenus lus en mémoire
+        let csv = process("people.csv.gz", CSV);
+        let events = process("events.jsonl.gz", b"{\"level\":\"info\"}\n{\"level\":\"warn\"}\n");
+        let text = process("notes.txt.gz", b"Far more than sixty-four bytes of text, which is the configured limit.");
+        let bomb = process("bomb.csv.gz", &CSV.repeat(1000));
+        let lines_bomb = process("bomb.jsonl.gz", &b"{\"level\":\"info\"}\n".repeat(1000));
+        let corrupt = {
+            let path = dir.join("broken.csv.gz");
+            let mut bytes = compress(Compression::Gzip, CSV);
+            let middle = bytes.len() / 2;
+            bytes[middle..].fill(0);
+            fs::write(&path, bytes).unwrap();
+            processor.process_file(&path, None)
+        };
+        fs::remove_dir_all(&dir).unwrap();
+
+        let csv = csv.unwrap();
+        assert_eq!(csv.document_metadata.document_type, DocumentType::CSV);
+        assert!(csv.chunks[0].content.contains("Wilmslow"));
+        assert_eq!(csv.document_metadata.format_metadata["compression"]["decompressed_size"], CSV.len());
+        assert_eq!(events.unwrap().chunks.len(), 2);
+        for result in [text, bomb, lines_bomb] {
+            assert!(matches!(result, Err(DocLoaderError::Decompression(message)) if message.contains("exceeds 64 bytes")));
+        }
+
+        // Appelé directement, le processeur renseigne la taille lue
+        let registry = processor.registry();
+        let output = registry.processor(&DocumentType::CSV).unwrap()
+            .process_decompressed(&mut &CSV[..], "people.csv", &params, registry).unwrap();
+        assert_eq!(output.document_metadata.file_size, CSV.len() as u64);
+        assert!(matches!(corrupt, Err(DocLoaderError::Decompression(message)) if message.contains("Invalid gzip stream")));
+    }
+}
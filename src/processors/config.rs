@@ -0,0 +1,729 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::processors::json::JsonProcessor;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata};
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::fs;
+use chrono::Utc;
+use serde_json::{json, Map, Number, Value};
+use toml_edit::{ImDocument, Item, TableLike};
+use yaml_rust2::parser::{Event, MarkedEventReceiver, Parser, Tag};
+use yaml_rust2::scanner::{Marker, TScalarStyle};
+use yaml_rust2::Yaml;
+
+/// Nombre maximal de nœuds YAML, alias développés compris (protection contre les « billion laughs »)
+const MAX_YAML_NODES: usize = 1_000_000;
+
+/// Document logique issu d'un fichier de configuration
+#[derive(Debug, Clone)]
+pub struct ConfigDocument {
+    pub value: Value,
+    /// Ligne source (à partir de 1) de chaque clé, indexée par chemin (`server.port`, `hosts[0]`)
+    pub key_lines: BTreeMap<String, usize>,
+    /// Ligne de début du document dans le flux
+    pub start_line: usize,
+}
+
+impl ConfigDocument {
+    /// Ligne d'une clé, ou à défaut la première ligne d'une de ses sous-clés
+    fn line_of(&self, path: &str) -> Option<usize> {
+        self.key_lines.get(path).copied().or_else(|| {
+            self.key_lines.iter()
+                .filter(|(key, _)| is_descendant(key, path))
+                .map(|(_, line)| *line)
+                .min()
+        })
+    }
+
+    /// Entrées de premier niveau `(chemin, valeur)`
+    fn top_level_entries(&self) -> Vec<(String, &Value)> {
+        match &self.value {
+            Value::Object(map) => map.iter().map(|(key, value)| (key.clone(), value)).collect(),
+            Value::Array(items) => items.iter().enumerate().map(|(i, value)| (format!("[{}]", i), value)).collect(),
+            other => vec![(String::new(), other)],
+        }
+    }
+}
+
+/// `path` est-il `parent` ou l'un de ses descendants ?
+fn is_descendant(path: &str, parent: &str) -> bool {
+    parent.is_empty()
+        || path == parent
+        || path.strip_prefix(parent).is_some_and(|rest| rest.starts_with('.') || rest.starts_with('['))
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Profondeur d'une valeur (0 pour un scalaire)
+fn value_depth(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => 1 + map.values().map(value_depth).max().unwrap_or(0),
+        Value::Array(items) => 1 + items.iter().map(value_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn value_nodes(value: &Value) -> usize {
+    match value {
+        Value::Object(map) => 1 + map.values().map(value_nodes).sum::<usize>(),
+        Value::Array(items) => 1 + items.iter().map(value_nodes).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+fn float_value(value: f64, raw: &str) -> Value {
+    Number::from_f64(value).map(Value::Number).unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+// --- YAML ---
+
+/// Conteneur YAML en cours de construction
+enum Frame {
+    Sequence { items: Vec<Value>, anchor: usize, path: String },
+    Mapping { entries: Map<String, Value>, anchor: usize, path: String, pending_key: Option<String> },
+}
+
+/// Construit des valeurs JSON à partir des événements YAML, en notant la ligne de chaque clé
+#[derive(Default)]
+struct YamlBuilder {
+    documents: Vec<ConfigDocument>,
+    stack: Vec<Frame>,
+    anchors: HashMap<usize, Value>,
+    key_lines: BTreeMap<String, usize>,
+    root: Option<Value>,
+    start_line: usize,
+    nodes: usize,
+    error: Option<String>,
+}
+
+impl YamlBuilder {
+    /// La prochaine valeur reçue est-elle une clé de mapping ?
+    fn expects_key(&self) -> bool {
+        matches!(self.stack.last(), Some(Frame::Mapping { pending_key: None, .. }))
+    }
+
+    /// Chemin de la prochaine valeur
+    fn child_path(&self) -> String {
+        match self.stack.last() {
+            None => String::new(),
+            Some(Frame::Sequence { items, path, .. }) => format!("{}[{}]", path, items.len()),
+            Some(Frame::Mapping { path, pending_key, .. }) => join_path(path, pending_key.as_deref().unwrap_or("?")),
+        }
+    }
+
+    /// Note la ligne d'un élément de séquence
+    fn record_item_line(&mut self, mark: &Marker) {
+        if matches!(self.stack.last(), Some(Frame::Sequence { .. })) {
+            let path = self.child_path();
+            self.key_lines.insert(path, mark.line());
+        }
+    }
+
+    fn set_key(&mut self, key: String, mark: &Marker) {
+        if let Some(Frame::Mapping { path, pending_key, .. }) = self.stack.last_mut() {
+            self.key_lines.insert(join_path(path, &key), mark.line());
+            *pending_key = Some(key);
+        }
+    }
+
+    fn push_value(&mut self, value: Value) {
+        match self.stack.last_mut() {
+            None => self.root = Some(value),
+            Some(Frame::Sequence { items, .. }) => items.push(value),
+            Some(Frame::Mapping { entries, pending_key, .. }) => {
+                let key = pending_key.take().unwrap_or_default();
+                entries.insert(key, value);
+            }
+        }
+    }
+
+    fn count_nodes(&mut self, count: usize) {
+        self.nodes += count;
+        if self.nodes > MAX_YAML_NODES {
+            self.error = Some(format!("YAML document expands to more than {} nodes", MAX_YAML_NODES));
+        }
+    }
+
+    /// Convertit un scalaire selon son style et son tag
+    fn scalar_value(value: String, style: TScalarStyle, tag: Option<&Tag>) -> Value {
+        let is_str_tag = tag.is_some_and(|tag| tag.suffix == "str");
+        if style != TScalarStyle::Plain || is_str_tag {
+            return Value::String(value);
+        }
+
+        match Yaml::from_str(&value) {
+            Yaml::Null => Value::Null,
+            Yaml::Boolean(b) => Value::Bool(b),
+            Yaml::Integer(i) => json!(i),
+            Yaml::Real(raw) => match raw.parse::<f64>() {
+                Ok(f) => float_value(f, &raw),
+                Err(_) => Value::String(raw),
+            },
+            _ => Value::String(value),
+        }
+    }
+
+    fn value_as_key(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+impl MarkedEventReceiver for YamlBuilder {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        if self.error.is_some() {
+            return;
+        }
+
+        match event {
+            Event::DocumentStart => {
+                self.root = None;
+                self.stack.clear();
+                self.anchors.clear();
+                self.key_lines.clear();
+                self.start_line = mark.line();
+            },
+            Event::DocumentEnd => {
+                if let Some(value) = self.root.take() {
+                    self.documents.push(ConfigDocument {
+                        value,
+                        key_lines: std::mem::take(&mut self.key_lines),
+                        start_line: self.start_line,
+                    });
+                }
+            },
+            Event::Scalar(value, style, anchor, tag) => {
+                let value = Self::scalar_value(value, style, tag.as_ref());
+                if self.expects_key() {
+                    self.set_key(Self::value_as_key(&value), &mark);
+                    return;
+                }
+                if anchor > 0 {
+                    self.anchors.insert(anchor, value.clone());
+                }
+                self.record_item_line(&mark);
+                self.count_nodes(1);
+                self.push_value(value);
+            },
+            Event::Alias(anchor) => {
+                let value = self.anchors.get(&anchor).cloned().unwrap_or(Value::Null);
+                if self.expects_key() {
+                    self.set_key(Self::value_as_key(&value), &mark);
+                    return;
+                }
+                self.record_item_line(&mark);
+                self.count_nodes(value_nodes(&value));
+                self.push_value(value);
+            },
+            Event::SequenceStart(anchor, _) => {
+                self.record_item_line(&mark);
+                let path = self.child_path();
+                self.stack.push(Frame::Sequence { items: Vec::new(), anchor, path });
+            },
+            Event::MappingStart(anchor, _) => {
+                self.record_item_line(&mark);
+                let path = self.child_path();
+                self.stack.push(Frame::Mapping { entries: Map::new(), anchor, path, pending_key: None });
+            },
+            Event::SequenceEnd | Event::MappingEnd => {
+                let (value, anchor) = match self.stack.pop() {
+                    Some(Frame::Sequence { items, anchor, .. }) => (Value::Array(items), anchor),
+                    Some(Frame::Mapping { entries, anchor, .. }) => (Value::Object(entries), anchor),
+                    None => return,
+                };
+                if anchor > 0 {
+                    self.anchors.insert(anchor, value.clone());
+                }
+                // Clé complexe (mapping ou séquence utilisé comme clé)
+                if self.expects_key() {
+                    self.set_key(Self::value_as_key(&value), &mark);
+                    return;
+                }
+                self.count_nodes(1);
+                self.push_value(value);
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Analyse un flux YAML : chaque document (`---`) devient un document logique
+fn parse_yaml(content: &str) -> Result<Vec<ConfigDocument>, DocLoaderError> {
+    let mut builder = YamlBuilder::default();
+    Parser::new_from_str(content).load(&mut builder, true)?;
+
+    if let Some(error) = builder.error {
+        return Err(DocLoaderError::YamlParsing(error));
+    }
+    Ok(builder.documents)
+}
+
+// --- TOML ---
+
+/// Conversion des positions (octets) en numéros de ligne
+struct LineIndex {
+    line_starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(content: &str) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(content.match_indices('\n').map(|(i, _)| i + 1));
+        Self { line_starts }
+    }
+
+    fn line(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|start| *start <= offset)
+    }
+}
+
+fn toml_table(table: &dyn TableLike, path: &str, lines: &LineIndex, key_lines: &mut BTreeMap<String, usize>) -> Value {
+    let mut entries = Map::new();
+    for (key, item) in table.iter() {
+        let child_path = join_path(path, key);
+        let span = table.key(key).and_then(|k| k.span()).or_else(|| item.span());
+        if let Some(span) = span {
+            key_lines.insert(child_path.clone(), lines.line(span.start));
+        }
+        entries.insert(key.to_string(), toml_item(item, &child_path, lines, key_lines));
+    }
+    Value::Object(entries)
+}
+
+fn toml_item(item: &Item, path: &str, lines: &LineIndex, key_lines: &mut BTreeMap<String, usize>) -> Value {
+    match item {
+        Item::None => Value::Null,
+        Item::Value(value) => toml_value(value, path, lines, key_lines),
+        Item::Table(table) => toml_table(table, path, lines, key_lines),
+        Item::ArrayOfTables(tables) => Value::Array(tables.iter()
+            .enumerate()
+            .map(|(i, table)| {
+                let item_path = format!("{}[{}]", path, i);
+                if let Some(span) = table.span() {
+                    key_lines.insert(item_path.clone(), lines.line(span.start));
+                }
+                toml_table(table, &item_path, lines, key_lines)
+            })
+            .collect()),
+    }
+}
+
+fn toml_value(value: &toml_edit::Value, path: &str, lines: &LineIndex, key_lines: &mut BTreeMap<String, usize>) -> Value {
+    use toml_edit::Value as Toml;
+
+    match value {
+        Toml::String(s) => Value::String(s.value().clone()),
+        Toml::Integer(i) => json!(*i.value()),
+        Toml::Float(f) => float_value(*f.value(), &f.value().to_string()),
+        Toml::Boolean(b) => Value::Bool(*b.value()),
+        Toml::Datetime(d) => Value::String(d.value().to_string()),
+        Toml::Array(items) => Value::Array(items.iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let item_path = format!("{}[{}]", path, i);
+                if let Some(span) = item.span() {
+                    key_lines.insert(item_path.clone(), lines.line(span.start));
+                }
+                toml_value(item, &item_path, lines, key_lines)
+            })
+            .collect()),
+        Toml::InlineTable(table) => toml_table(table, path, lines, key_lines),
+    }
+}
+
+/// Analyse un fichier TOML (un seul document logique)
+fn parse_toml(content: &str) -> Result<Vec<ConfigDocument>, DocLoaderError> {
+    let document = ImDocument::parse(content)?;
+    let lines = LineIndex::new(content);
+    let mut key_lines = BTreeMap::new();
+    let value = toml_table(document.as_table(), "", &lines, &mut key_lines);
+
+    Ok(vec![ConfigDocument { value, key_lines, start_line: 1 }])
+}
+
+// --- Sortie commune ---
+
+/// Construit la sortie universelle.
+///
+/// Chaque clé de premier niveau est aplatie avec `JsonProcessor::json_to_text`, puis les clés
+/// consécutives sont regroupées jusqu'à `max_chunk_size`. Un chunk ne chevauche jamais deux
+/// documents ; `position.line` est la ligne source de sa première clé.
+fn build_output(
+    processor: &dyn DocumentProcessor,
+    processor_name: &str,
+    chunk_prefix: &str,
+    documents: &[ConfigDocument],
+    document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+    params: &ProcessingParams,
+    start_time: std::time::Instant,
+) -> UniversalOutput {
+    let json_processor = JsonProcessor::new();
+    let mut chunks = Vec::new();
+    let mut full_text = String::new();
+
+    for (document_index, document) in documents.iter().enumerate() {
+        // Entrées de premier niveau dans l'ordre du fichier source
+        let mut entries: Vec<(String, usize, String)> = document.top_level_entries()
+            .into_iter()
+            .map(|(path, value)| {
+                let line = document.line_of(&path).unwrap_or(document.start_line);
+                let raw_text = json_processor.json_to_text(value, &path, 0);
+                // Nettoyer le texte si demandé
+                let text = if params.text_cleaning { clean_text(&raw_text) } else { raw_text };
+                (path, line, text)
+            })
+            .collect();
+        entries.sort_by_key(|(_, line, _)| *line);
+
+        if !full_text.is_empty() {
+            full_text.push_str("\n\n");
+        }
+
+        // Regrouper les clés consécutives ; une clé trop longue est découpée seule
+        let mut groups: Vec<(Vec<&str>, usize, String)> = Vec::new();
+        let mut current: Option<(Vec<&str>, usize, String)> = None;
+        for (path, line, text) in &entries {
+            if let Some((_, _, group_text)) = &current {
+                if group_text.len() + 1 + text.len() > params.max_chunk_size {
+                    groups.extend(current.take());
+                }
+            }
+
+            if text.len() > params.max_chunk_size {
+                for piece in chunk_text(text, params.max_chunk_size, params.chunk_overlap) {
+                    groups.push((vec![path.as_str()], *line, piece));
+                }
+                continue;
+            }
+
+            match current.as_mut() {
+                Some((paths, _, group_text)) => {
+                    paths.push(path);
+                    group_text.push('\n');
+                    group_text.push_str(text);
+                },
+                None => current = Some((vec![path.as_str()], *line, text.clone())),
+            }
+        }
+        groups.extend(current);
+
+        let document_offset = full_text.len();
+        let document_text = entries.iter().map(|(_, _, text)| text.as_str()).collect::<Vec<_>>().join("\n");
+        full_text.push_str(&document_text);
+
+        for (paths, line, chunk_text) in groups {
+            let text_meta = extract_text_metadata(&chunk_text);
+            let start_offset = document_offset + document_text.find(&chunk_text).unwrap_or(0);
+            let index = chunks.len();
+
+            let key_lines: BTreeMap<&str, usize> = document.key_lines.iter()
+                .filter(|(key, _)| paths.iter().any(|path| is_descendant(key, path)))
+                .map(|(key, line)| (key.as_str(), *line))
+                .collect();
+
+            chunks.push(DocumentChunk {
+                id: format!("{}_chunk_{}", chunk_prefix, index),
+                content: chunk_text.clone(),
+                chunk_index: index,
+                position: ChunkPosition {
+                    page: None,
+                    line: Some(line as u32),
+                    start_offset: Some(start_offset),
+                    end_offset: Some(start_offset + chunk_text.len()),
+                },
+                metadata: ChunkMetadata {
+                    size: chunk_text.len(),
+                    language: if params.language_detection { text_meta.detected_language } else { None },
+                    confidence: Some(1.0),
+                    format_specific: Some(json!({
+                        "word_count": text_meta.word_count,
+                        "estimated_tokens": text_meta.estimated_tokens,
+                        "document_index": document_index,
+                        "keys": paths,
+                        "key_lines": key_lines
+                    })),
+                },
+            });
+        }
+    }
+
+    let text_meta = extract_text_metadata(&full_text);
+    let format_metadata = json!({
+        "config_metadata": {
+            "document_count": documents.len(),
+            "key_count": documents.iter().map(|d| d.key_lines.len()).sum::<usize>(),
+            "max_depth": documents.iter().map(|d| value_depth(&d.value)).max().unwrap_or(0),
+            "documents": documents.iter().map(|document| json!({
+                "start_line": document.start_line,
+                "top_level_keys": document.top_level_entries().into_iter().map(|(path, _)| path).collect::<Vec<_>>()
+            })).collect::<Vec<_>>()
+        },
+        "total_words": text_meta.word_count,
+        "total_characters": text_meta.character_count,
+        "estimated_tokens": text_meta.estimated_tokens,
+        "detected_language": text_meta.detected_language
+    });
+
+    let processing_time = start_time.elapsed();
+    let processing_info = ProcessingInfo {
+        processor: processor_name.to_string(),
+        processor_version: processor.version().to_string(),
+        processed_at: Utc::now(),
+        processing_time_ms: processing_time.as_millis() as u64,
+        total_chunks: chunks.len(),
+        total_content_size: full_text.len(),
+        processing_params: params.clone(),
+        warnings: Vec::new(),
+    };
+
+    UniversalOutput {
+        document_metadata: document_metadata(format_metadata),
+        chunks,
+        processing_info,
+    }
+}
+
+fn decode_utf8(content: &[u8]) -> Result<&str, DocLoaderError> {
+    std::str::from_utf8(content)
+        .map(|text| text.trim_start_matches('\u{feff}'))
+        .map_err(|e| DocLoaderError::InvalidFormat(format!("Configuration file is not valid UTF-8: {}", e)))
+}
+
+pub struct YamlProcessor;
+
+impl YamlProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DocumentProcessor for YamlProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::YAML
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et parser le flux YAML
+        let content = fs::read(file_path)?;
+        let documents = parse_yaml(decode_utf8(&content)?)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(build_output(self, "YamlProcessor", "yaml", &documents, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.yaml")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::YAML,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Parser le YAML depuis les bytes
+        let documents = parse_yaml(decode_utf8(content)?)?;
+
+        Ok(build_output(self, "YamlProcessor", "yaml", &documents, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::YAML,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for YamlProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct TomlProcessor;
+
+impl TomlProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DocumentProcessor for TomlProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::TOML
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et parser le TOML
+        let content = fs::read(file_path)?;
+        let documents = parse_toml(decode_utf8(&content)?)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(build_output(self, "TomlProcessor", "toml", &documents, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.toml")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::TOML,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Parser le TOML depuis les bytes
+        let documents = parse_toml(decode_utf8(content)?)?;
+
+        Ok(build_output(self, "TomlProcessor", "toml", &documents, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::TOML,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for TomlProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTI_DOC_YAML: &str = "\
+apiVersion: v1
+kind: Service
+metadata:
+  name: web
+  labels: &labels
+    app: web
+selector: *labels
+---
+kind: Deployment
+spec:
+  replicas: 3
+  ports:
+    - 80
+    - '443'
+";
+
+    #[test]
+    fn test_config_processor_creation() {
+        assert_eq!(YamlProcessor::new().supported_type().to_string(), "YAML");
+        assert_eq!(TomlProcessor::new().supported_type().to_string(), "TOML");
+    }
+
+    #[test]
+    fn test_parse_yaml_documents() {
+        let documents = parse_yaml(MULTI_DOC_YAML).unwrap();
+        assert_eq!(documents.len(), 2);
+
+        let service = &documents[0];
+        assert_eq!(service.value["metadata"]["labels"]["app"], "web");
+        assert_eq!(service.key_lines["kind"], 2);
+        assert_eq!(service.key_lines["metadata.labels.app"], 6);
+        assert_eq!(service.value["selector"]["app"], "web");
+
+        let deployment = &documents[1];
+        assert_eq!(deployment.value["spec"]["replicas"], 3);
+        assert_eq!(deployment.value["spec"]["ports"], json!([80, "443"]));
+        assert_eq!(deployment.key_lines["spec.replicas"], 11);
+        assert_eq!(deployment.key_lines["spec.ports[1]"], 14);
+    }
+
+    #[test]
+    fn test_parse_toml_lines() {
+        let toml = "title = \"App\"\n\n[server]\nhost = \"localhost\"\nport = 8080\n\n[[users]]\nname = \"alice\"\n";
+        let documents = parse_toml(toml).unwrap();
+        let document = &documents[0];
+
+        assert_eq!(document.value["server"]["port"], 8080);
+        assert_eq!(document.value["users"][0]["name"], "alice");
+        assert_eq!(document.key_lines["title"], 1);
+        assert_eq!(document.key_lines["server.port"], 5);
+        assert_eq!(document.key_lines["users[0].name"], 8);
+    }
+
+    #[test]
+    fn test_process_yaml_content() {
+        let processor = YamlProcessor::new();
+        let params = ProcessingParams::default().with_chunk_size(60);
+
+        let output = processor.process_content(MULTI_DOC_YAML.as_bytes(), "k8s.yaml", &params).unwrap();
+        assert_eq!(output.document_metadata.format_metadata["config_metadata"]["document_count"], 2);
+
+        let first = &output.chunks[0];
+        assert_eq!(first.position.line, Some(1));
+        assert!(first.content.starts_with("apiVersion=\"v1\""));
+
+        // Les chunks ne chevauchent pas deux documents
+        let deployment_chunk = output.chunks.iter()
+            .find(|chunk| chunk.content.contains("spec.replicas=3"))
+            .unwrap();
+        let meta = deployment_chunk.metadata.format_specific.as_ref().unwrap();
+        assert_eq!(meta["document_index"], 1);
+        assert_eq!(meta["key_lines"]["spec.replicas"], 11);
+        assert!(!deployment_chunk.content.contains("apiVersion"));
+    }
+}
@@ -3,195 +3,705 @@ use crate::core::{
     DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
 };
 use crate::error::DocLoaderError;
+use crate::processors::profile::ColumnStatistics;
+use crate::processors::records::{self, RecordWarnings};
 use crate::processors::DocumentProcessor;
-use crate::utils::{chunk_text, clean_text, extract_text_metadata};
+use crate::processors::compression::BoundedReader;
+use crate::processors::registry::ProcessorRegistry;
+use crate::utils::{clean_text, detect_language, extract_text_metadata};
 
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Cursor, Read};
 use std::path::Path;
-use std::fs;
+use std::fs::{self, File};
 use csv::ReaderBuilder;
 use chrono::Utc;
+use serde::Deserialize;
 use serde_json::json;
 
-pub struct CsvProcessor;
+/// Taille de l'échantillon analysé pour détecter le dialecte
+const SNIFF_SAMPLE_SIZE: usize = 64 * 1024;
 
-impl CsvProcessor {
-    pub fn new() -> Self {
-        Self
+/// Nombre maximal de lignes de l'échantillon
+const SNIFF_SAMPLE_LINES: usize = 100;
+
+/// Séparateurs candidats, par ordre de préférence en cas d'égalité
+const CANDIDATE_DELIMITERS: [u8; 4] = [b',', b';', b'\t', b'|'];
+
+/// En mode tolérant, nombre maximal de lignes physiques d'un enregistrement entre guillemets
+const MAX_RECORD_LINES: usize = 1000;
+
+/// Options CSV, lues depuis `params.format_specific["csv"]` ; les valeurs absentes sont détectées
+///
+/// ```json
+/// { "csv": { "delimiter": ";", "quote": "\"", "escape": "\\", "has_headers": true } }
+/// { "csv": { "record_mode": true, "text_columns": ["title", "summary"] } }
+/// { "csv": { "template": "{title} by {author} ({year|n.d.})", "exclude_columns": ["internal_id"] } }
+/// { "csv": { "on_error": "repair" } }
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct CsvOptions {
+    pub delimiter: Option<char>,
+    pub quote: Option<char>,
+    pub escape: Option<char>,
+    pub has_headers: Option<bool>,
+    /// Un chunk par ligne plutôt que des groupes de lignes
+    pub record_mode: bool,
+    /// En mode enregistrement, colonnes rendues dans le texte (toutes par défaut) ;
+    /// les autres deviennent des métadonnées typées du chunk
+    pub text_columns: Vec<String>,
+    /// Gabarit de rendu de chaque ligne (`{colonne}`, `{colonne|défaut}`) à la place de `colonne="valeur"`
+    pub template: Option<String>,
+    /// Valeur des colonnes absentes ou vides sans défaut dans le gabarit
+    pub missing_value: String,
+    /// Colonnes conservées (toutes par défaut) pour le rendu et les métadonnées
+    pub include_columns: Vec<String>,
+    /// Colonnes ignorées pour le rendu et les métadonnées
+    pub exclude_columns: Vec<String>,
+    /// Traitement des enregistrements malformés (échec par défaut)
+    pub on_error: ErrorPolicy,
+}
+
+/// Traitement des enregistrements malformés : lignes de longueur inattendue, guillemets non
+/// refermés, octets UTF-8 invalides
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ErrorPolicy {
+    /// Le premier enregistrement malformé fait échouer le traitement
+    #[default]
+    Fail,
+    /// Les enregistrements malformés sont ignorés
+    Skip,
+    /// Les enregistrements malformés sont réparés : champs manquants vides, champs en trop
+    /// rattachés à la dernière colonne, ligne relue sans guillemets, octets invalides remplacés
+    Repair,
+}
+
+/// Politique insensible à la casse (`"repair"`, `"Repair"`)
+impl<'de> Deserialize<'de> for ErrorPolicy {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let policy = String::deserialize(deserializer)?;
+        match policy.to_lowercase().as_str() {
+            "fail" => Ok(ErrorPolicy::Fail),
+            "skip" => Ok(ErrorPolicy::Skip),
+            "repair" => Ok(ErrorPolicy::Repair),
+            _ => Err(serde::de::Error::unknown_variant(&policy, &["fail", "skip", "repair"])),
+        }
+    }
+}
+
+impl CsvOptions {
+    pub fn from_params(params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        params.format_options("csv")
+    }
+}
+
+/// Caractère de dialecte explicite ; le lecteur CSV ne travaille que sur des octets ASCII
+fn ascii_option(name: &str, value: Option<char>) -> Result<Option<u8>, DocLoaderError> {
+    match value {
+        Some(c) if !c.is_ascii() => Err(DocLoaderError::InvalidFormat(format!(
+            "Invalid format_specific[\"csv\"] options: {} '{}' is not an ASCII character", name, c
+        ))),
+        value => Ok(value.map(|c| c as u8)),
+    }
+}
+
+/// Dialecte CSV : séparateur, guillemet, caractère d'échappement et présence d'en-têtes
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvDialect {
+    pub delimiter: u8,
+    pub quote: u8,
+    /// `None` : les guillemets sont doublés (`""`) dans les champs
+    pub escape: Option<u8>,
+    pub has_headers: bool,
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        Self { delimiter: b',', quote: b'"', escape: None, has_headers: true }
+    }
+}
+
+impl CsvDialect {
+    /// Détecte le dialecte sur un échantillon ; les options explicites ne sont pas remises en cause,
+    /// mais doivent être des caractères ASCII
+    pub fn detect(content: &str, options: &CsvOptions) -> Result<Self, DocLoaderError> {
+        let lines: Vec<&str> = sample_lines(content);
+        let quote = ascii_option("quote", options.quote)?.unwrap_or_else(|| sniff_quote(&lines));
+        let delimiter = ascii_option("delimiter", options.delimiter)?.unwrap_or_else(|| sniff_delimiter(&lines, quote));
+        let escape = match ascii_option("escape", options.escape)? {
+            Some(escape) => Some(escape),
+            None => sniff_escape(&lines, quote),
+        };
+
+        let mut dialect = Self { delimiter, quote, escape, has_headers: true };
+        dialect.has_headers = options.has_headers.unwrap_or_else(|| {
+            let records = dialect.sample_records(&lines.join("\n"));
+            sniff_header(&records)
+        });
+        Ok(dialect)
+    }
+
+    fn reader_builder(&self) -> ReaderBuilder {
+        let mut builder = ReaderBuilder::new();
+        builder
+            .has_headers(false)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.escape.is_none());
+        builder
+    }
+
+    /// Enregistrements d'un échantillon, en tolérant les lignes de longueurs différentes
+    fn sample_records(&self, sample: &str) -> Vec<Vec<String>> {
+        self.reader_builder()
+            .flexible(true)
+            .from_reader(sample.as_bytes())
+            .records()
+            .map_while(Result::ok)
+            .map(|record| record.iter().map(|field| field.to_string()).collect())
+            .collect()
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "delimiter": (self.delimiter as char).to_string(),
+            "quote": (self.quote as char).to_string(),
+            "escape": self.escape.map(|escape| (escape as char).to_string()),
+            "has_headers": self.has_headers
+        })
+    }
+}
+
+/// Premières lignes non vides du contenu ; la dernière ligne d'un échantillon tronqué est écartée
+fn sample_lines(content: &str) -> Vec<&str> {
+    let mut end = content.len().min(SNIFF_SAMPLE_SIZE);
+    while !content.is_char_boundary(end) {
+        end -= 1;
+    }
+    let sample = &content[..end];
+    let mut lines: Vec<&str> = sample.lines().take(SNIFF_SAMPLE_LINES + 1).collect();
+    if lines.len() > SNIFF_SAMPLE_LINES || end < content.len() {
+        lines.pop();
+    }
+    lines.retain(|line| !line.trim().is_empty());
+    lines
+}
+
+/// Guillemet le plus fréquent en début de champ (`"` par défaut)
+fn sniff_quote(lines: &[&str]) -> u8 {
+    let count = |quote: char| -> usize {
+        lines.iter()
+            .map(|line| {
+                let starts = usize::from(line.starts_with(quote));
+                let after_delimiter = CANDIDATE_DELIMITERS.iter()
+                    .map(|&delimiter| line.matches(&format!("{}{}", delimiter as char, quote)).count())
+                    .sum::<usize>();
+                starts + after_delimiter
+            })
+            .sum()
+    };
+    if count('\'') > count('"') { b'\'' } else { b'"' }
+}
+
+/// Séparateur donnant le nombre de champs le plus régulier (et supérieur à un) sur l'échantillon
+fn sniff_delimiter(lines: &[&str], quote: u8) -> u8 {
+    let sample = lines.join("\n");
+    let mut best = (b',', 0.0, 0);
+    for delimiter in CANDIDATE_DELIMITERS {
+        let dialect = CsvDialect { delimiter, quote, escape: None, has_headers: false };
+        let records = dialect.sample_records(&sample);
+        if records.is_empty() {
+            continue;
+        }
+
+        let mut frequencies: HashMap<usize, usize> = HashMap::new();
+        for record in &records {
+            *frequencies.entry(record.len()).or_insert(0) += 1;
+        }
+        let (fields, occurrences) = frequencies.into_iter()
+            .max_by_key(|&(fields, occurrences)| (occurrences, fields))
+            .unwrap_or_default();
+        if fields < 2 {
+            continue;
+        }
+
+        let consistency = occurrences as f64 / records.len() as f64;
+        if consistency > best.1 || (consistency == best.1 && fields > best.2) {
+            best = (delimiter, consistency, fields);
+        }
+    }
+    best.0
+}
+
+/// Échappement par antislash (`\"`) plutôt que par guillemet doublé (`""`)
+fn sniff_escape(lines: &[&str], quote: u8) -> Option<u8> {
+    let quote = quote as char;
+    let backslashed = lines.iter().any(|line| line.contains(&format!("\\{}", quote)));
+    // `""` après un séparateur est un champ vide, après un antislash la fin d'un champ échappé
+    let doubled = lines.iter().any(|line| {
+        let chars: Vec<char> = line.chars().collect();
+        chars.windows(3).any(|window| {
+            window[1] == quote && window[2] == quote
+                && window[0] != '\\'
+                && !CANDIDATE_DELIMITERS.iter().any(|&delimiter| window[0] == delimiter as char)
+        })
+    });
+    (backslashed && !doubled).then_some(b'\\')
+}
+
+/// Première ligne d'en-têtes si son type diffère de celui des valeurs de la colonne
+/// (heuristique du module `csv` de Python) ; en cas de doute, des en-têtes sont supposés
+fn sniff_header(records: &[Vec<String>]) -> bool {
+    let Some((first, rest)) = records.split_first() else {
+        return true;
+    };
+    if rest.is_empty() {
+        return true;
+    }
+
+    let mut votes = 0i32;
+    for (index, name) in first.iter().enumerate() {
+        let values: Vec<&str> = rest.iter()
+            .filter_map(|record| record.get(index))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+
+        if values.iter().all(|value| value.parse::<f64>().is_ok()) {
+            votes += if name.trim().parse::<f64>().is_ok() { -1 } else { 1 };
+        } else {
+            let length = values[0].chars().count();
+            if values.iter().all(|value| value.chars().count() == length) {
+                votes += if name.trim().chars().count() == length { -1 } else { 1 };
+            }
+        }
+    }
+    votes >= 0
+}
+
+/// Texte d'un chunk, lignes de données qu'il couvre et ligne du fichier où il commence
+struct CsvChunk {
+    text: String,
+    rows: std::ops::Range<usize>,
+    line: u64,
+    /// Colonnes conservées en métadonnées (mode enregistrement)
+    fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Regroupe au fil de la lecture des lignes entières en chunks d'au plus `max_size` caractères,
+/// chacun précédé de la ligne d'en-têtes ; une ligne n'est jamais coupée, même si elle dépasse
+/// à elle seule la taille maximale
+struct RowGrouper {
+    header_line: Option<String>,
+    max_size: usize,
+    texts: Vec<String>,
+    first_row: usize,
+    first_line: u64,
+    len: usize,
+}
+
+impl RowGrouper {
+    fn new(header_line: Option<String>, max_size: usize) -> Self {
+        Self { header_line, max_size, texts: Vec::new(), first_row: 0, first_line: 0, len: 0 }
     }
     
-    /// Lit et parse un fichier CSV
-    fn read_csv_file(&self, file_path: &Path) -> Result<(Vec<Vec<String>>, Vec<String>), DocLoaderError> {
-        let content = fs::read_to_string(file_path)?;
-        self.parse_csv_content(&content)
+    /// Ajoute une ligne rendue ; renvoie le chunk en cours s'il ne peut pas l'accueillir
+    fn push(&mut self, row_index: usize, line: u64, text: String) -> Option<CsvChunk> {
+        let full = !self.texts.is_empty() && self.len + 1 + text.len() > self.max_size;
+        let chunk = if full { self.finish() } else { None };
+        if self.texts.is_empty() {
+            self.first_row = row_index;
+            self.first_line = line;
+            self.len = self.header_line.as_ref().map_or(0, |header_line| header_line.len() + 1);
+        }
+        self.len += 1 + text.len();
+        self.texts.push(text);
+        chunk
     }
     
-    /// Parse le contenu CSV
-    fn parse_csv_content(&self, content: &str) -> Result<(Vec<Vec<String>>, Vec<String>), DocLoaderError> {
-        let mut reader = ReaderBuilder::new()
-            .has_headers(true)
-            .from_reader(content.as_bytes());
-        
-        // Récupérer les en-têtes
-        let headers = reader.headers()
-            .map_err(|e| DocLoaderError::Processing(format!("Failed to read CSV headers: {}", e)))?
-            .iter()
-            .map(|h| h.to_string())
-            .collect::<Vec<String>>();
-        
-        // Lire toutes les lignes
-        let mut rows = Vec::new();
-        for result in reader.records() {
-            let record = result
-                .map_err(|e| DocLoaderError::Processing(format!("Failed to read CSV record: {}", e)))?;
-            
-            let row = record.iter().map(|field| field.to_string()).collect();
-            rows.push(row);
+    /// Chunk des lignes en attente
+    fn finish(&mut self) -> Option<CsvChunk> {
+        if self.texts.is_empty() {
+            return None;
         }
-        
-        Ok((rows, headers))
+        let rows = self.first_row..self.first_row + self.texts.len();
+        let rows_text = std::mem::take(&mut self.texts).join("\n");
+        Some(CsvChunk {
+            text: match &self.header_line {
+                Some(header_line) => format!("{}\n\n{}", header_line, rows_text),
+                None => rows_text,
+            },
+            rows,
+            line: self.first_line,
+            fields: None,
+        })
+    }
+}
+
+/// Lecture tolérante : chaque enregistrement est reconstitué à partir des lignes physiques, ce
+/// qui permet d'isoler un guillemet non refermé ou des octets invalides sans perdre la suite du fichier
+struct LenientRecords<R: BufRead> {
+    reader: R,
+    dialect: CsvDialect,
+    policy: ErrorPolicy,
+    line: u64,
+    /// Lignes relues après l'isolement d'un guillemet non refermé
+    pending: VecDeque<(u64, Vec<u8>)>,
+}
+
+impl<R: BufRead> LenientRecords<R> {
+    fn new(reader: R, dialect: CsvDialect, policy: ErrorPolicy) -> Self {
+        Self { reader, dialect, policy, line: 0, pending: VecDeque::new() }
     }
     
-    /// Convertit les données CSV en texte lisible
-    fn csv_to_text(&self, rows: &[Vec<String>], headers: &[String]) -> String {
-        let mut text = String::new();
-        
-        // Ajouter les en-têtes
-        text.push_str("Headers: ");
-        text.push_str(&headers.join(", "));
-        text.push_str("\n\n");
-        
-        // Ajouter chaque ligne avec ses labels
-        for (row_index, row) in rows.iter().enumerate() {
-            text.push_str(&format!("Row {}: ", row_index + 1));
-            
-            for (col_index, value) in row.iter().enumerate() {
-                if col_index > 0 {
-                    text.push_str(", ");
-                }
-                
-                let header = headers.get(col_index).map(|h| h.as_str()).unwrap_or("unknown");
-                text.push_str(&format!("{}=\"{}\"", header, value));
+    fn next_line(&mut self) -> Result<Option<(u64, Vec<u8>)>, DocLoaderError> {
+        if let Some(line) = self.pending.pop_front() {
+            return Ok(Some(line));
+        }
+        let mut bytes = Vec::new();
+        if self.reader.read_until(b'\n', &mut bytes)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        while bytes.last().is_some_and(|&byte| byte == b'\n' || byte == b'\r') {
+            bytes.pop();
+        }
+        Ok(Some((self.line, bytes)))
+    }
+    
+    /// Un champ entre guillemets reste ouvert à la fin du texte
+    fn has_open_quote(&self, bytes: &[u8]) -> bool {
+        let mut open = false;
+        let mut escaped = false;
+        for &byte in bytes {
+            if escaped {
+                escaped = false;
+            } else if open && Some(byte) == self.dialect.escape {
+                escaped = true;
+            } else if byte == self.dialect.quote {
+                open = !open;
             }
-            text.push('\n');
         }
-        
-        text
+        open
     }
     
-    /// Extrait les métadonnées du CSV
-    fn extract_csv_metadata(&self, rows: &[Vec<String>], headers: &[String]) -> serde_json::Value {
-        let total_rows = rows.len();
-        let total_columns = headers.len();
-        
-        // Analyser les types de données par colonne
-        let mut column_stats = Vec::new();
-        
-        for (col_index, header) in headers.iter().enumerate() {
-            let mut non_empty_count = 0;
-            let mut numeric_count = 0;
-            let mut max_length = 0;
-            let mut unique_values = std::collections::HashSet::new();
+    /// Champs du premier enregistrement du texte
+    fn parse(&self, bytes: &[u8], quoting: bool) -> Vec<Vec<u8>> {
+        let mut builder = self.dialect.reader_builder();
+        builder.flexible(true).quoting(quoting);
+        builder.from_reader(bytes)
+            .byte_records()
+            .next()
+            .and_then(Result::ok)
+            .map(|record| record.iter().map(<[u8]>::to_vec).collect())
+            .unwrap_or_default()
+    }
+    
+    /// Prochain enregistrement ; `expected` est le nombre de champs attendu, s'il est connu
+    fn next_record(&mut self, expected: Option<usize>, warnings: &mut RecordWarnings) -> Result<Option<(u64, Vec<String>)>, DocLoaderError> {
+        loop {
+            let Some((line, first)) = self.next_line()? else {
+                return Ok(None);
+            };
+            if first.iter().all(u8::is_ascii_whitespace) {
+                continue;
+            }
             
-            for row in rows {
-                if let Some(value) = row.get(col_index) {
-                    if !value.is_empty() {
-                        non_empty_count += 1;
-                        max_length = max_length.max(value.len());
-                        unique_values.insert(value.clone());
-                        
-                        // Tenter de parser comme nombre
-                        if value.parse::<f64>().is_ok() {
-                            numeric_count += 1;
-                        }
-                    }
-                }
+            // Un champ entre guillemets peut s'étendre sur plusieurs lignes
+            let mut raw = first.clone();
+            let mut continuation = Vec::new();
+            while self.has_open_quote(&raw) && continuation.len() + 1 < MAX_RECORD_LINES {
+                let Some((next_line, next)) = self.next_line()? else {
+                    break;
+                };
+                raw.push(b'\n');
+                raw.extend_from_slice(&next);
+                continuation.push((next_line, next));
             }
             
-            let data_type = if numeric_count == non_empty_count && non_empty_count > 0 {
-                "numeric"
-            } else if unique_values.len() <= 10 && total_rows > 10 {
-                "categorical"
-            } else {
-                "text"
-            };
+            let mut fields = self.parse(&raw, true);
+            let broken = self.has_open_quote(&raw)
+                || (!continuation.is_empty() && expected.is_some_and(|expected| fields.len() != expected));
+            if broken {
+                // Les lignes avalées par le guillemet sont relues comme des enregistrements
+                for pending in continuation.into_iter().rev() {
+                    self.pending.push_front(pending);
+                }
+                if self.policy == ErrorPolicy::Skip {
+                    warnings.skipped(line, "unbalanced quote".to_string());
+                    continue;
+                }
+                warnings.repaired(line, "unbalanced quote (line read without quoting)".to_string());
+                fields = self.parse(&first, false);
+            }
             
-            column_stats.push(json!({
-                "name": header,
-                "data_type": data_type,
-                "non_empty_count": non_empty_count,
-                "fill_rate": if total_rows > 0 { non_empty_count as f64 / total_rows as f64 } else { 0.0 },
-                "unique_values": unique_values.len(),
-                "max_length": max_length
-            }));
+            if fields.iter().any(|field| std::str::from_utf8(field).is_err()) {
+                if self.policy == ErrorPolicy::Skip {
+                    warnings.skipped(line, "invalid UTF-8".to_string());
+                    continue;
+                }
+                warnings.repaired(line, "invalid UTF-8 (replaced with U+FFFD)".to_string());
+            }
+            let row = fields.iter().map(|field| String::from_utf8_lossy(field).into_owned()).collect();
+            return Ok(Some((line, row)));
         }
-        
-        json!({
-            "total_rows": total_rows,
-            "total_columns": total_columns,
-            "headers": headers,
-            "column_statistics": column_stats,
-            "data_completeness": if total_rows > 0 && total_columns > 0 {
-                rows.iter().map(|row| {
-                    row.iter().filter(|cell| !cell.is_empty()).count() as f64 / total_columns as f64
-                }).sum::<f64>() / total_rows as f64
-            } else { 0.0 }
-        })
     }
 }
 
-impl DocumentProcessor for CsvProcessor {
-    fn supported_type(&self) -> DocumentType {
-        DocumentType::CSV
+/// Source des enregistrements : lecteur CSV strict, ou lecture tolérante ligne à ligne
+enum RecordSource<R: Read> {
+    Strict(csv::ByteRecordsIntoIter<R>),
+    Lenient(LenientRecords<BufReader<R>>),
+}
+
+impl<R: Read> RecordSource<R> {
+    fn new(reader: R, dialect: &CsvDialect, policy: ErrorPolicy) -> Self {
+        match policy {
+            ErrorPolicy::Fail => RecordSource::Strict(dialect.reader_builder().from_reader(reader).into_byte_records()),
+            policy => RecordSource::Lenient(LenientRecords::new(BufReader::new(reader), dialect.clone(), policy)),
+        }
     }
     
-    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
-        let start_time = std::time::Instant::now();
-        
-        // Vérifier que le fichier existe
-        if !file_path.exists() {
-            return Err(DocLoaderError::FileNotFound(
-                format!("File not found: {}", file_path.display())
-            ));
+    fn next_record(&mut self, expected: Option<usize>, warnings: &mut RecordWarnings) -> Result<Option<(u64, Vec<String>)>, DocLoaderError> {
+        match self {
+            RecordSource::Strict(records) => records.next()
+                .map(|result| {
+                    let record = result
+                        .map_err(|e| DocLoaderError::Processing(format!("Failed to read CSV record: {}", e)))?;
+                    let line = record.position().map_or(0, |position| position.line());
+                    // Les octets invalides ne sont remplacés qu'en mode `repair`
+                    let fields = record.iter()
+                        .map(|field| String::from_utf8(field.to_vec()).map_err(|e| DocLoaderError::Processing(format!(
+                            "Invalid UTF-8 in CSV record at line {}: {}", line, e.utf8_error()
+                        ))))
+                        .collect::<Result<Vec<String>, DocLoaderError>>()?;
+                    Ok((line, fields))
+                })
+                .transpose(),
+            RecordSource::Lenient(records) => records.next_record(expected, warnings),
         }
+    }
+}
+
+/// Résultat de la lecture en flux, une fois tous les chunks transmis
+struct CsvSummary {
+    headers: Vec<String>,
+    statistics: ColumnStatistics,
+    warnings: RecordWarnings,
+}
+
+/// Processeur CSV/TSV.
+///
+/// Seul `process_file_streaming` borne la mémoire : `process_file`, `process_content` (et donc
+/// `UniversalProcessor`) lisent le fichier en flux mais conservent tous les chunks dans la
+/// sortie, soit de l'ordre de la taille du fichier en mémoire.
+pub struct CsvProcessor;
+
+impl CsvProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+    
+    /// Début du fichier, pour la détection du dialecte (un octet de plus que l'échantillon
+    /// pour savoir s'il est tronqué)
+    fn read_sample(&self, file_path: &Path) -> Result<String, DocLoaderError> {
+        let mut sample = Vec::new();
+        File::open(file_path)?
+            .take(SNIFF_SAMPLE_SIZE as u64 + 4)
+            .read_to_end(&mut sample)?;
+        Ok(String::from_utf8_lossy(&sample).into_owned())
+    }
+    
+    /// Ligne d'en-têtes répétée en tête de chaque chunk
+    fn header_line(&self, headers: &[String]) -> String {
+        format!("Headers: {}", headers.join(", "))
+    }
+    
+    /// Rend une ligne de données avec le nom de chaque colonne (`row_number` commence à 1)
+    fn row_to_text(&self, row_number: usize, row: &[String], headers: &[String]) -> String {
+        let fields: Vec<String> = row.iter().enumerate().map(|(col_index, value)| {
+            let header = headers.get(col_index).map(|h| h.as_str()).unwrap_or("unknown");
+            format!("{}=\"{}\"", header, value)
+        }).collect();
+        format!("Row {}: {}", row_number, fields.join(", "))
+    }
+    
+    /// Métadonnées du CSV, à partir des statistiques accumulées pendant la lecture
+    fn csv_metadata(&self, summary: &CsvSummary, dialect: &CsvDialect) -> serde_json::Value {
+        let CsvSummary { headers, statistics, warnings } = summary;
+        json!({
+            "total_rows": statistics.total_rows(),
+            "total_columns": headers.len(),
+            "headers": headers,
+            "dialect": dialect.to_json(),
+            "column_statistics": statistics.to_json(headers),
+            "schema": statistics.schema(headers),
+            "data_completeness": statistics.data_completeness(),
+            "skipped_rows": warnings.skipped_count(),
+            "repaired_rows": warnings.repaired_count()
+        })
+    }
+    
+    /// Colonnes retenues par `include_columns` et `exclude_columns`
+    fn selected_columns(&self, headers: &[String], options: &CsvOptions) -> Vec<usize> {
+        headers.iter().enumerate()
+            .filter(|(_, header)| options.include_columns.is_empty() || options.include_columns.contains(header))
+            .filter(|(_, header)| !options.exclude_columns.contains(header))
+            .map(|(index, _)| index)
+            .collect()
+    }
+    
+    /// Valeur brute d'une colonne nommée, pour le gabarit (toutes les colonnes restent accessibles)
+    fn column_value(&self, headers: &[String], row: &[String], name: &str) -> Option<String> {
+        headers.iter().position(|header| header == name).and_then(|index| row.get(index)).cloned()
+    }
+    
+    /// Lit les enregistrements en flux et transmet chaque chunk dès qu'il est formé : seules les
+    /// lignes du chunk en cours et les compteurs de colonnes restent en mémoire
+    fn stream_chunks(
+        &self,
+        reader: impl Read,
+        dialect: &CsvDialect,
+        options: &CsvOptions,
+        params: &ProcessingParams,
+        mut emit: impl FnMut(CsvChunk) -> Result<(), DocLoaderError>,
+    ) -> Result<CsvSummary, DocLoaderError> {
+        let mut source = RecordSource::new(reader, dialect, options.on_error);
+        let mut warnings = RecordWarnings::default();
         
-        // Lire et parser le CSV
-        let (rows, headers) = self.read_csv_file(file_path)?;
-        
-        // Convertir en texte pour l'extraction
-        let raw_text = self.csv_to_text(&rows, &headers);
+        // Récupérer les en-têtes, ou les nommer `column_1`, `column_2`, ... en leur absence
+        let (headers, mut first_row) = match source.next_record(None, &mut warnings)? {
+            Some((_, row)) if dialect.has_headers => (row, None),
+            Some((line, row)) => ((1..=row.len()).map(|index| format!("column_{}", index)).collect(), Some((line, row))),
+            None => (Vec::new(), None),
+        };
         
-        // Nettoyer le texte si demandé
-        let text = if params.text_cleaning {
-            clean_text(&raw_text)
-        } else {
-            raw_text
+        let template = options.template.as_deref().map(records::Template::parse);
+        let columns = self.selected_columns(&headers, options);
+        let selected_headers: Vec<String> = columns.iter().map(|&index| headers[index].clone()).collect();
+        let header_line = match template {
+            Some(_) => None,
+            None => Some(self.header_line(&selected_headers)),
         };
+        let mut grouper = RowGrouper::new(header_line, params.max_chunk_size);
+        let mut statistics = ColumnStatistics::new(headers.len());
         
-        // Découper en chunks
-        let chunks_text = chunk_text(&text, params.max_chunk_size, params.chunk_overlap);
+        let mut row_index = 0;
+        loop {
+            let (line, mut row) = match first_row.take() {
+                Some(record) => record,
+                None => match source.next_record(Some(headers.len()), &mut warnings)? {
+                    Some(record) => record,
+                    None => break,
+                },
+            };
+            
+            if options.on_error != ErrorPolicy::Fail && row.len() != headers.len() && !headers.is_empty() {
+                let reason = format!("expected {} fields, found {}", headers.len(), row.len());
+                if options.on_error == ErrorPolicy::Skip {
+                    warnings.skipped(line, reason);
+                    continue;
+                }
+                if row.len() < headers.len() {
+                    row.resize(headers.len(), String::new());
+                    warnings.repaired(line, format!("{} (padded with empty fields)", reason));
+                } else {
+                    let extra = row.split_off(headers.len());
+                    if let Some(last) = row.last_mut() {
+                        for field in extra {
+                            last.push(dialect.delimiter as char);
+                            last.push_str(&field);
+                        }
+                    }
+                    warnings.repaired(line, format!("{} (extra fields merged into the last column)", reason));
+                }
+            }
+            statistics.push(&row);
+            
+            if options.record_mode {
+                // Un chunk par ligne : les colonnes choisies (ou le gabarit) forment le texte, les autres les métadonnées
+                let fields = columns.iter()
+                    .filter_map(|&index| row.get(index).map(|value| (headers[index].clone(), records::typed_value(value))))
+                    .collect();
+                let (text, metadata) = records::render_record(
+                    fields,
+                    &options.text_columns,
+                    template.as_ref(),
+                    |name| self.column_value(&headers, &row, name),
+                    &options.missing_value,
+                    params.text_cleaning,
+                );
+                emit(CsvChunk { text, rows: row_index..row_index + 1, line, fields: Some(metadata) })?;
+            } else {
+                let text = match &template {
+                    Some(template) => template.render(|name| self.column_value(&headers, &row, name), &options.missing_value),
+                    None => {
+                        let values: Vec<String> = columns.iter().map(|&index| row.get(index).cloned().unwrap_or_default()).collect();
+                        self.row_to_text(row_index + 1, &values, &selected_headers)
+                    },
+                };
+                let text = if params.text_cleaning { clean_text(&text) } else { text };
+                if let Some(chunk) = grouper.push(row_index, line, text) {
+                    emit(chunk)?;
+                }
+            }
+            row_index += 1;
+        }
+        if let Some(chunk) = grouper.finish() {
+            emit(chunk)?;
+        }
         
-        // Créer les chunks avec métadonnées
-        let mut chunks = Vec::new();
+        Ok(CsvSummary { headers, statistics, warnings })
+    }
+    
+    /// Traite un flux CSV ; les chunks sont transmis à `on_chunk` et la sortie renvoyée n'en contient aucun
+    fn process_stream(
+        &self,
+        reader: impl Read,
+        dialect: &CsvDialect,
+        document_metadata: impl FnOnce(serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+        mut on_chunk: impl FnMut(DocumentChunk) -> Result<(), DocLoaderError>,
+    ) -> Result<UniversalOutput, DocLoaderError> {
+        let options = CsvOptions::from_params(params)?;
+        let mut total_chunks = 0;
+        let mut offset = 0;
+        let mut total_words = 0;
+        let mut estimated_tokens = 0;
+        // La langue du document est détectée sur le début du texte
+        let mut language_sample = String::new();
         
-        for (index, chunk_text) in chunks_text.iter().enumerate() {
-            let text_meta = extract_text_metadata(chunk_text);
+        let summary = self.stream_chunks(reader, dialect, &options, params, |csv_chunk| {
+            let CsvChunk { text: chunk_text, rows: group, line, fields } = csv_chunk;
+            let text_meta = extract_text_metadata(&chunk_text);
             
-            // Estimer quelle partie des données ce chunk représente
-            let estimated_rows = chunk_text.lines().count().saturating_sub(1); // -1 pour l'en-tête
-            
-            let start_offset = if index == 0 { 
-                0 
-            } else { 
-                text.find(chunk_text).unwrap_or(0)
-            };
+            if total_chunks > 0 {
+                offset += 2;
+            }
+            let start_offset = offset;
+            offset += chunk_text.len();
+            total_words += text_meta.word_count;
+            estimated_tokens += text_meta.estimated_tokens;
+            if language_sample.len() < SNIFF_SAMPLE_SIZE {
+                language_sample.push_str(&chunk_text);
+                language_sample.push_str("\n\n");
+            }
+            let index = total_chunks;
+            total_chunks += 1;
             
-            let chunk = DocumentChunk {
+            on_chunk(DocumentChunk {
                 id: format!("csv_chunk_{}", index),
                 content: chunk_text.clone(),
                 chunk_index: index,
                 position: ChunkPosition {
                     page: None,
-                    line: None, // Les CSV sont structurés différemment
+                    line: Some(line as u32),
                     start_offset: Some(start_offset),
                     end_offset: Some(start_offset + chunk_text.len()),
                 },
@@ -199,43 +709,34 @@ impl DocumentProcessor for CsvProcessor {
                     size: chunk_text.len(),
                     language: if params.language_detection { text_meta.detected_language } else { None },
                     confidence: Some(1.0), // Confiance maximale pour l'extraction CSV
-                    format_specific: Some(json!({
-                        "word_count": text_meta.word_count,
-                        "estimated_tokens": text_meta.estimated_tokens,
-                        "estimated_rows": estimated_rows,
-                        "contains_headers": index == 0 // Premier chunk contient probablement les en-têtes
-                    })),
+                    format_specific: Some(match fields {
+                        Some(fields) => json!({
+                            "word_count": text_meta.word_count,
+                            "estimated_tokens": text_meta.estimated_tokens,
+                            "row": group.start + 1,
+                            "fields": fields
+                        }),
+                        None => json!({
+                            "word_count": text_meta.word_count,
+                            "estimated_tokens": text_meta.estimated_tokens,
+                            "first_row": group.start + 1,
+                            "last_row": group.end,
+                            "row_count": group.len(),
+                            "contains_headers": options.template.is_none()
+                        }),
+                    }),
                 },
-            };
-            
-            chunks.push(chunk);
-        }
+            })
+        })?;
         
-        // Métadonnées du fichier
-        let file_metadata = fs::metadata(file_path)?;
-        let csv_metadata = self.extract_csv_metadata(&rows, &headers);
-        let text_meta = extract_text_metadata(&text);
-        
-        let document_metadata = DocumentMetadata {
-            filename: file_path.file_name()
-                .and_then(|name| name.to_str())
-                .unwrap_or("unknown.csv")
-                .to_string(),
-            filepath: file_path.to_string_lossy().to_string(),
-            document_type: DocumentType::CSV,
-            file_size: file_metadata.len(),
-            created_at: file_metadata.created().ok().map(|t| t.into()),
-            modified_at: file_metadata.modified().ok().map(|t| t.into()),
-            title: None, // Les fichiers CSV n'ont généralement pas de titre
-            author: None, // Les fichiers CSV n'ont généralement pas d'auteur
-            format_metadata: json!({
-                "csv_metadata": csv_metadata,
-                "total_words": text_meta.word_count,
-                "total_characters": text_meta.character_count,
-                "estimated_tokens": text_meta.estimated_tokens,
-                "detected_language": text_meta.detected_language
-            }),
-        };
+        let csv_metadata = self.csv_metadata(&summary, dialect);
+        let document_metadata = document_metadata(json!({
+            "csv_metadata": csv_metadata,
+            "total_words": total_words,
+            "total_characters": offset,
+            "estimated_tokens": estimated_tokens,
+            "detected_language": detect_language(&language_sample)
+        }));
         
         let processing_time = start_time.elapsed();
         let processing_info = ProcessingInfo {
@@ -243,83 +744,99 @@ impl DocumentProcessor for CsvProcessor {
             processor_version: self.version().to_string(),
             processed_at: Utc::now(),
             processing_time_ms: processing_time.as_millis() as u64,
-            total_chunks: chunks.len(),
-            total_content_size: text.len(),
+            total_chunks,
+            total_content_size: offset,
             processing_params: params.clone(),
+            warnings: summary.warnings.into_warnings(),
         };
         
         Ok(UniversalOutput {
             document_metadata,
-            chunks,
+            chunks: Vec::new(),
             processing_info,
         })
     }
     
-    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+    /// Traite un fichier CSV en flux, en mémoire bornée quelle que soit sa taille : chaque chunk
+    /// est transmis à `on_chunk` dès qu'il est formé, et la sortie renvoyée ne contient que les
+    /// métadonnées du document (`processing_info.total_chunks` compte les chunks transmis).
+    ///
+    /// ```rust,no_run
+    /// # use doc_loader::processors::csv::CsvProcessor;
+    /// # use doc_loader::ProcessingParams;
+    /// # use std::path::Path;
+    /// let output = CsvProcessor::new().process_file_streaming(Path::new("export.csv"), &ProcessingParams::default(), |chunk| {
+    ///     println!("{}: {} bytes", chunk.id, chunk.content.len());
+    ///     Ok(())
+    /// })?;
+    /// println!("{} chunks", output.processing_info.total_chunks);
+    /// # Ok::<(), doc_loader::DocLoaderError>(())
+    /// ```
+    pub fn process_file_streaming(
+        &self,
+        file_path: &Path,
+        params: &ProcessingParams,
+        on_chunk: impl FnMut(DocumentChunk) -> Result<(), DocLoaderError>,
+    ) -> Result<UniversalOutput, DocLoaderError> {
         let start_time = std::time::Instant::now();
         
-        // Convertir en string
-        let content_str = String::from_utf8_lossy(content);
-        
-        // Parser le CSV depuis le contenu
-        let (rows, headers) = self.parse_csv_content(&content_str)?;
-        
-        // Convertir en texte pour l'extraction
-        let raw_text = self.csv_to_text(&rows, &headers);
-        
-        // Nettoyer le texte si demandé
-        let text = if params.text_cleaning {
-            clean_text(&raw_text)
-        } else {
-            raw_text
-        };
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
         
-        // Découper en chunks
-        let chunks_text = chunk_text(&text, params.max_chunk_size, params.chunk_overlap);
+        // Détecter le dialecte sur le début du fichier, puis le lire en flux
+        let options = CsvOptions::from_params(params)?;
+        let dialect = CsvDialect::detect(&self.read_sample(file_path)?, &options)?;
+        let file_metadata = fs::metadata(file_path)?;
+        let reader = File::open(file_path)?;
         
-        // Créer les chunks avec métadonnées
+        self.process_stream(reader, &dialect, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.csv")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::CSV,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None, // Les fichiers CSV n'ont généralement pas de titre
+            author: None, // Les fichiers CSV n'ont généralement pas d'auteur
+            format_metadata,
+        }, params, start_time, on_chunk)
+    }
+}
+
+impl DocumentProcessor for CsvProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::CSV
+    }
+    
+    /// Conserve tous les chunks en mémoire ; pour les gros exports, préférer
+    /// `process_file_streaming`, dont la mémoire est bornée
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
         let mut chunks = Vec::new();
-        
-        for (index, chunk_text) in chunks_text.iter().enumerate() {
-            let text_meta = extract_text_metadata(chunk_text);
-            let estimated_rows = chunk_text.lines().count().saturating_sub(1);
-            
-            let start_offset = if index == 0 { 
-                0 
-            } else { 
-                text.find(chunk_text).unwrap_or(0)
-            };
-            
-            let chunk = DocumentChunk {
-                id: format!("csv_chunk_{}", index),
-                content: chunk_text.clone(),
-                chunk_index: index,
-                position: ChunkPosition {
-                    page: None,
-                    line: None,
-                    start_offset: Some(start_offset),
-                    end_offset: Some(start_offset + chunk_text.len()),
-                },
-                metadata: ChunkMetadata {
-                    size: chunk_text.len(),
-                    language: if params.language_detection { text_meta.detected_language } else { None },
-                    confidence: Some(1.0),
-                    format_specific: Some(json!({
-                        "word_count": text_meta.word_count,
-                        "estimated_tokens": text_meta.estimated_tokens,
-                        "estimated_rows": estimated_rows,
-                        "contains_headers": index == 0
-                    })),
-                },
-            };
-            
+        let mut output = self.process_file_streaming(file_path, params, |chunk| {
             chunks.push(chunk);
-        }
+            Ok(())
+        })?;
+        output.chunks = chunks;
+        Ok(output)
+    }
+    
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
         
-        let csv_metadata = self.extract_csv_metadata(&rows, &headers);
-        let text_meta = extract_text_metadata(&text);
+        // Convertir en string pour la détection du dialecte
+        let content_str = String::from_utf8_lossy(content);
+        let options = CsvOptions::from_params(params)?;
+        let dialect = CsvDialect::detect(&content_str, &options)?;
         
-        let document_metadata = DocumentMetadata {
+        let mut chunks = Vec::new();
+        let mut output = self.process_stream(content, &dialect, |format_metadata| DocumentMetadata {
             filename: filename.to_string(),
             filepath: format!("memory://{}", filename),
             document_type: DocumentType::CSV,
@@ -328,31 +845,48 @@ impl DocumentProcessor for CsvProcessor {
             modified_at: Some(Utc::now()),
             title: None,
             author: None,
-            format_metadata: json!({
-                "csv_metadata": csv_metadata,
-                "total_words": text_meta.word_count,
-                "total_characters": text_meta.character_count,
-                "estimated_tokens": text_meta.estimated_tokens,
-                "detected_language": text_meta.detected_language
-            }),
-        };
+            format_metadata,
+        }, params, start_time, |chunk| {
+            chunks.push(chunk);
+            Ok(())
+        })?;
+        output.chunks = chunks;
+        Ok(output)
+    }
+    
+    fn process_decompressed(&self, reader: &mut dyn Read, filename: &str, params: &ProcessingParams, _registry: &ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
         
-        let processing_time = start_time.elapsed();
-        let processing_info = ProcessingInfo {
-            processor: "CsvProcessor".to_string(),
-            processor_version: self.version().to_string(),
-            processed_at: Utc::now(),
-            processing_time_ms: processing_time.as_millis() as u64,
-            total_chunks: chunks.len(),
-            total_content_size: text.len(),
-            processing_params: params.clone(),
-        };
+        // Les chunks sont conservés en mémoire : le flux reste borné par `max_decompressed_size`
+        let mut reader = BoundedReader::new(reader, params)?;
         
-        Ok(UniversalOutput {
-            document_metadata,
-            chunks,
-            processing_info,
-        })
+        // Détecter le dialecte sur le début du flux, puis le relire avec le reste
+        let mut sample = Vec::new();
+        (&mut reader).take(SNIFF_SAMPLE_SIZE as u64 + 4).read_to_end(&mut sample)?;
+        let options = CsvOptions::from_params(params)?;
+        let dialect = CsvDialect::detect(&String::from_utf8_lossy(&sample), &options)?;
+        
+        let mut chunks = Vec::new();
+        let result = self.process_stream(Cursor::new(sample).chain(&mut reader), &dialect, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::CSV,
+            file_size: 0, // Complétée une fois le flux lu
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time, |chunk| {
+            chunks.push(chunk);
+            Ok(())
+        });
+        // Un flux tronqué par la limite peut échouer au milieu d'un enregistrement : le dépassement prime
+        let file_size = reader.check(filename)?;
+        let mut output = result?;
+        output.document_metadata.file_size = file_size;
+        output.chunks = chunks;
+        Ok(output)
     }
 }
 
@@ -373,16 +907,14 @@ mod tests {
     }
 
     #[test]
-    fn test_csv_to_text() {
+    fn test_row_to_text() {
         let processor = CsvProcessor::new();
         let headers = vec!["name".to_string(), "age".to_string(), "city".to_string()];
-        let rows = vec![
-            vec!["John".to_string(), "30".to_string(), "New York".to_string()],
-            vec!["Jane".to_string(), "25".to_string(), "Boston".to_string()],
-        ];
+        let row = vec!["John".to_string(), "30".to_string(), "New York".to_string()];
         
-        let text = processor.csv_to_text(&rows, &headers);
-        assert!(text.contains("Headers: name, age, city"));
+        assert_eq!(processor.header_line(&headers), "Headers: name, age, city");
+        let text = processor.row_to_text(1, &row, &headers);
+        assert!(text.starts_with("Row 1: "));
         assert!(text.contains("name=\"John\""));
         assert!(text.contains("age=\"30\""));
     }
@@ -401,4 +933,207 @@ mod tests {
         assert_eq!(output.document_metadata.document_type.to_string(), "CSV");
         assert!(!output.chunks.is_empty());
     }
+
+    #[test]
+    fn test_row_aware_chunks() {
+        let processor = CsvProcessor::new();
+        let mut content = String::from("id,description\n");
+        for id in 1..=30 {
+            content.push_str(&format!("{},\"Item number {} with a multi-word description\"\n", id, id));
+        }
+        let params = ProcessingParams::default().with_chunk_size(300);
+        
+        let output = processor.process_content(content.as_bytes(), "items.csv", &params).unwrap();
+        assert!(output.chunks.len() > 1);
+        
+        let mut expected_first_row = 1;
+        for chunk in &output.chunks {
+            let metadata = chunk.metadata.format_specific.as_ref().unwrap();
+            let first_row = metadata["first_row"].as_u64().unwrap();
+            let last_row = metadata["last_row"].as_u64().unwrap();
+            assert_eq!(first_row, expected_first_row);
+            assert!(chunk.content.starts_with("Headers: id, description\n\n"));
+            assert!(chunk.content.contains(&format!("Row {}: ", first_row)));
+            assert!(chunk.content.ends_with("with a multi-word description\""));
+            assert_eq!(chunk.position.line, Some(first_row as u32 + 1));
+            expected_first_row = last_row + 1;
+        }
+        assert_eq!(expected_first_row, 31);
+    }
+
+    #[test]
+    fn test_record_mode() {
+        let processor = CsvProcessor::new();
+        let content = "isbn,title,author,price,availability\n\
+                       9780134685991,Effective Java,Joshua Bloch,54.99,In Stock\n\
+                       9780132350884,Clean Code,Robert C. Martin,42.99,Out of Stock\n";
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "csv": { "record_mode": true, "text_columns": ["title", "author"] } }));
+        
+        let output = processor.process_content(content.as_bytes(), "books.csv", &params).unwrap();
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[0].content, "title: Effective Java\nauthor: Joshua Bloch");
+        
+        let metadata = output.chunks[0].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(metadata["row"], 1);
+        assert_eq!(metadata["fields"]["isbn"], 9780134685991u64);
+        assert_eq!(metadata["fields"]["price"], 54.99);
+        assert_eq!(metadata["fields"]["availability"], "In Stock");
+        assert!(metadata["fields"].get("title").is_none());
+    }
+
+    #[test]
+    fn test_templates() {
+        let processor = CsvProcessor::new();
+        let content = "title,author,year,internal_id\n\
+                       Effective Java,Joshua Bloch,2018,A1\n\
+                       Untitled Notes,,,A2\n";
+        let params = ProcessingParams::default().with_format_specific(json!({ "csv": {
+            "template": "{title} by {author} ({year|n.d.})",
+            "missing_value": "unknown"
+        } }));
+        
+        let output = processor.process_content(content.as_bytes(), "books.csv", &params).unwrap();
+        assert_eq!(output.chunks.len(), 1);
+        assert_eq!(output.chunks[0].content, "Effective Java by Joshua Bloch (2018)\nUntitled Notes by unknown (n.d.)");
+        
+        // Sans gabarit, les colonnes exclues disparaissent du texte et des métadonnées
+        let params = ProcessingParams::default().with_format_specific(json!({ "csv": {
+            "record_mode": true,
+            "text_columns": ["title"],
+            "exclude_columns": ["internal_id"]
+        } }));
+        let output = processor.process_content(content.as_bytes(), "books.csv", &params).unwrap();
+        let metadata = output.chunks[0].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(output.chunks[0].content, "title: Effective Java");
+        assert_eq!(metadata["fields"], json!({ "author": "Joshua Bloch", "year": 2018 }));
+    }
+
+    #[test]
+    fn test_streaming() {
+        let dir = std::env::temp_dir().join(format!("doc_loader_csv_stream_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("events.csv");
+        let mut content = String::from("id,user,status\n");
+        for i in 0..5000 {
+            content.push_str(&format!("{},user{},{}\n", i, i % 1200, if i % 3 == 0 { "ok" } else { "failed" }));
+        }
+        fs::write(&path, &content).unwrap();
+        
+        let params = ProcessingParams::default().with_chunk_size(2000);
+        let mut streamed = Vec::new();
+        let output = CsvProcessor::new().process_file_streaming(&path, &params, |chunk| {
+            streamed.push((chunk.chunk_index, chunk.position.line));
+            Ok(())
+        });
+        let collected = CsvProcessor::new().process_file(&path, &params);
+        fs::remove_dir_all(&dir).unwrap();
+        let (output, collected) = (output.unwrap(), collected.unwrap());
+        
+        assert!(output.chunks.is_empty());
+        assert_eq!(output.processing_info.total_chunks, streamed.len());
+        assert_eq!(collected.chunks.len(), streamed.len());
+        assert_eq!(streamed[0], (0, Some(2)));
+        
+        let csv_metadata = &output.document_metadata.format_metadata["csv_metadata"];
+        assert_eq!(csv_metadata["total_rows"], 5000);
+        assert_eq!(csv_metadata["column_statistics"][1]["unique_values"], 1200);
+        assert_eq!(csv_metadata["column_statistics"][2]["data_type"], "categorical");
+    }
+
+    #[test]
+    fn test_lenient_parsing() {
+        let processor = CsvProcessor::new();
+        let mut content = b"id,name,city\n1,Ada,London\n2,Alan\n3,Grace,New York,USA\n4,\"Edsger,Rotterdam\n\
+                            5,Barbara,Boston\n6,Don,Stan".to_vec();
+        content.extend_from_slice(b"\xff\xfeford\n7,\"Multi\nline\",Paris\n");
+        
+        let strict = processor.process_content(&content, "people.csv", &ProcessingParams::default());
+        assert!(matches!(strict, Err(DocLoaderError::Processing(_))));
+        
+        // En lecture stricte, un octet UTF-8 invalide est une erreur et non un remplacement silencieux
+        let strict = processor.process_content(b"id,name\n1,Ada\n2,Stan\xffford\n", "people.csv", &ProcessingParams::default());
+        assert!(matches!(strict, Err(DocLoaderError::Processing(message)) if message.starts_with("Invalid UTF-8 in CSV record at line 3")));
+        
+        let params = ProcessingParams::default().with_format_specific(json!({ "csv": { "on_error": "repair" } }));
+        let output = processor.process_content(&content, "people.csv", &params).unwrap();
+        let warnings: Vec<(Option<u64>, &str)> = output.processing_info.warnings.iter()
+            .map(|warning| (warning.line, warning.message.as_str()))
+            .collect();
+        assert_eq!(warnings, vec![
+            (Some(3), "Record repaired: expected 3 fields, found 2 (padded with empty fields)"),
+            (Some(4), "Record repaired: expected 3 fields, found 4 (extra fields merged into the last column)"),
+            (Some(5), "Record repaired: unbalanced quote (line read without quoting)"),
+            (Some(7), "Record repaired: invalid UTF-8 (replaced with U+FFFD)"),
+        ]);
+        let csv_metadata = &output.document_metadata.format_metadata["csv_metadata"];
+        assert_eq!(csv_metadata["total_rows"], 7);
+        assert!(output.chunks[0].content.contains("city=\"New York,USA\""));
+        assert!(output.chunks[0].content.contains("name=\"Multi line\", city=\"Paris\""));
+        
+        // La politique est insensible à la casse
+        let params = ProcessingParams::default().with_format_specific(json!({ "csv": { "on_error": "Skip" } }));
+        let output = processor.process_content(&content, "people.csv", &params).unwrap();
+        let csv_metadata = &output.document_metadata.format_metadata["csv_metadata"];
+        assert_eq!(csv_metadata["total_rows"], 3);
+        assert_eq!(csv_metadata["skipped_rows"], 4);
+        assert_eq!(output.processing_info.warnings.len(), 4);
+        
+        // Une politique inconnue est signalée au lieu de rétablir la lecture stricte
+        let params = ProcessingParams::default().with_format_specific(json!({ "csv": { "on_error": "ignore" } }));
+        let error = processor.process_content(&content, "people.csv", &params).unwrap_err();
+        assert!(matches!(&error, DocLoaderError::InvalidFormat(message) if message.contains("unknown variant `ignore`")), "{}", error);
+    }
+
+    #[test]
+    fn test_sniff_dialect() {
+        let options = CsvOptions::default();
+
+        // Virgule décimale : le point-virgule reste le séparateur le plus régulier
+        let european = "nom;prix;origine\nPomme;1,5;France\nPoire;2,25;Belgique\n";
+        assert_eq!(CsvDialect::detect(european, &options).unwrap().delimiter, b';');
+        assert_eq!(CsvDialect::detect("a\tb\n1\t2\n3\t4\n", &options).unwrap().delimiter, b'\t');
+        assert_eq!(CsvDialect::detect("a|b|c\nx|\"y, z\"|1\n", &options).unwrap().delimiter, b'|');
+
+        let single_quoted = "name,comment\n'Ada','Hello, world'\n'Alan','Hi'\n";
+        let dialect = CsvDialect::detect(single_quoted, &options).unwrap();
+        assert_eq!((dialect.delimiter, dialect.quote), (b',', b'\''));
+
+        let backslashed = "id,quote\n1,\"She said \\\"hi\\\"\"\n2,\"plain\"\n";
+        assert_eq!(CsvDialect::detect(backslashed, &options).unwrap().escape, Some(b'\\'));
+    }
+
+    #[test]
+    fn test_sniff_header() {
+        let options = CsvOptions::default();
+        assert!(CsvDialect::detect("name,age\nJohn,30\nJane,25\n", &options).unwrap().has_headers);
+        assert!(!CsvDialect::detect("1,2.5\n3,4.5\n5,6.5\n", &options).unwrap().has_headers);
+
+        let processor = CsvProcessor::new();
+        let output = processor.process_content(b"10;20\n30;40\n", "numbers.csv", &ProcessingParams::default()).unwrap();
+        let csv_metadata = &output.document_metadata.format_metadata["csv_metadata"];
+        assert_eq!(csv_metadata["headers"], json!(["column_1", "column_2"]));
+        assert_eq!(csv_metadata["total_rows"], 2);
+    }
+
+    #[test]
+    fn test_dialect_overrides() {
+        let processor = CsvProcessor::new();
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "csv": { "delimiter": ";", "has_headers": false } }));
+
+        let output = processor.process_content(b"a;b,c\nd;e,f\n", "export.tsv", &params).unwrap();
+        let csv_metadata = &output.document_metadata.format_metadata["csv_metadata"];
+        assert_eq!(csv_metadata["dialect"]["delimiter"], ";");
+        assert_eq!(csv_metadata["dialect"]["has_headers"], false);
+        assert_eq!(csv_metadata["total_rows"], 2);
+        assert_eq!(csv_metadata["headers"], json!(["column_1", "column_2"]));
+
+        // Un caractère non ASCII serait tronqué en octet : il est refusé
+        for option in ["delimiter", "quote", "escape"] {
+            let params = ProcessingParams::default().with_format_specific(json!({ "csv": { option: "§" } }));
+            let error = processor.process_content(b"a;b\n1;2\n", "export.csv", &params).unwrap_err();
+            assert!(matches!(&error, DocLoaderError::InvalidFormat(message) if message.contains(&format!("{} '§' is not an ASCII character", option))), "{}", error);
+        }
+    }
 }
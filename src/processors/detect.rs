@@ -0,0 +1,416 @@
+//! Détection du type de document par signature (magic bytes) et heuristiques de contenu.
+//!
+//! Les signatures binaires (`%PDF-`, `{\rtf`, conteneurs ZIP OOXML/ODF/EPUB) font foi ; les
+//! heuristiques textuelles (JSON, CSV, XML, ...) ne servent qu'en l'absence d'extension connue.
+
+use crate::core::DocumentType;
+use crate::error::DocLoaderError;
+use crate::processors::json;
+
+use std::fs::File;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+use encoding_rs::{UTF_16BE, UTF_16LE};
+use zip::ZipArchive;
+
+/// Nombre d'octets lus en tête de fichier pour la détection
+pub(crate) const HEAD_SIZE: usize = 8192;
+
+/// Nombre de lignes examinées par l'heuristique CSV
+const CSV_SAMPLE_LINES: usize = 20;
+
+/// Résultat d'une détection : les signatures binaires sont fiables, les heuristiques textuelles non
+#[derive(Debug, Clone, PartialEq)]
+struct Detection {
+    doc_type: DocumentType,
+    signature: bool,
+}
+
+impl Detection {
+    fn signature(doc_type: DocumentType) -> Self {
+        Self { doc_type, signature: true }
+    }
+
+    fn heuristic(doc_type: DocumentType) -> Self {
+        Self { doc_type, signature: false }
+    }
+}
+
+/// Détecte le type d'un document à partir de son contenu complet.
+///
+/// Retourne `None` pour un contenu binaire non reconnu (ou une archive ZIP ordinaire).
+///
+/// ```rust
+/// use doc_loader::{detect_type, DocumentType};
+///
+/// assert_eq!(detect_type(b"%PDF-1.7\n..."), Some(DocumentType::PDF));
+/// assert_eq!(detect_type(b"{\"title\": \"Guide\"}"), Some(DocumentType::JSON));
+/// assert_eq!(detect_type(b"name,city\nAda,London\n"), Some(DocumentType::CSV));
+/// ```
+pub fn detect_type(content: &[u8]) -> Option<DocumentType> {
+    sniff(content, true, || zip_container(Cursor::new(content))).map(|detection| detection.doc_type)
+}
+
+/// Détecte le type d'un document, l'extension servant d'indice.
+///
+/// Une signature binaire l'emporte toujours sur l'extension ; une extension binaire (`.pdf`,
+/// `.docx`, ...) démentie par le contenu est ignorée ; sinon l'extension est conservée.
+pub fn detect_type_with_hint(content: &[u8], hint: Option<DocumentType>) -> Option<DocumentType> {
+    resolve(sniff(content, true, || zip_container(Cursor::new(content))), hint)
+}
+
+/// Détecte le type d'un fichier à partir de ses premiers octets et de son extension
+pub(crate) fn detect_file_type(file_path: &Path, head: &[u8], hint: Option<DocumentType>) -> Option<DocumentType> {
+    let complete = head.len() < HEAD_SIZE;
+    resolve(sniff(head, complete, || File::open(file_path).ok().and_then(zip_container)), hint)
+}
+
+/// Détecte le type d'un flux à partir de ses premiers octets (au plus `HEAD_SIZE`)
+pub(crate) fn detect_stream_type(head: &[u8], hint: Option<DocumentType>) -> Option<DocumentType> {
+    resolve(sniff(head, head.len() < HEAD_SIZE, || zip_container(Cursor::new(head))), hint)
+}
+
+/// Lit les premiers octets d'un fichier pour en reconnaître la signature
+pub(crate) fn read_head(file_path: &Path) -> Result<Vec<u8>, DocLoaderError> {
+    let mut head = Vec::with_capacity(HEAD_SIZE);
+    File::open(file_path)?.take(HEAD_SIZE as u64).read_to_end(&mut head)?;
+    Ok(head)
+}
+
+fn resolve(detection: Option<Detection>, hint: Option<DocumentType>) -> Option<DocumentType> {
+    match (detection, hint) {
+        (Some(detection), _) if detection.signature => Some(detection.doc_type),
+        (Some(detection), Some(hint)) if is_binary(&hint) => Some(detection.doc_type),
+        (_, Some(hint)) => Some(hint),
+        (detection, None) => detection.map(|detection| detection.doc_type),
+    }
+}
+
+/// Formats reconnaissables uniquement par signature
+fn is_binary(doc_type: &DocumentType) -> bool {
+    matches!(
+        doc_type,
+        DocumentType::PDF | DocumentType::DOCX | DocumentType::PPTX | DocumentType::ODT
+            | DocumentType::ODS | DocumentType::ODP | DocumentType::EPUB | DocumentType::RTF
+    )
+}
+
+/// `complete` indique si `head` contient tout le document (sinon les heuristiques restent prudentes)
+fn sniff(head: &[u8], complete: bool, container: impl FnOnce() -> Option<DocumentType>) -> Option<Detection> {
+    if is_pdf(head) {
+        // Un texte qui commence par `%PDF-` (extrait, documentation) garde son extension textuelle
+        return Some(if decode_text(head).is_some() {
+            Detection::heuristic(DocumentType::PDF)
+        } else {
+            Detection::signature(DocumentType::PDF)
+        });
+    }
+    if head.starts_with(b"PK\x03\x04") {
+        return container().map(Detection::signature);
+    }
+
+    let text = decode_text(head)?;
+    let text = text.trim_start();
+    if text.starts_with("{\\rtf") {
+        return Some(Detection::signature(DocumentType::RTF));
+    }
+    sniff_text(text, complete).map(Detection::heuristic)
+}
+
+/// Signature `%PDF-`, précédée au plus d'espaces ou de quelques octets binaires parasites (jamais de texte)
+fn is_pdf(head: &[u8]) -> bool {
+    let Some(position) = head[..head.len().min(1024)].windows(5).position(|window| window == b"%PDF-") else {
+        return false;
+    };
+    let prefix = &head[..position];
+    prefix.iter().all(u8::is_ascii_whitespace) || decode_text(prefix).is_none()
+}
+
+/// Type d'un conteneur ZIP : `mimetype` (ODF, EPUB) ou `[Content_Types].xml` (OOXML)
+fn zip_container<R: Read + Seek>(reader: R) -> Option<DocumentType> {
+    let mut archive = ZipArchive::new(reader).ok()?;
+
+    if let Ok(entry) = archive.by_name("mimetype") {
+        let mut mimetype = String::new();
+        entry.take(128).read_to_string(&mut mimetype).ok()?;
+        return match mimetype.trim() {
+            "application/vnd.oasis.opendocument.text" => Some(DocumentType::ODT),
+            "application/vnd.oasis.opendocument.spreadsheet" => Some(DocumentType::ODS),
+            "application/vnd.oasis.opendocument.presentation" => Some(DocumentType::ODP),
+            "application/epub+zip" => Some(DocumentType::EPUB),
+            _ => None,
+        };
+    }
+
+    let mut content_types = String::new();
+    archive.by_name("[Content_Types].xml").ok()?
+        .take(1024 * 1024)
+        .read_to_string(&mut content_types)
+        .ok()?;
+    if content_types.contains("wordprocessingml") {
+        Some(DocumentType::DOCX)
+    } else if content_types.contains("presentationml") {
+        Some(DocumentType::PPTX)
+    } else {
+        None
+    }
+}
+
+/// Décode un contenu textuel (BOM UTF-8/UTF-16 respecté) ; `None` pour un contenu binaire
+fn decode_text(bytes: &[u8]) -> Option<String> {
+    let text = if let Some(rest) = bytes.strip_prefix(&[0xef, 0xbb, 0xbf]) {
+        String::from_utf8_lossy(rest).into_owned()
+    } else if bytes.starts_with(&[0xff, 0xfe]) {
+        UTF_16LE.decode_with_bom_removal(bytes).0.into_owned()
+    } else if bytes.starts_with(&[0xfe, 0xff]) {
+        UTF_16BE.decode_with_bom_removal(bytes).0.into_owned()
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    };
+
+    let total = text.chars().count();
+    let suspicious = text.chars()
+        .filter(|c| *c == '\u{fffd}' || (c.is_control() && !matches!(c, '\n' | '\r' | '\t' | '\u{c}')))
+        .count();
+    if text.contains('\0') || suspicious * 20 > total {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+fn sniff_text(text: &str, complete: bool) -> Option<DocumentType> {
+    if text.is_empty() {
+        return Some(DocumentType::TXT);
+    }
+    if text.starts_with('{') || text.starts_with('[') {
+        if let Some(doc_type) = sniff_json(text, complete) {
+            return Some(doc_type);
+        }
+    }
+    if text.starts_with("WEBVTT") {
+        return Some(DocumentType::VTT);
+    }
+    if text.starts_with("<?xml") || (is_xml_root(text, complete) && !text.chars().take(64).collect::<String>().to_lowercase().contains("html")) {
+        return Some(DocumentType::XML);
+    }
+    if text.starts_with("\\documentclass") || text.contains("\\begin{document}") {
+        return Some(DocumentType::TEX);
+    }
+
+    let lines: Vec<&str> = text.lines().take(CSV_SAMPLE_LINES + 1).collect();
+    if is_mbox(&lines) {
+        return Some(DocumentType::MBOX);
+    }
+    if is_email(&lines) {
+        return Some(DocumentType::EML);
+    }
+    if is_srt(&lines) {
+        return Some(DocumentType::SRT);
+    }
+    // La dernière ligne d'un extrait tronqué peut être incomplète
+    let sample = if !complete && lines.len() <= CSV_SAMPLE_LINES {
+        &lines[..lines.len().saturating_sub(1)]
+    } else {
+        &lines[..lines.len().min(CSV_SAMPLE_LINES)]
+    };
+    if is_csv(sample) {
+        return Some(DocumentType::CSV);
+    }
+    Some(DocumentType::TXT)
+}
+
+/// Élément racine XML : `<nom ...>` ou `<nom/>`, refermé par `</nom>` quand le document est complet
+fn is_xml_root(text: &str, complete: bool) -> bool {
+    let Some(rest) = text.strip_prefix('<') else {
+        return false;
+    };
+    let name_end = rest.find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'))).unwrap_or(rest.len());
+    let name = &rest[..name_end];
+    if !name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        return false;
+    }
+    let after = &rest[name_end..];
+    if !after.starts_with(|c: char| c.is_whitespace() || c == '>' || c == '/') {
+        return false;
+    }
+    let Some(tag_end) = after.find('>') else {
+        return false;
+    };
+    after[..tag_end].ends_with('/') || !complete || text.trim_end().ends_with(&format!("</{}>", name))
+}
+
+fn sniff_json(text: &str, complete: bool) -> Option<DocumentType> {
+    if complete {
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+            // JSON Lines : un document par ligne, invalide comme document unique
+            return json::looks_like_json_lines(text.as_bytes()).then_some(DocumentType::JSON);
+        };
+        let notebook = value.get("cells").is_some_and(|cells| cells.is_array())
+            || value.get("worksheets").is_some_and(|worksheets| worksheets.is_array());
+        return Some(if notebook && value.get("nbformat").is_some() { DocumentType::IPYNB } else { DocumentType::JSON });
+    }
+
+    // Extrait tronqué : le premier jeton après l'ouverture doit être une valeur JSON
+    // (et non `[section]` d'un fichier TOML ou INI)
+    let after = text[1..].trim_start();
+    let value_start = after.starts_with(['"', '{', '[', ']', '}', '-'])
+        || after.starts_with(|c: char| c.is_ascii_digit())
+        || ["true", "false", "null"].iter().any(|word| after.starts_with(word));
+    if !value_start {
+        return None;
+    }
+    if text.contains("\"cell_type\"") && text.contains("\"cells\"") {
+        Some(DocumentType::IPYNB)
+    } else {
+        Some(DocumentType::JSON)
+    }
+}
+
+fn is_header_line(line: &str) -> Option<String> {
+    let (name, _) = line.split_once(':')?;
+    (!name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+        .then(|| name.to_ascii_lowercase())
+}
+
+/// En-têtes RFC 5322 en début de contenu (au moins deux en-têtes caractéristiques)
+fn is_email(lines: &[&str]) -> bool {
+    const EMAIL_HEADERS: [&str; 8] = ["from", "to", "subject", "date", "message-id", "received", "mime-version", "return-path"];
+    let mut known = 0;
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+        match is_header_line(line) {
+            Some(name) if EMAIL_HEADERS.contains(&name.as_str()) => known += 1,
+            Some(_) => {},
+            None => return false,
+        }
+    }
+    known >= 2
+}
+
+/// Séparateur mbox `From <expéditeur> <date>` suivi d'en-têtes
+fn is_mbox(lines: &[&str]) -> bool {
+    lines.first().is_some_and(|line| line.starts_with("From ") && line.split_whitespace().count() >= 3)
+        && is_email(&lines[1..])
+}
+
+/// Bloc SRT : numéro de séquence puis ligne de temps `00:00:01,000 --> 00:00:02,000`
+fn is_srt(lines: &[&str]) -> bool {
+    let mut lines = lines.iter().map(|line| line.trim()).filter(|line| !line.is_empty());
+    let index = lines.next().unwrap_or_default();
+    let timing = lines.next().unwrap_or_default();
+    !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()) && timing.contains("-->")
+}
+
+/// Au moins deux lignes contenant le même nombre (non nul) d'un même séparateur hors guillemets
+fn is_csv(lines: &[&str]) -> bool {
+    let lines: Vec<&str> = lines.iter().copied().filter(|line| !line.trim().is_empty()).collect();
+    if lines.len() < 2 {
+        return false;
+    }
+    [',', ';', '\t', '|'].iter().any(|&delimiter| {
+        let first = count_delimiters(lines[0], delimiter);
+        first > 0 && lines.iter().all(|line| count_delimiters(line, delimiter) == first)
+    })
+}
+
+fn count_delimiters(line: &str, delimiter: char) -> usize {
+    let mut in_quotes = false;
+    line.chars()
+        .filter(|&c| {
+            if c == '"' {
+                in_quotes = !in_quotes;
+            }
+            c == delimiter && !in_quotes
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    fn build_zip(entries: &[(&str, &str)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_detect_binary_signatures() {
+        assert_eq!(detect_type(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n"), Some(DocumentType::PDF));
+        assert_eq!(detect_type(b"{\\rtf1\\ansi Hello}"), Some(DocumentType::RTF));
+
+        let docx = build_zip(&[
+            ("[Content_Types].xml", "<Types><Override ContentType=\"application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml\"/></Types>"),
+            ("word/document.xml", "<w:document/>"),
+        ]);
+        assert_eq!(detect_type(&docx), Some(DocumentType::DOCX));
+
+        let odp = build_zip(&[("mimetype", "application/vnd.oasis.opendocument.presentation")]);
+        assert_eq!(detect_type(&odp), Some(DocumentType::ODP));
+        let epub = build_zip(&[("mimetype", "application/epub+zip")]);
+        assert_eq!(detect_type(&epub), Some(DocumentType::EPUB));
+
+        // Archive ZIP ordinaire et contenu binaire : non reconnus
+        assert_eq!(detect_type(&build_zip(&[("notes.txt", "hello")])), None);
+        assert_eq!(detect_type(&[0x00, 0x01, 0x02, 0xff, 0x00, 0x10]), None);
+    }
+
+    #[test]
+    fn test_detect_text_formats() {
+        assert_eq!(detect_type(b"  [{\"id\": 1}, {\"id\": 2}]"), Some(DocumentType::JSON));
+        assert_eq!(detect_type(b"{\"cells\": [], \"nbformat\": 4, \"metadata\": {}}"), Some(DocumentType::IPYNB));
+        assert_eq!(detect_type(b"{\"a\":1}\n{\"b\":2}\n"), Some(DocumentType::JSON));
+        assert_eq!(detect_type(b"[1, 2]\n\n[3, 4]\n"), Some(DocumentType::JSON));
+        assert_eq!(detect_type(b"{\"a\":1}\nnot json\n"), Some(DocumentType::TXT));
+        assert_eq!(detect_type(b"[package]\nname = \"demo\"\n"), Some(DocumentType::TXT));
+        assert_eq!(detect_type(b"<?xml version=\"1.0\"?><catalog/>"), Some(DocumentType::XML));
+        assert_eq!(detect_type(b"<catalog id=\"1\"><book/></catalog>\n"), Some(DocumentType::XML));
+        assert_eq!(detect_type(b"<3 love this, truly\n"), Some(DocumentType::TXT));
+        assert_eq!(detect_type(b"<b>bold</b> then plain text\n"), Some(DocumentType::TXT));
+        assert_eq!(detect_type(b"WEBVTT\n\n00:00.000 --> 00:01.000\nHi\n"), Some(DocumentType::VTT));
+        assert_eq!(detect_type(b"1\n00:00:01,000 --> 00:00:02,000\nHi\n"), Some(DocumentType::SRT));
+        assert_eq!(detect_type(b"From: ada@example.com\nTo: alan@example.com\nSubject: Hi\n\nBody"), Some(DocumentType::EML));
+        assert_eq!(detect_type(b"From ada@example.com Mon Jan  1 00:00:00 2024\nFrom: ada@example.com\nSubject: Hi\n\nBody"), Some(DocumentType::MBOX));
+        assert_eq!(detect_type(b"\\documentclass{article}\n\\begin{document}Hi\\end{document}"), Some(DocumentType::TEX));
+        assert_eq!(detect_type(b"name;city\nAda;London\n\"Turing; Alan\";Wilmslow\n"), Some(DocumentType::CSV));
+        assert_eq!(detect_type(b"Just a sentence, with a comma.\nAnd another line.\n"), Some(DocumentType::TXT));
+    }
+
+    #[test]
+    fn test_detect_bom() {
+        assert_eq!(detect_type(b"\xef\xbb\xbfname,city\nAda,London\n"), Some(DocumentType::CSV));
+
+        let utf16: Vec<u8> = [0xff, 0xfe].into_iter()
+            .chain("{\"title\": \"Guide\"}".encode_utf16().flat_map(|unit| unit.to_le_bytes()))
+            .collect();
+        assert_eq!(detect_type(&utf16), Some(DocumentType::JSON));
+    }
+
+    #[test]
+    fn test_extension_hint() {
+        // L'extension textuelle est conservée, une extension binaire démentie est ignorée
+        assert_eq!(detect_type_with_hint(b"fn main() {}\n", Some(DocumentType::Code)), Some(DocumentType::Code));
+        assert_eq!(detect_type_with_hint(b"a,b\n1,2\n", Some(DocumentType::TXT)), Some(DocumentType::TXT));
+        assert_eq!(detect_type_with_hint(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n", Some(DocumentType::TXT)), Some(DocumentType::PDF));
+        assert_eq!(detect_type_with_hint(b"\x00\x01\xff%PDF-1.4\n%\xe2\xe3\xcf\xd3\n", None), Some(DocumentType::PDF));
+
+        // `%PDF-` dans un texte ne fait pas un PDF ; en tête d'un texte, l'extension l'emporte
+        assert_eq!(detect_type_with_hint(b"%PDF-1.7\n", Some(DocumentType::TXT)), Some(DocumentType::TXT));
+        assert_eq!(detect_type_with_hint(b"Header bytes are %PDF-1.7", Some(DocumentType::TXT)), Some(DocumentType::TXT));
+        assert_eq!(detect_type(b"{\"magic\": \"%PDF-\"}"), Some(DocumentType::JSON));
+        assert_eq!(detect_type_with_hint(b"{\"a\": 1}", Some(DocumentType::PDF)), Some(DocumentType::JSON));
+        assert_eq!(detect_type_with_hint(b"plain notes", None), Some(DocumentType::TXT));
+    }
+}
@@ -179,6 +179,7 @@ impl DocumentProcessor for DocxProcessor {
             total_chunks: chunks.len(),
             total_content_size: text.len(),
             processing_params: params.clone(),
+            warnings: Vec::new(),
         };
         
         Ok(UniversalOutput {
@@ -280,6 +281,7 @@ impl DocumentProcessor for DocxProcessor {
             total_chunks: chunks.len(),
             total_content_size: text.len(),
             processing_params: params.clone(),
+            warnings: Vec::new(),
         };
         
         Ok(UniversalOutput {
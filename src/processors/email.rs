@@ -0,0 +1,725 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::processors::registry::ProcessorRegistry;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata, html_to_text, normalize_line_breaks};
+
+use std::io::Cursor;
+use std::path::Path;
+use std::fs;
+use chrono::{DateTime, Utc};
+use mail_parser::mailbox::mbox::MessageIterator;
+use mail_parser::{Address, Message, MessageParser, MimeHeaders, PartType};
+use serde_json::{json, Value};
+
+/// Profondeur maximale des messages imbriqués (`message/rfc822`)
+const MAX_NESTING_DEPTH: usize = 8;
+
+/// Extensions associées aux types MIME courants, pour les pièces jointes sans nom de fichier
+const MIME_EXTENSIONS: &[(&str, &str)] = &[
+    ("application/pdf", "pdf"),
+    ("text/plain", "txt"),
+    ("text/csv", "csv"),
+    ("application/json", "json"),
+    ("application/xml", "xml"),
+    ("text/xml", "xml"),
+    ("application/rtf", "rtf"),
+    ("text/rtf", "rtf"),
+    ("application/epub+zip", "epub"),
+    ("application/vnd.openxmlformats-officedocument.wordprocessingml.document", "docx"),
+    ("application/vnd.openxmlformats-officedocument.presentationml.presentation", "pptx"),
+    ("application/vnd.oasis.opendocument.text", "odt"),
+    ("application/vnd.oasis.opendocument.spreadsheet", "ods"),
+    ("application/vnd.oasis.opendocument.presentation", "odp"),
+    ("message/rfc822", "eml"),
+];
+
+/// Message extrait d'un fichier `.eml` ou d'une boîte mbox
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    /// Position du message dans le fichier (messages imbriqués inclus)
+    pub index: usize,
+    /// Index du message englobant pour un message transféré en pièce jointe
+    pub parent_index: Option<usize>,
+    pub from: Option<String>,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub subject: Option<String>,
+    pub date: Option<DateTime<Utc>>,
+    pub message_id: Option<String>,
+    pub in_reply_to: Option<String>,
+    /// Type MIME du corps retenu (`text/plain` ou `text/html`)
+    pub body_format: Option<&'static str>,
+    pub body: String,
+    pub attachments: Vec<EmailAttachment>,
+}
+
+impl EmailMessage {
+    /// Texte du message : en-têtes principaux puis corps
+    fn to_text(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(subject) = &self.subject {
+            lines.push(format!("Subject: {}", subject));
+        }
+        if let Some(from) = &self.from {
+            lines.push(format!("From: {}", from));
+        }
+        if !self.to.is_empty() {
+            lines.push(format!("To: {}", self.to.join(", ")));
+        }
+        if let Some(date) = &self.date {
+            lines.push(format!("Date: {}", date.to_rfc3339()));
+        }
+
+        let mut text = lines.join("\n");
+        if !self.body.is_empty() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(&self.body);
+        }
+        text
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "index": self.index,
+            "parent_index": self.parent_index,
+            "from": self.from,
+            "to": self.to,
+            "cc": self.cc,
+            "subject": self.subject,
+            "date": self.date.map(|date| date.to_rfc3339()),
+            "message_id": self.message_id,
+            "in_reply_to": self.in_reply_to,
+            "body_format": self.body_format,
+            "attachments": self.attachments.iter().map(EmailAttachment::to_json).collect::<Vec<_>>()
+        })
+    }
+}
+
+/// Pièce jointe d'un message
+#[derive(Debug, Clone)]
+pub struct EmailAttachment {
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub size: usize,
+    /// Type de document détecté, `None` si le format n'est pas pris en charge
+    pub document_type: Option<DocumentType>,
+    /// Chunks produits par le processeur du format de la pièce jointe
+    pub chunks: Vec<DocumentChunk>,
+    pub error: Option<String>,
+}
+
+impl EmailAttachment {
+    fn to_json(&self) -> Value {
+        json!({
+            "filename": self.filename,
+            "content_type": self.content_type,
+            "size": self.size,
+            "document_type": self.document_type.as_ref().map(|t| t.to_string()),
+            "processed": self.document_type.is_some() && self.error.is_none(),
+            "chunk_count": self.chunks.len(),
+            "error": self.error
+        })
+    }
+}
+
+/// Formate une liste d'adresses (`Nom <adresse>`)
+fn format_addresses(address: Option<&Address<'_>>) -> Vec<String> {
+    address
+        .map(|address| address.iter()
+            .filter_map(|addr| match (addr.name(), addr.address()) {
+                (Some(name), Some(email)) => Some(format!("{} <{}>", name, email)),
+                (None, Some(email)) => Some(email.to_string()),
+                (Some(name), None) => Some(name.to_string()),
+                (None, None) => None,
+            })
+            .collect())
+        .unwrap_or_default()
+}
+
+/// Corps du message : `text/plain` de préférence, sinon `text/html` converti en texte
+fn message_body(message: &Message<'_>) -> (Option<&'static str>, String) {
+    let plain: Vec<&str> = message.text_bodies()
+        .filter_map(|part| match &part.body {
+            PartType::Text(text) => Some(text.as_ref()),
+            _ => None,
+        })
+        .collect();
+    if !plain.is_empty() {
+        return (Some("text/plain"), normalize_line_breaks(&plain.join("\n\n")).trim().to_string());
+    }
+
+    let html: Vec<String> = message.html_bodies()
+        .filter_map(|part| match &part.body {
+            PartType::Html(html) => Some(html_to_text(html)),
+            _ => None,
+        })
+        .collect();
+    if !html.is_empty() {
+        return (Some("text/html"), html.join("\n\n").trim().to_string());
+    }
+
+    (None, String::new())
+}
+
+/// Nom de fichier d'une pièce jointe, déduit du type MIME si absent
+fn attachment_filename(name: Option<&str>, content_type: Option<&str>, position: usize) -> String {
+    if let Some(name) = name.filter(|name| !name.trim().is_empty()) {
+        return name.trim().to_string();
+    }
+    let extension = content_type
+        .and_then(|mime| MIME_EXTENSIONS.iter().find(|(known, _)| known.eq_ignore_ascii_case(mime)))
+        .map(|(_, extension)| *extension)
+        .unwrap_or("bin");
+    format!("attachment_{}.{}", position, extension)
+}
+
+/// Convertit un message analysé, ses pièces jointes et ses messages imbriqués
+fn collect_messages(
+    message: &Message<'_>,
+    parent_index: Option<usize>,
+    depth: usize,
+    fallback_date: Option<DateTime<Utc>>,
+    params: &ProcessingParams,
+    registry: &ProcessorRegistry,
+    messages: &mut Vec<EmailMessage>,
+) {
+    let index = messages.len();
+    let (body_format, body) = message_body(message);
+    messages.push(EmailMessage {
+        index,
+        parent_index,
+        from: format_addresses(message.from()).into_iter().next(),
+        to: format_addresses(message.to()),
+        cc: format_addresses(message.cc()),
+        subject: message.subject().map(|subject| subject.trim().to_string()),
+        date: message.date()
+            .and_then(|date| DateTime::from_timestamp(date.to_timestamp(), 0))
+            .or(fallback_date),
+        message_id: message.message_id().map(|id| id.to_string()),
+        in_reply_to: message.in_reply_to().as_text().map(|id| id.to_string()),
+        body_format,
+        body,
+        attachments: Vec::new(),
+    });
+
+    for (position, part) in message.attachments().enumerate() {
+        // Message transféré : traité comme un message à part entière
+        if let PartType::Message(nested) = &part.body {
+            if depth < MAX_NESTING_DEPTH {
+                collect_messages(nested, Some(index), depth + 1, None, params, registry, messages);
+            } else {
+                log::warn!("Skipping nested message beyond depth {}", MAX_NESTING_DEPTH);
+            }
+            continue;
+        }
+
+        let content_type = part.content_type().map(|ct| match ct.subtype() {
+            Some(subtype) => format!("{}/{}", ct.ctype(), subtype),
+            None => ct.ctype().to_string(),
+        });
+        let filename = attachment_filename(part.attachment_name(), content_type.as_deref(), position);
+        let document_type = Path::new(&filename)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| registry.type_for_extension(ext))
+            .or_else(|| content_type.as_deref().and_then(|mime| registry.type_for_mime_type(mime)));
+
+        let mut attachment = EmailAttachment {
+            filename,
+            content_type,
+            size: part.len(),
+            document_type: document_type.clone(),
+            chunks: Vec::new(),
+            error: None,
+        };
+
+        // Les pièces jointes d'un format supporté passent par le processeur correspondant du registre
+        if let Some(doc_type) = document_type {
+            let processed = registry.processor(&doc_type)
+                .and_then(|processor| processor.process_content_with_registry(part.contents(), &attachment.filename, params, registry));
+            match processed {
+                Ok(output) => attachment.chunks = output.chunks,
+                Err(e) => {
+                    log::warn!("Failed to process attachment {}: {}", attachment.filename, e);
+                    attachment.error = Some(e.to_string());
+                }
+            }
+        }
+
+        messages[index].attachments.push(attachment);
+    }
+}
+
+/// Analyse un fichier `.eml` (message et messages imbriqués)
+fn parse_eml(content: &[u8], params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<Vec<EmailMessage>, DocLoaderError> {
+    let message = MessageParser::default()
+        .parse(content)
+        .filter(|message| !message.headers().is_empty())
+        .ok_or_else(|| DocLoaderError::InvalidFormat("Not an RFC 5322 message: no headers found".to_string()))?;
+
+    let mut messages = Vec::new();
+    collect_messages(&message, None, 0, None, params, registry, &mut messages);
+    Ok(messages)
+}
+
+/// Analyse une boîte mbox (messages séparés par des lignes `From `)
+fn parse_mbox(content: &[u8], params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<Vec<EmailMessage>, DocLoaderError> {
+    let start = content.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(0);
+    if !content[start..].starts_with(b"From ") {
+        return Err(DocLoaderError::InvalidFormat("Not an mbox file: missing \"From \" separator line".to_string()));
+    }
+
+    let parser = MessageParser::default();
+    let mut messages = Vec::new();
+
+    for (position, entry) in MessageIterator::new(Cursor::new(&content[start..])).enumerate() {
+        let entry = entry?;
+        match parser.parse(entry.contents()) {
+            Some(message) => {
+                let envelope_date = DateTime::from_timestamp(entry.internal_date() as i64, 0)
+                    .filter(|_| entry.internal_date() > 0);
+                collect_messages(&message, None, 0, envelope_date, params, registry, &mut messages);
+            },
+            None => log::warn!("Skipping unparsable message #{} in mbox", position + 1),
+        }
+    }
+
+    Ok(messages)
+}
+
+/// Construit la sortie universelle : corps des messages puis chunks des pièces jointes
+fn build_output(
+    processor: &dyn DocumentProcessor,
+    processor_name: &str,
+    chunk_prefix: &str,
+    messages: &[EmailMessage],
+    document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+    params: &ProcessingParams,
+    start_time: std::time::Instant,
+) -> UniversalOutput {
+    let mut chunks = Vec::new();
+    let mut full_text = String::new();
+
+    for message in messages {
+        let raw_text = message.to_text();
+
+        // Nettoyer le texte si demandé
+        let message_text = if params.text_cleaning {
+            clean_text(&raw_text)
+        } else {
+            raw_text
+        };
+
+        if !full_text.is_empty() {
+            full_text.push_str("\n\n");
+        }
+        let message_offset = full_text.len();
+        full_text.push_str(&message_text);
+
+        // Découper chaque message séparément pour que les chunks ne chevauchent pas deux messages
+        for chunk_text in chunk_text(&message_text, params.max_chunk_size, params.chunk_overlap) {
+            let text_meta = extract_text_metadata(&chunk_text);
+            let start_offset = message_offset + message_text.find(&chunk_text).unwrap_or(0);
+            let index = chunks.len();
+
+            chunks.push(DocumentChunk {
+                id: format!("{}_chunk_{}", chunk_prefix, index),
+                content: chunk_text.clone(),
+                chunk_index: index,
+                position: ChunkPosition {
+                    page: None,
+                    line: None,
+                    start_offset: Some(start_offset),
+                    end_offset: Some(start_offset + chunk_text.len()),
+                },
+                metadata: ChunkMetadata {
+                    size: chunk_text.len(),
+                    language: if params.language_detection { text_meta.detected_language } else { None },
+                    confidence: Some(if message.body_format == Some("text/html") { 0.9 } else { 1.0 }),
+                    format_specific: Some(json!({
+                        "word_count": text_meta.word_count,
+                        "estimated_tokens": text_meta.estimated_tokens,
+                        "part": "body",
+                        "message_index": message.index,
+                        "message_id": message.message_id,
+                        "subject": message.subject,
+                        "from": message.from,
+                        "date": message.date.map(|date| date.to_rfc3339())
+                    })),
+                },
+            });
+        }
+
+        // Les chunks des pièces jointes gardent leurs positions relatives à la pièce jointe
+        for attachment in &message.attachments {
+            for source in &attachment.chunks {
+                let index = chunks.len();
+                chunks.push(DocumentChunk {
+                    id: format!("{}_chunk_{}", chunk_prefix, index),
+                    content: source.content.clone(),
+                    chunk_index: index,
+                    position: source.position.clone(),
+                    metadata: ChunkMetadata {
+                        size: source.metadata.size,
+                        language: source.metadata.language.clone(),
+                        confidence: source.metadata.confidence,
+                        format_specific: Some(json!({
+                            "part": "attachment",
+                            "message_index": message.index,
+                            "message_id": message.message_id,
+                            "attachment_filename": attachment.filename,
+                            "attachment_type": attachment.document_type.as_ref().map(|t| t.to_string()),
+                            "attachment_chunk_index": source.chunk_index,
+                            "attachment_metadata": source.metadata.format_specific
+                        })),
+                    },
+                });
+            }
+        }
+    }
+
+    let text_meta = extract_text_metadata(&full_text);
+    let format_metadata = json!({
+        "email_metadata": {
+            "message_count": messages.len(),
+            "attachment_count": messages.iter().map(|m| m.attachments.len()).sum::<usize>()
+        },
+        "messages": messages.iter().map(EmailMessage::to_json).collect::<Vec<_>>(),
+        "extracted_text_metadata": {
+            "total_words": text_meta.word_count,
+            "total_characters": text_meta.character_count,
+            "total_lines": text_meta.line_count,
+            "estimated_tokens": text_meta.estimated_tokens,
+            "detected_language": text_meta.detected_language
+        }
+    });
+
+    let processing_time = start_time.elapsed();
+    let processing_info = ProcessingInfo {
+        processor: processor_name.to_string(),
+        processor_version: processor.version().to_string(),
+        processed_at: Utc::now(),
+        processing_time_ms: processing_time.as_millis() as u64,
+        total_chunks: chunks.len(),
+        total_content_size: full_text.len(),
+        processing_params: params.clone(),
+        warnings: Vec::new(),
+    };
+
+    UniversalOutput {
+        document_metadata: document_metadata(format_metadata),
+        chunks,
+        processing_info,
+    }
+}
+
+/// Processeur des messages `.eml`.
+///
+/// Appelé directement, il traite les pièces jointes avec les seuls processeurs intégrés
+/// (`ProcessorRegistry::with_builtins()`, reconstruit à chaque appel) : pour que les formats
+/// enregistrés par l'application s'appliquent aussi aux pièces jointes, passer par
+/// `UniversalProcessor` ou par `process_content_with_registry`.
+pub struct EmlProcessor;
+
+impl EmlProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DocumentProcessor for EmlProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::EML
+    }
+
+    /// Pièces jointes traitées avec les seuls processeurs intégrés
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        self.process_file_with_registry(file_path, params, &ProcessorRegistry::with_builtins())
+    }
+
+    /// Pièces jointes traitées avec les seuls processeurs intégrés
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        self.process_content_with_registry(content, filename, params, &ProcessorRegistry::with_builtins())
+    }
+
+    fn process_file_with_registry(&self, file_path: &Path, params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et analyser le message
+        let content = fs::read(file_path)?;
+        let messages = parse_eml(&content, params, registry)?;
+        let root = &messages[0];
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(build_output(self, "EmlProcessor", "eml", &messages, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.eml")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::EML,
+            file_size: file_metadata.len(),
+            created_at: root.date.or_else(|| file_metadata.created().ok().map(|t| t.into())),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: root.subject.clone(),
+            author: root.from.clone(),
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content_with_registry(&self, content: &[u8], filename: &str, params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Analyser le message depuis les bytes
+        let messages = parse_eml(content, params, registry)?;
+        let root = &messages[0];
+
+        Ok(build_output(self, "EmlProcessor", "eml", &messages, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::EML,
+            file_size: content.len() as u64,
+            created_at: root.date.or_else(|| Some(Utc::now())),
+            modified_at: Some(Utc::now()),
+            title: root.subject.clone(),
+            author: root.from.clone(),
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for EmlProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Processeur des boîtes `.mbox`.
+///
+/// Comme pour `EmlProcessor`, un appel direct ne connaît que les processeurs intégrés pour les
+/// pièces jointes : passer par `UniversalProcessor` pour les formats personnalisés.
+pub struct MboxProcessor;
+
+impl MboxProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DocumentProcessor for MboxProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::MBOX
+    }
+
+    /// Pièces jointes traitées avec les seuls processeurs intégrés
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        self.process_file_with_registry(file_path, params, &ProcessorRegistry::with_builtins())
+    }
+
+    /// Pièces jointes traitées avec les seuls processeurs intégrés
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        self.process_content_with_registry(content, filename, params, &ProcessorRegistry::with_builtins())
+    }
+
+    fn process_file_with_registry(&self, file_path: &Path, params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et découper la boîte aux lettres
+        let content = fs::read(file_path)?;
+        let messages = parse_mbox(&content, params, registry)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(build_output(self, "MboxProcessor", "mbox", &messages, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.mbox")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::MBOX,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content_with_registry(&self, content: &[u8], filename: &str, params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Découper la boîte aux lettres depuis les bytes
+        let messages = parse_mbox(content, params, registry)?;
+
+        Ok(build_output(self, "MboxProcessor", "mbox", &messages, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::MBOX,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for MboxProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MULTIPART: &str = "From: Alice Martin <alice@example.com>\r\n\
+To: support@example.com\r\n\
+Subject: =?UTF-8?Q?Probl=C3=A8me_de_facture?=\r\n\
+Date: Tue, 14 May 2024 10:30:00 +0200\r\n\
+Message-ID: <abc123@example.com>\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: multipart/mixed; boundary=\"outer\"\r\n\
+\r\n\
+--outer\r\n\
+Content-Type: multipart/alternative; boundary=\"inner\"\r\n\
+\r\n\
+--inner\r\n\
+Content-Type: text/plain; charset=utf-8\r\n\
+Content-Transfer-Encoding: quoted-printable\r\n\
+\r\n\
+Bonjour, la facture est erron=C3=A9e.\r\n\
+--inner\r\n\
+Content-Type: text/html; charset=utf-8\r\n\
+\r\n\
+<p>Bonjour, <b>HTML</b></p>\r\n\
+--inner--\r\n\
+--outer\r\n\
+Content-Type: text/plain; name=\"details.txt\"\r\n\
+Content-Disposition: attachment; filename=\"details.txt\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+TW9udGFudCBkdSA6IDQyIEVVUg==\r\n\
+--outer--\r\n";
+
+    #[test]
+    fn test_email_processor_creation() {
+        assert_eq!(EmlProcessor::new().supported_type().to_string(), "EML");
+        assert_eq!(MboxProcessor::new().supported_type().to_string(), "MBOX");
+    }
+
+    #[test]
+    fn test_parse_multipart_eml() {
+        let messages = parse_eml(MULTIPART.as_bytes(), &ProcessingParams::default(), &ProcessorRegistry::with_builtins()).unwrap();
+        assert_eq!(messages.len(), 1);
+
+        let message = &messages[0];
+        assert_eq!(message.subject.as_deref(), Some("Problème de facture"));
+        assert_eq!(message.from.as_deref(), Some("Alice Martin <alice@example.com>"));
+        assert_eq!(message.to, vec!["support@example.com"]);
+        assert_eq!(message.message_id.as_deref(), Some("abc123@example.com"));
+        assert_eq!(message.date.unwrap().to_rfc3339(), "2024-05-14T08:30:00+00:00");
+        assert_eq!(message.body_format, Some("text/plain"));
+        assert_eq!(message.body, "Bonjour, la facture est erronée.");
+
+        assert_eq!(message.attachments.len(), 1);
+        let attachment = &message.attachments[0];
+        assert_eq!(attachment.filename, "details.txt");
+        assert_eq!(attachment.document_type, Some(DocumentType::TXT));
+        assert!(attachment.chunks[0].content.contains("42 EUR"));
+    }
+
+    #[test]
+    fn test_html_fallback() {
+        let raw = "From: bob@example.com\r\nSubject: News\r\nContent-Type: text/html\r\n\r\n<html><body><h1>Title</h1><p>Hello&nbsp;world</p></body></html>\r\n";
+        let messages = parse_eml(raw.as_bytes(), &ProcessingParams::default(), &ProcessorRegistry::with_builtins()).unwrap();
+        assert_eq!(messages[0].body_format, Some("text/html"));
+        assert_eq!(messages[0].body, "Title\nHello world");
+    }
+
+    #[test]
+    fn test_process_mbox() {
+        let mbox = "From alice@example.com Tue May 14 10:30:00 2024\n\
+From: alice@example.com\nSubject: First\n\nFirst body\n\n\
+From bob@example.com Wed May 15 09:00:00 2024\n\
+From: bob@example.com\nSubject: Second\n\n>From the start, second body\n";
+
+        let processor = MboxProcessor::new();
+        let output = processor.process_content(mbox.as_bytes(), "support.mbox", &ProcessingParams::default()).unwrap();
+
+        assert_eq!(output.document_metadata.document_type.to_string(), "MBOX");
+        assert_eq!(output.document_metadata.format_metadata["email_metadata"]["message_count"], 2);
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[1].metadata.format_specific.as_ref().unwrap()["subject"], "Second");
+        assert!(output.chunks[1].content.contains("From the start"));
+
+        assert!(processor.process_content(b"not a mailbox", "bad.mbox", &ProcessingParams::default()).is_err());
+    }
+
+    #[test]
+    fn test_attachments_use_caller_registry() {
+        let raw = "From: ops@example.com\r\n\
+Subject: Logs\r\n\
+Content-Type: multipart/mixed; boundary=\"b\"\r\n\
+\r\n\
+--b\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Voir les journaux.\r\n\
+--b\r\n\
+Content-Type: application/octet-stream\r\n\
+Content-Disposition: attachment; filename=\"server.log\"\r\n\
+\r\n\
+disk full on node-1\r\n\
+--b\r\n\
+Content-Type: text/x-log\r\n\
+Content-Disposition: attachment\r\n\
+\r\n\
+restart scheduled\r\n\
+--b--\r\n";
+
+        // Sans enregistrement, ni l'extension `.log` ni le type `text/x-log` ne sont reconnus
+        let output = crate::UniversalProcessor::new().process_content(raw.as_bytes(), "logs.eml", None).unwrap();
+        assert_eq!(output.document_metadata.format_metadata["email_metadata"]["attachment_count"], 2);
+        assert!(output.chunks.iter().all(|chunk| chunk.metadata.format_specific.as_ref().unwrap()["part"] == "body"));
+
+        let mut universal = crate::UniversalProcessor::new();
+        universal.register(crate::processors::txt::TxtProcessor::new())
+            .extensions(&["log"])
+            .mime_types(&["text/x-log"]);
+        let output = universal.process_content(raw.as_bytes(), "logs.eml", None).unwrap();
+        let attachments: Vec<_> = output.chunks.iter()
+            .filter_map(|chunk| chunk.metadata.format_specific.as_ref())
+            .filter(|specific| specific["part"] == "attachment")
+            .map(|specific| (specific["attachment_filename"].as_str().unwrap(), specific["attachment_type"].as_str().unwrap()))
+            .collect();
+        assert_eq!(attachments, vec![("server.log", "TXT"), ("attachment_1.bin", "TXT")]);
+        assert!(output.chunks.iter().any(|chunk| chunk.content.contains("restart scheduled")));
+    }
+}
@@ -0,0 +1,629 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::processors::ooxml::{self, Archive};
+use crate::utils::{chunk_text, clean_text, extract_text_metadata, html_to_text};
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::fs;
+use std::sync::LazyLock;
+use chrono::{DateTime, NaiveDate, Utc};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use regex::Regex;
+use serde_json::json;
+
+static NAV_LINK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(?is)<a\s[^>]*href\s*=\s*["']([^"'#]+)[^"']*["'][^>]*>(.*?)</a>"#).unwrap());
+static HEADING: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?is)<h([1-3])[^>]*>(.*?)</h[1-3]\s*>").unwrap());
+static TITLE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?is)<title[^>]*>(.*?)</title\s*>").unwrap());
+
+pub struct EpubProcessor;
+
+impl EpubProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lit et extrait les chapitres d'un fichier EPUB
+    fn read_epub_file(&self, file_path: &Path) -> Result<(Vec<EpubChapter>, EpubMetadata), DocLoaderError> {
+        let file_content = fs::read(file_path)?;
+        self.extract_from_epub_content(&file_content)
+    }
+
+    /// Extrait les chapitres (dans l'ordre du spine) et les métadonnées Dublin Core
+    fn extract_from_epub_content(&self, content: &[u8]) -> Result<(Vec<EpubChapter>, EpubMetadata), DocLoaderError> {
+        let mut archive = ooxml::open_archive(content)?;
+
+        // Le conteneur indique l'emplacement du paquet OPF
+        let container = ooxml::read_entry(&mut archive, "META-INF/container.xml")?
+            .ok_or_else(|| DocLoaderError::InvalidFormat(
+                "Not an EPUB file: META-INF/container.xml is missing".to_string()
+            ))?;
+        let opf_path = self.rootfile_path(&container)?;
+        let opf_xml = ooxml::read_entry(&mut archive, &opf_path)?
+            .ok_or_else(|| DocLoaderError::InvalidFormat(format!("EPUB package {} is missing", opf_path)))?;
+        let package = OpfPackage::parse(&opf_xml)?;
+        let opf_dir = opf_path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+
+        let toc_titles = self.toc_titles(&mut archive, &package, opf_dir)?;
+
+        let mut chapters = Vec::new();
+        for (spine_index, idref) in package.spine.iter().enumerate() {
+            let item = match package.manifest.get(idref) {
+                Some(item) if item.is_xhtml() => item,
+                Some(_) => continue,
+                None => {
+                    log::warn!("Spine item {} is missing from the EPUB manifest", idref);
+                    continue;
+                }
+            };
+
+            let href = ooxml::resolve_target(opf_dir, &percent_decode(&item.href));
+            let xhtml = match ooxml::read_entry(&mut archive, &href)? {
+                Some(xhtml) => xhtml,
+                None => {
+                    log::warn!("EPUB chapter {} is missing from archive", href);
+                    continue;
+                }
+            };
+
+            let text = html_to_text(&xhtml);
+            if text.is_empty() {
+                continue;
+            }
+
+            let title = toc_titles.get(&href).cloned()
+                .or_else(|| first_heading(&xhtml))
+                .or_else(|| html_title(&xhtml));
+
+            chapters.push(EpubChapter {
+                spine_index,
+                href,
+                title,
+                text,
+            });
+        }
+
+        Ok((chapters, package.metadata))
+    }
+
+    /// Chemin du fichier OPF déclaré dans `META-INF/container.xml`
+    fn rootfile_path(&self, container: &str) -> Result<String, DocLoaderError> {
+        let mut reader = Reader::from_str(container);
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"rootfile" => {
+                    if let Some(path) = ooxml::attribute(&e, b"full-path") {
+                        return Ok(path);
+                    }
+                },
+                Event::Eof => break,
+                _ => {},
+            }
+        }
+
+        Err(DocLoaderError::InvalidFormat("EPUB container does not declare a rootfile".to_string()))
+    }
+
+    /// Titres de la table des matières (NCX pour EPUB 2, document `nav` pour EPUB 3), indexés par chemin
+    fn toc_titles(&self, archive: &mut Archive<'_>, package: &OpfPackage, opf_dir: &str) -> Result<HashMap<String, String>, DocLoaderError> {
+        let mut titles = HashMap::new();
+
+        let nav_item = package.manifest.values().find(|item| item.properties.split_whitespace().any(|p| p == "nav"));
+        let ncx_item = package.toc_id.as_ref()
+            .and_then(|id| package.manifest.get(id))
+            .or_else(|| package.manifest.values().find(|item| item.media_type == "application/x-dtbncx+xml"));
+
+        if let Some(item) = nav_item {
+            let nav_path = ooxml::resolve_target(opf_dir, &percent_decode(&item.href));
+            let nav_dir = nav_path.rsplit_once('/').map(|(dir, _)| dir.to_string()).unwrap_or_default();
+            if let Some(nav) = ooxml::read_entry(archive, &nav_path)? {
+                for capture in NAV_LINK.captures_iter(&nav) {
+                    let target = ooxml::resolve_target(&nav_dir, &percent_decode(&capture[1]));
+                    let label = html_to_text(&capture[2]).replace('\n', " ");
+                    if !label.is_empty() {
+                        titles.entry(target).or_insert(label);
+                    }
+                }
+            }
+        } else if let Some(item) = ncx_item {
+            let ncx_path = ooxml::resolve_target(opf_dir, &percent_decode(&item.href));
+            let ncx_dir = ncx_path.rsplit_once('/').map(|(dir, _)| dir.to_string()).unwrap_or_default();
+            if let Some(ncx) = ooxml::read_entry(archive, &ncx_path)? {
+                let mut reader = Reader::from_str(&ncx);
+                let mut in_label = false;
+                let mut label = String::new();
+
+                loop {
+                    match reader.read_event()? {
+                        Event::Start(e) if e.local_name().as_ref() == b"navLabel" => {
+                            in_label = true;
+                            label.clear();
+                        },
+                        Event::End(e) if e.local_name().as_ref() == b"navLabel" => in_label = false,
+                        Event::Text(t) if in_label => label.push_str(t.unescape()?.trim()),
+                        Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"content" => {
+                            if let Some(src) = ooxml::attribute(&e, b"src") {
+                                let src = src.split('#').next().unwrap_or_default();
+                                let target = ooxml::resolve_target(&ncx_dir, &percent_decode(src));
+                                if !label.is_empty() {
+                                    titles.entry(target).or_insert_with(|| label.clone());
+                                }
+                            }
+                        },
+                        Event::Eof => break,
+                        _ => {},
+                    }
+                }
+            }
+        }
+
+        Ok(titles)
+    }
+
+    /// Construit la sortie universelle à partir des chapitres extraits
+    fn build_output(
+        &self,
+        chapters: &[EpubChapter],
+        epub_meta: &EpubMetadata,
+        document_metadata: impl FnOnce(serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let mut chunks = Vec::new();
+        let mut full_text = String::new();
+
+        for chapter in chapters {
+            // Nettoyer le texte si demandé
+            let chapter_text = if params.text_cleaning {
+                clean_text(&chapter.text)
+            } else {
+                chapter.text.clone()
+            };
+
+            if !full_text.is_empty() {
+                full_text.push_str("\n\n");
+            }
+            let chapter_offset = full_text.len();
+            full_text.push_str(&chapter_text);
+
+            // Découper chaque chapitre séparément pour que les chunks ne chevauchent pas deux chapitres
+            for chunk_text in chunk_text(&chapter_text, params.max_chunk_size, params.chunk_overlap) {
+                let text_meta = extract_text_metadata(&chunk_text);
+                let start_offset = chapter_offset + chapter_text.find(&chunk_text).unwrap_or(0);
+                let index = chunks.len();
+
+                chunks.push(DocumentChunk {
+                    id: format!("epub_chunk_{}", index),
+                    content: chunk_text.clone(),
+                    chunk_index: index,
+                    position: ChunkPosition {
+                        page: None,
+                        line: None,
+                        start_offset: Some(start_offset),
+                        end_offset: Some(start_offset + chunk_text.len()),
+                    },
+                    metadata: ChunkMetadata {
+                        size: chunk_text.len(),
+                        language: if params.language_detection {
+                            text_meta.detected_language.or_else(|| epub_meta.language.clone())
+                        } else {
+                            None
+                        },
+                        confidence: Some(1.0),
+                        format_specific: Some(json!({
+                            "word_count": text_meta.word_count,
+                            "estimated_tokens": text_meta.estimated_tokens,
+                            "chapter_title": chapter.title,
+                            "spine_index": chapter.spine_index,
+                            "href": chapter.href
+                        })),
+                    },
+                });
+            }
+        }
+
+        let text_meta = extract_text_metadata(&full_text);
+        let format_metadata = json!({
+            "epub_metadata": {
+                "version": epub_meta.version,
+                "creators": epub_meta.creators,
+                "language": epub_meta.language,
+                "identifier": epub_meta.identifier,
+                "publisher": epub_meta.publisher,
+                "date": epub_meta.date,
+                "subjects": epub_meta.subjects,
+                "description": epub_meta.description,
+                "chapter_count": chapters.len()
+            },
+            "chapters": chapters.iter().map(|chapter| json!({
+                "spine_index": chapter.spine_index,
+                "title": chapter.title,
+                "href": chapter.href
+            })).collect::<Vec<_>>(),
+            "extracted_text_metadata": {
+                "total_words": text_meta.word_count,
+                "total_characters": text_meta.character_count,
+                "total_lines": text_meta.line_count,
+                "estimated_tokens": text_meta.estimated_tokens,
+                "detected_language": text_meta.detected_language
+            }
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "EpubProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: full_text.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+/// Chapitre (document XHTML du spine)
+#[derive(Debug, Clone)]
+pub struct EpubChapter {
+    /// Position dans le spine (ordre de lecture)
+    pub spine_index: usize,
+    /// Chemin du document dans l'archive
+    pub href: String,
+    pub title: Option<String>,
+    pub text: String,
+}
+
+/// Métadonnées Dublin Core du paquet OPF
+#[derive(Debug, Clone, Default)]
+pub struct EpubMetadata {
+    pub version: Option<String>,
+    pub title: Option<String>,
+    pub creators: Vec<String>,
+    pub language: Option<String>,
+    pub identifier: Option<String>,
+    pub publisher: Option<String>,
+    pub date: Option<String>,
+    pub subjects: Vec<String>,
+    pub description: Option<String>,
+}
+
+impl EpubMetadata {
+    fn author(&self) -> Option<String> {
+        if self.creators.is_empty() {
+            None
+        } else {
+            Some(self.creators.join(", "))
+        }
+    }
+
+    /// `dc:date` peut être une date complète ou seulement une année / un mois
+    fn parse_date(&self) -> Option<DateTime<Utc>> {
+        let date = self.date.as_deref()?;
+        DateTime::parse_from_rfc3339(date)
+            .map(|date| date.with_timezone(&Utc))
+            .ok()
+            .or_else(|| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .map(|date| date.and_utc()))
+    }
+}
+
+/// Élément du manifeste OPF
+#[derive(Debug, Clone)]
+struct ManifestItem {
+    href: String,
+    media_type: String,
+    properties: String,
+}
+
+impl ManifestItem {
+    fn is_xhtml(&self) -> bool {
+        matches!(self.media_type.as_str(), "application/xhtml+xml" | "text/html")
+    }
+}
+
+/// Contenu du paquet OPF : métadonnées, manifeste et spine
+#[derive(Debug, Default)]
+struct OpfPackage {
+    metadata: EpubMetadata,
+    manifest: HashMap<String, ManifestItem>,
+    spine: Vec<String>,
+    toc_id: Option<String>,
+}
+
+impl OpfPackage {
+    fn parse(xml: &str) -> Result<Self, DocLoaderError> {
+        let mut reader = Reader::from_str(xml);
+        let mut package = OpfPackage::default();
+        let mut in_metadata = false;
+        let mut current: Option<(String, String)> = None;
+
+        loop {
+            match reader.read_event()? {
+                // `<item/>` comme `<item></item>`
+                Event::Start(e) | Event::Empty(e) if matches!(e.local_name().as_ref(), b"item" | b"itemref") => {
+                    package.add_entry(&e);
+                },
+                Event::Start(e) => match e.local_name().as_ref() {
+                    b"package" => package.metadata.version = ooxml::attribute(&e, b"version"),
+                    b"metadata" => in_metadata = true,
+                    b"spine" => package.toc_id = ooxml::attribute(&e, b"toc"),
+                    name if in_metadata => {
+                        current = Some((String::from_utf8_lossy(name).into_owned(), String::new()));
+                    },
+                    _ => {},
+                },
+                Event::Text(t) => {
+                    if let Some((_, text)) = current.as_mut() {
+                        text.push_str(&t.unescape()?);
+                    }
+                },
+                Event::End(e) => {
+                    if e.local_name().as_ref() == b"metadata" {
+                        in_metadata = false;
+                    }
+                    if let Some((name, text)) = current.take() {
+                        let value = text.trim().to_string();
+                        if value.is_empty() {
+                            continue;
+                        }
+                        let meta = &mut package.metadata;
+                        match name.as_str() {
+                            "title" if meta.title.is_none() => meta.title = Some(value),
+                            "creator" => meta.creators.push(value),
+                            "language" if meta.language.is_none() => meta.language = Some(value),
+                            "identifier" if meta.identifier.is_none() => meta.identifier = Some(value),
+                            "publisher" => meta.publisher = Some(value),
+                            "date" if meta.date.is_none() => meta.date = Some(value),
+                            "subject" => meta.subjects.push(value),
+                            "description" => meta.description = Some(value),
+                            _ => {},
+                        }
+                    }
+                },
+                Event::Eof => break,
+                _ => {},
+            }
+        }
+
+        Ok(package)
+    }
+
+    /// Entrée du manifeste (`item`) ou du spine (`itemref`)
+    fn add_entry(&mut self, e: &BytesStart<'_>) {
+        if e.local_name().as_ref() == b"item" {
+            if let (Some(id), Some(href)) = (ooxml::attribute(e, b"id"), ooxml::attribute(e, b"href")) {
+                self.manifest.insert(id, ManifestItem {
+                    href,
+                    media_type: ooxml::attribute(e, b"media-type").unwrap_or_default(),
+                    properties: ooxml::attribute(e, b"properties").unwrap_or_default(),
+                });
+            }
+        } else {
+            // Les documents non linéaires (notes, annexes) ne font pas partie de la lecture
+            let linear = ooxml::attribute(e, b"linear").map(|l| l != "no").unwrap_or(true);
+            if let (Some(idref), true) = (ooxml::attribute(e, b"idref"), linear) {
+                self.spine.push(idref);
+            }
+        }
+    }
+}
+
+/// Premier titre `<h1>`–`<h3>` d'un chapitre
+fn first_heading(xhtml: &str) -> Option<String> {
+    let title = HEADING.captures_iter(xhtml)
+        .map(|capture| html_to_text(&capture[2]).replace('\n', " "))
+        .find(|title| !title.is_empty());
+    title
+}
+
+/// Contenu de l'élément `<title>` d'un chapitre
+fn html_title(xhtml: &str) -> Option<String> {
+    TITLE.captures(xhtml)
+        .map(|capture| html_to_text(&capture[1]))
+        .filter(|title| !title.is_empty())
+}
+
+/// Décode les séquences `%XX` des chemins du manifeste
+fn percent_decode(href: &str) -> String {
+    let bytes = href.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+impl DocumentProcessor for EpubProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::EPUB
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et extraire les chapitres
+        let (chapters, epub_meta) = self.read_epub_file(file_path)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&chapters, &epub_meta, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.epub")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::EPUB,
+            file_size: file_metadata.len(),
+            created_at: epub_meta.parse_date()
+                .or_else(|| file_metadata.created().ok().map(|t| t.into())),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: epub_meta.title.clone(),
+            author: epub_meta.author(),
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Extraire les chapitres depuis les bytes
+        let (chapters, epub_meta) = self.extract_from_epub_content(content)?;
+
+        Ok(self.build_output(&chapters, &epub_meta, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::EPUB,
+            file_size: content.len() as u64,
+            created_at: epub_meta.parse_date().or_else(|| Some(Utc::now())),
+            modified_at: Some(Utc::now()),
+            title: epub_meta.title.clone(),
+            author: epub_meta.author(),
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for EpubProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    const OPF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="uid">
+            <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+                <dc:identifier id="uid">urn:isbn:9780000000001</dc:identifier>
+                <dc:title>Rust in Practice</dc:title>
+                <dc:creator>Ada Writer</dc:creator>
+                <dc:creator>Bob Editor</dc:creator>
+                <dc:language>en</dc:language>
+                <dc:date>2023-05-01</dc:date>
+            </metadata>
+            <manifest>
+                <item id="nav" href="nav.xhtml" media-type="application/xhtml+xml" properties="nav"/>
+                <item id="intro" href="text/intro.xhtml" media-type="application/xhtml+xml"/>
+                <item id="ch1" href="text/chapter%201.xhtml" media-type="application/xhtml+xml"/>
+                <item id="cover" href="images/cover.jpg" media-type="image/jpeg"/>
+            </manifest>
+            <spine>
+                <itemref idref="intro"/>
+                <itemref idref="ch1"/>
+            </spine>
+        </package>"#;
+
+    fn build_epub() -> Vec<u8> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let mut zip = zip::ZipWriter::new(&mut buffer);
+        let options = SimpleFileOptions::default();
+
+        let parts = [
+            ("mimetype", "application/epub+zip"),
+            ("META-INF/container.xml", r#"<container xmlns="urn:oasis:names:tc:opendocument:xmlns:container" version="1.0"><rootfiles><rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/></rootfiles></container>"#),
+            ("OEBPS/content.opf", OPF),
+            ("OEBPS/nav.xhtml", r#"<html><body><nav epub:type="toc"><ol><li><a href="text/chapter%201.xhtml#start">Getting Started</a></li></ol></nav></body></html>"#),
+            ("OEBPS/text/intro.xhtml", r#"<html><head><title>Introduction</title></head><body><p>Why Rust &mdash; and why now.</p></body></html>"#),
+            ("OEBPS/text/chapter 1.xhtml", r#"<html><head><title>ch1</title></head><body><h1>Chapter One</h1><p>Install the toolchain&nbsp;first.</p></body></html>"#),
+        ];
+        for (name, data) in parts {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(data.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn test_epub_processor_creation() {
+        let processor = EpubProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "EPUB");
+    }
+
+    #[test]
+    fn test_opf_parsing() {
+        let package = OpfPackage::parse(OPF).unwrap();
+        assert_eq!(package.metadata.title.as_deref(), Some("Rust in Practice"));
+        assert_eq!(package.metadata.creators, vec!["Ada Writer", "Bob Editor"]);
+        assert_eq!(package.metadata.language.as_deref(), Some("en"));
+        assert_eq!(package.spine, vec!["intro", "ch1"]);
+        assert_eq!(package.manifest["ch1"].href, "text/chapter%201.xhtml");
+
+        // Éléments du manifeste et du spine écrits avec une balise fermante
+        let explicit = OPF
+            .replace(r#"media-type="image/jpeg"/>"#, r#"media-type="image/jpeg"></item>"#)
+            .replace(r#"<itemref idref="ch1"/>"#, r#"<itemref idref="ch1"></itemref>"#);
+        let package = OpfPackage::parse(&explicit).unwrap();
+        assert_eq!(package.spine, vec!["intro", "ch1"]);
+        assert_eq!(package.manifest["cover"].media_type, "image/jpeg");
+    }
+
+    #[test]
+    fn test_process_content() {
+        let processor = EpubProcessor::new();
+        let params = ProcessingParams::default();
+
+        let output = processor.process_content(&build_epub(), "book.epub", &params).unwrap();
+        assert_eq!(output.document_metadata.document_type.to_string(), "EPUB");
+        assert_eq!(output.document_metadata.title.as_deref(), Some("Rust in Practice"));
+        assert_eq!(output.document_metadata.author.as_deref(), Some("Ada Writer, Bob Editor"));
+        assert_eq!(output.document_metadata.format_metadata["epub_metadata"]["language"], "en");
+
+        assert_eq!(output.chunks.len(), 2);
+        let intro = output.chunks[0].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(intro["spine_index"], 0);
+        assert_eq!(intro["chapter_title"], "Introduction");
+        assert_eq!(output.chunks[0].content, "Why Rust — and why now.");
+
+        // Le titre de la table des matières prime sur le titre du chapitre
+        let chapter = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(chapter["spine_index"], 1);
+        assert_eq!(chapter["chapter_title"], "Getting Started");
+        assert_eq!(output.chunks[1].content, "Chapter One Install the toolchain first.");
+    }
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("text/chapter%201.xhtml"), "text/chapter 1.xhtml");
+        assert_eq!(percent_decode("100%"), "100%");
+    }
+}
@@ -3,15 +3,269 @@ use crate::core::{
     DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
 };
 use crate::error::DocLoaderError;
+use crate::processors::records::{self, RecordWarnings};
+use crate::processors::selector::Selector;
+use crate::processors::compression::{self, BoundedReader};
+use crate::processors::registry::ProcessorRegistry;
 use crate::processors::DocumentProcessor;
-use crate::utils::{chunk_text, clean_text, extract_text_metadata};
+use crate::utils::{chunk_text, clean_text, detect_language, extract_text_metadata};
 
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
-use std::fs;
+use std::fs::{self, File};
+use serde::Deserialize;
 use serde_json::{Value, Map};
 use chrono::Utc;
 use serde_json::json;
 
+/// Extensions des fichiers JSON Lines (un document JSON par ligne)
+const JSON_LINES_EXTENSIONS: [&str; 2] = ["jsonl", "ndjson"];
+
+/// Taille maximale d'une ligne JSON Lines ; les lignes plus longues sont ignorées
+const MAX_LINE_SIZE: u64 = 64 * 1024 * 1024;
+
+/// Taille de l'échantillon de texte servant à détecter la langue d'un fichier JSON Lines
+const LANGUAGE_SAMPLE_SIZE: usize = 64 * 1024;
+
+/// Options JSON, lues depuis `params.format_specific["json"]`
+///
+/// ```json
+/// { "json": { "record_mode": true, "text_fields": ["title", "abstract"] } }
+/// { "json": { "template": "{title} by {author.name} ({year|n.d.}): {summary}", "exclude_fields": ["id"] } }
+/// { "json": { "lines": true } }
+/// { "json": { "text_paths": ["$.articles[*].abstract"], "metadata_paths": ["$.articles[*].doi"] } }
+/// { "json": { "style": "yaml", "max_depth": 4, "drop_keys": ["id", "_meta", "*_url"] } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct JsonOptions {
+    /// Un chunk par élément du tableau racine plutôt que le document aplati
+    pub record_mode: bool,
+    /// En mode enregistrement, champs rendus dans le texte (tous par défaut) ;
+    /// les autres deviennent des métadonnées du chunk
+    pub text_fields: Vec<String>,
+    /// Gabarit de rendu de chaque objet (`{champ}`, `{champ.sous_champ}`, `{champ|défaut}`)
+    /// à la place de l'aplatissement `chemin=valeur`
+    pub template: Option<String>,
+    /// Valeur des champs absents ou vides sans défaut dans le gabarit
+    pub missing_value: String,
+    /// Clés de premier niveau conservées (toutes par défaut) pour le rendu et les métadonnées
+    pub include_fields: Vec<String>,
+    /// Clés de premier niveau ignorées pour le rendu et les métadonnées
+    pub exclude_fields: Vec<String>,
+    /// Lecture en JSON Lines, un enregistrement par ligne ; détectée par défaut
+    /// (extensions `.jsonl` et `.ndjson`, ou contenu qui n'est pas un document JSON unique)
+    pub lines: Option<bool>,
+    /// Sélecteur JSONPath ou JSON Pointer des enregistrements (`$.articles[*]`, `/articles`),
+    /// un chunk par valeur désignée ; un tableau désigné sans joker fournit ses éléments
+    pub record_path: Option<String>,
+    /// Sélecteurs des valeurs formant le texte de chaque enregistrement, relatifs à
+    /// l'enregistrement (`$.abstract`) ou préfixés par `record_path` (`$.articles[*].abstract`) ;
+    /// remplacent `text_fields`
+    pub text_paths: Vec<String>,
+    /// Sélecteurs des valeurs conservées en métadonnées (par défaut, les clés de premier
+    /// niveau non utilisées par `text_paths`)
+    pub metadata_paths: Vec<String>,
+    /// Rendu du document aplati
+    pub style: FlattenStyle,
+    /// Profondeur d'imbrication rendue ; au-delà, les valeurs sont remplacées par
+    /// `[MAX_DEPTH_REACHED]` (style `paths`) ou omises
+    pub max_depth: usize,
+    /// Clés ignorées à tous les niveaux du document aplati ; `*` remplace toute suite de
+    /// caractères (`id`, `_meta`, `*_url`)
+    pub drop_keys: Vec<String>,
+}
+
+impl Default for JsonOptions {
+    fn default() -> Self {
+        Self {
+            record_mode: false,
+            text_fields: Vec::new(),
+            template: None,
+            missing_value: String::new(),
+            include_fields: Vec::new(),
+            exclude_fields: Vec::new(),
+            lines: None,
+            record_path: None,
+            text_paths: Vec::new(),
+            metadata_paths: Vec::new(),
+            style: FlattenStyle::Paths,
+            max_depth: 10,
+            drop_keys: Vec::new(),
+        }
+    }
+}
+
+impl JsonOptions {
+    pub fn from_params(params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        params.format_options("json")
+    }
+}
+
+/// Rendu texte d'un document JSON aplati
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlattenStyle {
+    /// Chemin complet de chaque valeur : `author.name="Ada"`
+    #[default]
+    Paths,
+    /// Valeurs seules, une par ligne
+    Values,
+    /// `clé: valeur` indenté selon l'imbrication, les tableaux en listes `- `
+    Yaml,
+    /// Titres pour les objets et tableaux imbriqués, listes à puces pour les valeurs
+    Markdown,
+}
+
+/// Clé correspondant à un motif où `*` remplace toute suite de caractères
+fn key_matches(pattern: &str, key: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = key.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Texte d'une valeur scalaire ; `None` pour `null` et les chaînes vides
+fn scalar_text(value: &Value, clean: bool) -> Option<String> {
+    let text = match value {
+        Value::Null | Value::Array(_) | Value::Object(_) => return None,
+        Value::String(text) if clean => clean_text(text),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+/// Sélecteurs des options JSON, analysés une fois par document
+struct Selection {
+    /// Enregistrements ; sans sélecteur, le document (ou les éléments du tableau racine en mode enregistrement)
+    records: Option<Selector>,
+    text: Vec<Selector>,
+    metadata: Vec<Selector>,
+}
+
+impl Selection {
+    /// `None` si aucun sélecteur n'est configuré. Sans `record_path`, les enregistrements sont
+    /// désignés par le préfixe commun des sélecteurs de champs jusqu'à leur dernier joker.
+    fn from_options(options: &JsonOptions) -> Result<Option<Self>, DocLoaderError> {
+        if options.record_path.is_none() && options.text_paths.is_empty() && options.metadata_paths.is_empty() {
+            return Ok(None);
+        }
+        let parse_all = |expressions: &[String]| {
+            expressions.iter().map(|expression| Selector::parse(expression)).collect::<Result<Vec<_>, _>>()
+        };
+        let text = parse_all(&options.text_paths)?;
+        let metadata = parse_all(&options.metadata_paths)?;
+        
+        let records = match &options.record_path {
+            Some(expression) => Some(Selector::parse(expression)?),
+            None => {
+                let prefixes: Vec<Option<Selector>> = text.iter().chain(&metadata).map(Selector::wildcard_prefix).collect();
+                prefixes.first().cloned().flatten().filter(|first| prefixes.iter().all(|prefix| prefix.as_ref() == Some(first)))
+            },
+        };
+        let relative = |selectors: Vec<Selector>| match &records {
+            Some(records) => selectors.into_iter()
+                .map(|selector| selector.strip_prefix(records).unwrap_or(selector))
+                .collect(),
+            None => selectors,
+        };
+        Ok(Some(Self { text: relative(text), metadata: relative(metadata), records }))
+    }
+    
+    /// Vrai si les champs du texte ou des métadonnées sont choisis par sélecteur
+    fn selects_fields(&self) -> bool {
+        !self.text.is_empty() || !self.metadata.is_empty()
+    }
+    
+    /// Enregistrements désignés et leur JSON Pointer
+    fn records<'a>(&self, value: &'a Value, record_mode: bool) -> Vec<(String, &'a Value)> {
+        let selected = match &self.records {
+            Some(records) => records.select(value),
+            None => vec![(String::new(), value)],
+        };
+        let expand = self.records.as_ref().map_or(record_mode, Selector::is_single);
+        match selected.as_slice() {
+            [(pointer, Value::Array(elements))] if expand => elements.iter()
+                .enumerate()
+                .map(|(index, element)| (format!("{}/{}", pointer, index), element))
+                .collect(),
+            _ => selected,
+        }
+    }
+}
+
+/// Texte d'un chunk et sa position dans le texte complet
+struct JsonChunk {
+    text: String,
+    offset: usize,
+    /// JSON Pointer de la valeur d'origine (`""` pour le document entier)
+    pointer: String,
+    /// JSON Pointers des valeurs formant le texte, quand elles sont choisies par sélecteur
+    text_pointers: Vec<String>,
+    /// Indice de l'enregistrement et champs conservés en métadonnées (mode enregistrement, sélecteurs)
+    record: Option<(usize, Map<String, Value>)>,
+}
+
+/// Nom de fichier JSON Lines (`events.jsonl`, `logs.ndjson`)
+fn is_json_lines_name(name: &str) -> bool {
+    Path::new(name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| JSON_LINES_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+}
+
+/// Contenu JSON Lines : les deux premières lignes non vides sont chacune un objet ou un tableau JSON
+pub(crate) fn looks_like_json_lines(content: &[u8]) -> bool {
+    let lines: Vec<&[u8]> = content.split(|&byte| byte == b'\n')
+        .map(<[u8]>::trim_ascii)
+        .filter(|line| !line.is_empty())
+        .take(2)
+        .collect();
+    lines.len() == 2 && lines.iter().all(|line| {
+        matches!(serde_json::from_slice::<Value>(line), Ok(Value::Object(_) | Value::Array(_)))
+    })
+}
+
+/// Lit la ligne suivante dans `line` : `None` en fin de flux, `Some(false)` si la ligne dépasse
+/// `max_size` octets (elle est alors consommée sans être conservée en mémoire)
+fn read_line(reader: &mut impl BufRead, line: &mut Vec<u8>, max_size: u64) -> std::io::Result<Option<bool>> {
+    line.clear();
+    if (&mut *reader).take(max_size + 1).read_until(b'\n', line)? == 0 {
+        return Ok(None);
+    }
+    if line.len() as u64 <= max_size || line.ends_with(b"\n") {
+        return Ok(Some(true));
+    }
+    loop {
+        line.clear();
+        if (&mut *reader).take(max_size).read_until(b'\n', line)? == 0 || line.ends_with(b"\n") {
+            break;
+        }
+    }
+    line.clear();
+    Ok(Some(false))
+}
+
+/// Ajoute `text_pointers` aux métadonnées d'un chunk dont le texte provient de sélecteurs
+fn with_text_pointers(mut format_specific: Value, text_pointers: Vec<String>) -> Value {
+    if !text_pointers.is_empty() {
+        format_specific["text_pointers"] = json!(text_pointers);
+    }
+    format_specific
+}
+
 pub struct JsonProcessor;
 
 impl JsonProcessor {
@@ -19,16 +273,35 @@ impl JsonProcessor {
         Self
     }
     
-    /// Lit et parse un fichier JSON
-    fn read_json_file(&self, file_path: &Path) -> Result<Value, DocLoaderError> {
-        let content = fs::read_to_string(file_path)?;
-        let json_value: Value = serde_json::from_str(&content)?;
-        Ok(json_value)
+    /// Convertit une valeur JSON en texte lisible pour l'extraction (style `paths`, options par défaut)
+    pub(crate) fn json_to_text(&self, value: &Value, path: &str, depth: usize) -> String {
+        self.paths_text(value, path, depth, &JsonOptions::default())
+    }
+    
+    /// Texte aplati d'un document selon `options.style` ; hors style `paths`, chaque valeur est
+    /// nettoyée séparément pour préserver la mise en forme
+    fn flatten(&self, value: &Value, options: &JsonOptions, clean: bool) -> String {
+        let mut lines = Vec::new();
+        match options.style {
+            FlattenStyle::Paths => return self.paths_text(value, "", 0, options),
+            FlattenStyle::Values => self.values_lines(value, 0, options, clean, &mut lines),
+            FlattenStyle::Yaml => self.yaml_lines(value, 0, 0, options, clean, &mut lines),
+            FlattenStyle::Markdown => {
+                self.markdown_blocks(value, None, 0, options, clean, &mut lines);
+                return lines.join("\n\n");
+            },
+        }
+        lines.join("\n")
+    }
+    
+    /// Entrées d'un objet conservées par `drop_keys`
+    fn kept_entries<'a>(&self, object: &'a Map<String, Value>, options: &'a JsonOptions) -> impl Iterator<Item = (&'a String, &'a Value)> {
+        object.iter().filter(|(key, _)| !options.drop_keys.iter().any(|pattern| key_matches(pattern, key)))
     }
     
-    /// Convertit une valeur JSON en texte lisible pour l'extraction
-    fn json_to_text(&self, value: &Value, path: &str, depth: usize) -> String {
-        if depth > 10 { // Limite de profondeur pour éviter les récursions infinies
+    /// Style `paths` : `chemin=valeur`, chaînes entre guillemets
+    fn paths_text(&self, value: &Value, path: &str, depth: usize, options: &JsonOptions) -> String {
+        if depth > options.max_depth { // Limite de profondeur pour éviter les récursions infinies
             return format!("{}=[MAX_DEPTH_REACHED]", path);
         }
         
@@ -41,21 +314,21 @@ impl JsonProcessor {
                 let mut text = format!("{}=[", path);
                 for (i, item) in arr.iter().enumerate() {
                     if i > 0 { text.push_str(", "); }
-                    text.push_str(&self.json_to_text(item, &format!("{}[{}]", path, i), depth + 1));
+                    text.push_str(&self.paths_text(item, &format!("{}[{}]", path, i), depth + 1, options));
                 }
                 text.push(']');
                 text
             },
             Value::Object(obj) => {
                 let mut text = format!("{}={{", path);
-                for (i, (key, val)) in obj.iter().enumerate() {
+                for (i, (key, val)) in self.kept_entries(obj, options).enumerate() {
                     if i > 0 { text.push_str(", "); }
                     let new_path = if path.is_empty() { 
                         key.clone() 
                     } else { 
                         format!("{}.{}", path, key) 
                     };
-                    text.push_str(&self.json_to_text(val, &new_path, depth + 1));
+                    text.push_str(&self.paths_text(val, &new_path, depth + 1, options));
                 }
                 text.push('}');
                 text
@@ -63,6 +336,112 @@ impl JsonProcessor {
         }
     }
     
+    /// Style `values` : une ligne par valeur scalaire
+    fn values_lines(&self, value: &Value, depth: usize, options: &JsonOptions, clean: bool, lines: &mut Vec<String>) {
+        if depth > options.max_depth {
+            return;
+        }
+        match value {
+            Value::Array(items) => items.iter().for_each(|item| self.values_lines(item, depth + 1, options, clean, lines)),
+            Value::Object(object) => self.kept_entries(object, options)
+                .for_each(|(_, item)| self.values_lines(item, depth + 1, options, clean, lines)),
+            scalar => lines.extend(scalar_text(scalar, clean)),
+        }
+    }
+    
+    /// Style `yaml` : `clé: valeur` indenté de deux espaces par niveau, éléments de tableau en `- `
+    fn yaml_lines(&self, value: &Value, indent: usize, depth: usize, options: &JsonOptions, clean: bool, lines: &mut Vec<String>) {
+        let pad = "  ".repeat(indent);
+        // Les entrées d'un objet ou d'un tableau sont à la profondeur `depth + 1`
+        let entries: Vec<(Option<&str>, &Value)> = match value {
+            _ if depth >= options.max_depth => return,
+            Value::Object(object) => self.kept_entries(object, options).map(|(key, item)| (Some(key.as_str()), item)).collect(),
+            Value::Array(items) => items.iter().map(|item| (None, item)).collect(),
+            scalar => {
+                lines.extend(scalar_text(scalar, clean).map(|text| format!("{}{}", pad, text)));
+                return;
+            },
+        };
+        
+        for (key, item) in entries {
+            let label = key.map_or_else(|| "- ".to_string(), |key| format!("{}: ", key));
+            if let Some(text) = scalar_text(item, clean) {
+                lines.push(format!("{}{}{}", pad, label, text));
+                continue;
+            }
+            let nested = match item {
+                Value::Array(items) => !items.is_empty(),
+                Value::Object(object) => !object.is_empty(),
+                _ => false,
+            };
+            if !nested {
+                continue;
+            }
+            
+            let mut item_lines = Vec::new();
+            self.yaml_lines(item, indent + 1, depth + 1, options, clean, &mut item_lines);
+            if item_lines.is_empty() {
+                continue;
+            }
+            match key {
+                Some(_) => {
+                    lines.push(format!("{}{}", pad, label.trim_end()));
+                    lines.extend(item_lines);
+                },
+                // Un élément composé commence sur la ligne du tiret : `- name: Ada`
+                None => lines.extend(item_lines.into_iter().enumerate().map(|(index, line)| match index {
+                    0 => format!("{}- {}", pad, &line[pad.len() + 2..]),
+                    _ => line,
+                })),
+            }
+        }
+    }
+    
+    /// Style `markdown` : un titre par objet ou tableau imbriqué (niveau 6 au plus), les valeurs
+    /// scalaires en liste à puces `- **clé**: valeur` ; le niveau du titre suit la profondeur
+    fn markdown_blocks(
+        &self,
+        value: &Value,
+        title: Option<&str>,
+        depth: usize,
+        options: &JsonOptions,
+        clean: bool,
+        blocks: &mut Vec<String>,
+    ) {
+        if depth >= options.max_depth && (value.is_object() || value.is_array()) {
+            return;
+        }
+        if let Some(title) = title {
+            blocks.push(format!("{} {}", "#".repeat(depth.clamp(1, 6)), title));
+        }
+        let entries: Vec<(String, &Value)> = match value {
+            Value::Object(object) => self.kept_entries(object, options).map(|(key, item)| (key.clone(), item)).collect(),
+            Value::Array(items) => items.iter()
+                .enumerate()
+                .map(|(index, item)| (format!("{} {}", title.unwrap_or("Item"), index + 1), item))
+                .collect(),
+            scalar => {
+                blocks.extend(scalar_text(scalar, clean));
+                return;
+            },
+        };
+        
+        let bullets: Vec<String> = entries.iter()
+            .filter_map(|(name, item)| scalar_text(item, clean).map(|text| match value {
+                Value::Object(_) => format!("- **{}**: {}", name, text),
+                _ => format!("- {}", text),
+            }))
+            .collect();
+        if !bullets.is_empty() {
+            blocks.push(bullets.join("\n"));
+        }
+        for (name, item) in entries {
+            if item.is_object() || item.is_array() {
+                self.markdown_blocks(item, Some(&name), depth + 1, options, clean, blocks);
+            }
+        }
+    }
+    
     /// Extrait les métadonnées du JSON
     fn extract_json_metadata(&self, json_value: &Value) -> serde_json::Value {
         let mut metadata = Map::new();
@@ -118,105 +497,277 @@ impl JsonProcessor {
             _ => 0,
         }
     }
-}
-
-impl DocumentProcessor for JsonProcessor {
-    fn supported_type(&self) -> DocumentType {
-        DocumentType::JSON
+    
+    /// Objet restreint aux clés de `include_fields` et `exclude_fields`
+    fn select_object(&self, object: &Map<String, Value>, options: &JsonOptions) -> Map<String, Value> {
+        let fields = object.iter().map(|(key, value)| (key.clone(), value.clone())).collect();
+        records::select_fields(fields, &options.include_fields, &options.exclude_fields)
+            .into_iter()
+            .collect()
     }
     
-    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
-        let start_time = std::time::Instant::now();
-        
-        // Vérifier que le fichier existe
-        if !file_path.exists() {
-            return Err(DocLoaderError::FileNotFound(
-                format!("File not found: {}", file_path.display())
-            ));
-        }
-        
-        // Lire et parser le JSON
-        let json_value = self.read_json_file(file_path)?;
-        
-        // Convertir en texte pour l'extraction
-        let raw_text = self.json_to_text(&json_value, "", 0);
-        
-        // Nettoyer le texte si demandé
-        let text = if params.text_cleaning {
+    /// Texte d'un objet rendu par le gabarit (les chemins pointés parcourent l'objet complet)
+    fn render_template(&self, template: &records::Template, value: &Value, options: &JsonOptions, params: &ProcessingParams) -> String {
+        let text = template.render(|path| records::lookup_path(value, path).map(records::value_to_text), &options.missing_value);
+        if params.text_cleaning { clean_text(&text) } else { text }
+    }
+    
+    /// Document aplati (ou rendu par le gabarit, un objet par ligne) puis découpé par `chunk_text`
+    fn flattened_chunks(&self, json_value: &Value, options: &JsonOptions, params: &ProcessingParams) -> (Vec<JsonChunk>, String) {
+        let filter = !options.include_fields.is_empty() || !options.exclude_fields.is_empty();
+        // Le gabarit et les styles autres que `paths` sont nettoyés valeur par valeur pour conserver leurs lignes
+        let raw_text = match (options.template.as_deref().map(records::Template::parse), json_value) {
+            (Some(template), Value::Array(elements)) => elements.iter()
+                .map(|element| self.render_template(&template, element, options, params))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            (Some(template), value) => self.render_template(&template, value, options, params),
+            (None, Value::Object(object)) if filter => {
+                self.flatten(&Value::Object(self.select_object(object, options)), options, params.text_cleaning)
+            },
+            (None, Value::Array(elements)) if filter => {
+                let elements = elements.iter()
+                    .map(|element| match element {
+                        Value::Object(object) => Value::Object(self.select_object(object, options)),
+                        other => other.clone(),
+                    })
+                    .collect();
+                self.flatten(&Value::Array(elements), options, params.text_cleaning)
+            },
+            (None, value) => self.flatten(value, options, params.text_cleaning),
+        };
+        let text = if params.text_cleaning && options.template.is_none() && options.style == FlattenStyle::Paths {
             clean_text(&raw_text)
         } else {
             raw_text
         };
         
-        // Découper en chunks
-        let chunks_text = chunk_text(&text, params.max_chunk_size, params.chunk_overlap);
+        let chunks = chunk_text(&text, params.max_chunk_size, params.chunk_overlap)
+            .into_iter()
+            .enumerate()
+            .map(|(index, chunk_text)| JsonChunk {
+                offset: if index == 0 { 0 } else { text.find(&chunk_text).unwrap_or(0) },
+                text: chunk_text,
+                pointer: String::new(),
+                text_pointers: Vec::new(),
+                record: None,
+            })
+            .collect();
+        (chunks, text)
+    }
+    
+    /// Texte d'un enregistrement : les champs choisis (ou le gabarit) forment le texte, les autres
+    /// les métadonnées ; renvoie aussi les pointeurs des valeurs choisies par sélecteur
+    fn record_text(
+        &self,
+        element: &Value,
+        pointer: &str,
+        selection: Option<&Selection>,
+        template: Option<&records::Template>,
+        options: &JsonOptions,
+        params: &ProcessingParams,
+    ) -> (String, Map<String, Value>, Vec<String>) {
+        let (text, mut metadata) = self.record_fields(element, template, options, params);
+        let Some(selection) = selection.filter(|selection| selection.selects_fields()) else {
+            return (text, metadata, Vec::new());
+        };
         
-        // Créer les chunks avec métadonnées
+        let mut text_fields = Vec::new();
+        let mut text_pointers = Vec::new();
+        for selector in &selection.text {
+            if let Some((value, pointers)) = selector.select_value(element) {
+                text_pointers.extend(pointers.into_iter().map(|relative| format!("{}{}", pointer, relative)));
+                text_fields.push((selector.name(), value));
+            }
+        }
+        let text = if template.is_some() || selection.text.is_empty() {
+            text
+        } else {
+            records::fields_to_text(&text_fields, params.text_cleaning)
+        };
+        
+        if !selection.metadata.is_empty() {
+            metadata = selection.metadata.iter()
+                .filter_map(|selector| selector.select_value(element).map(|(value, _)| (selector.name(), value)))
+                .collect();
+        } else {
+            // Sans gabarit, `render_record` a placé tous les champs dans le texte
+            if template.is_none() {
+                metadata = match element {
+                    Value::Object(object) => self.select_object(object, options),
+                    _ => Map::new(),
+                };
+            }
+            metadata.retain(|key, _| !selection.text.iter().any(|selector| selector.first_key() == Some(key.as_str())));
+        }
+        (text, metadata, text_pointers)
+    }
+    
+    /// Texte et métadonnées d'un enregistrement selon `text_fields` ou le gabarit
+    fn record_fields(
+        &self,
+        element: &Value,
+        template: Option<&records::Template>,
+        options: &JsonOptions,
+        params: &ProcessingParams,
+    ) -> (String, Map<String, Value>) {
+        match element {
+            Value::Object(object) => records::render_record(
+                self.select_object(object, options).into_iter().collect(),
+                &options.text_fields,
+                template,
+                |path| records::lookup_path(element, path).map(records::value_to_text),
+                &options.missing_value,
+                params.text_cleaning,
+            ),
+            other => {
+                let text = records::value_to_text(other);
+                (if params.text_cleaning { clean_text(&text) } else { text }, Map::new())
+            },
+        }
+    }
+    
+    /// Un chunk par enregistrement (élément du tableau racine ou valeur désignée par `record_path`)
+    fn record_chunks(
+        &self,
+        elements: Vec<(String, &Value)>,
+        selection: Option<&Selection>,
+        options: &JsonOptions,
+        params: &ProcessingParams,
+    ) -> (Vec<JsonChunk>, String) {
+        let template = options.template.as_deref().map(records::Template::parse);
         let mut chunks = Vec::new();
+        let mut full_text = String::new();
         
-        for (index, chunk_text) in chunks_text.iter().enumerate() {
-            let text_meta = extract_text_metadata(chunk_text);
+        for (index, (pointer, element)) in elements.into_iter().enumerate() {
+            let (text, metadata, text_pointers) = self.record_text(element, &pointer, selection, template.as_ref(), options, params);
             
-            let start_offset = if index == 0 { 
-                0 
-            } else { 
-                text.find(chunk_text).unwrap_or(0)
+            if !full_text.is_empty() {
+                full_text.push_str("\n\n");
+            }
+            let offset = full_text.len();
+            full_text.push_str(&text);
+            chunks.push(JsonChunk { text, offset, pointer, text_pointers, record: Some((index, metadata)) });
+        }
+        (chunks, full_text)
+    }
+    
+    /// Lit un flux JSON Lines ligne à ligne : chaque ligne non vide est un enregistrement rendu
+    /// en un chunk ; les lignes vides ou invalides sont ignorées et signalées dans les avertissements
+    fn process_lines(
+        &self,
+        mut reader: impl BufRead,
+        options: &JsonOptions,
+        document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> Result<UniversalOutput, DocLoaderError> {
+        let template = options.template.as_deref().map(records::Template::parse);
+        let selection = Selection::from_options(options)?;
+        let mut warnings = RecordWarnings::default();
+        let mut chunks = Vec::new();
+        let mut element_types = Map::new();
+        let mut max_depth = 0;
+        let mut offset = 0;
+        let mut total_words = 0;
+        let mut estimated_tokens = 0;
+        let mut language_sample = String::new();
+        
+        let mut line_number = 0;
+        let mut bytes = Vec::new();
+        while let Some(complete) = read_line(&mut reader, &mut bytes, MAX_LINE_SIZE)? {
+            line_number += 1;
+            if !complete {
+                warnings.skipped(line_number, format!("line longer than {} bytes", MAX_LINE_SIZE));
+                continue;
+            }
+            let line = bytes.trim_ascii();
+            if line.is_empty() {
+                warnings.skipped(line_number, "blank line".to_string());
+                continue;
+            }
+            let element: Value = match serde_json::from_slice(line) {
+                Ok(element) => element,
+                Err(e) => {
+                    warnings.skipped(line_number, format!("invalid JSON ({})", e));
+                    continue;
+                },
             };
             
-            let chunk = DocumentChunk {
-                id: format!("json_chunk_{}", index),
-                content: chunk_text.clone(),
-                chunk_index: index,
-                position: ChunkPosition {
-                    page: None,
-                    line: None,
-                    start_offset: Some(start_offset),
-                    end_offset: Some(start_offset + chunk_text.len()),
-                },
-                metadata: ChunkMetadata {
-                    size: chunk_text.len(),
-                    language: if params.language_detection { text_meta.detected_language } else { None },
-                    confidence: Some(1.0), // Confiance maximale pour l'extraction JSON
-                    format_specific: Some(json!({
-                        "word_count": text_meta.word_count,
-                        "estimated_tokens": text_meta.estimated_tokens,
-                        "json_type": match &json_value {
-                            Value::Object(_) => "object",
-                            Value::Array(_) => "array",
-                            _ => "primitive"
-                        }
-                    })),
-                },
+            let element_type = match &element {
+                Value::Object(_) => "object",
+                Value::Array(_) => "array",
+                Value::String(_) => "string",
+                Value::Number(_) => "number",
+                Value::Bool(_) => "boolean",
+                Value::Null => "null",
             };
+            let count = element_types.get(element_type).and_then(|v| v.as_u64()).unwrap_or(0);
+            element_types.insert(element_type.to_string(), json!(count + 1));
+            max_depth = max_depth.max(self.calculate_depth(&element));
             
-            chunks.push(chunk);
+            // Sans `record_path`, chaque ligne est un enregistrement ; les pointeurs sont relatifs à la ligne
+            let line_records = match &selection {
+                Some(selection) => selection.records(&element, false),
+                None => vec![(String::new(), &element)],
+            };
+            for (pointer, record) in line_records {
+                let (chunk_text, fields, text_pointers) = self.record_text(record, &pointer, selection.as_ref(), template.as_ref(), options, params);
+                let text_meta = extract_text_metadata(&chunk_text);
+                if !chunks.is_empty() {
+                    offset += 2;
+                }
+                let start_offset = offset;
+                offset += chunk_text.len();
+                total_words += text_meta.word_count;
+                estimated_tokens += text_meta.estimated_tokens;
+                if language_sample.len() < LANGUAGE_SAMPLE_SIZE {
+                    language_sample.push_str(&chunk_text);
+                    language_sample.push_str("\n\n");
+                }
+                
+                let index = chunks.len();
+                chunks.push(DocumentChunk {
+                    id: format!("json_chunk_{}", index),
+                    content: chunk_text.clone(),
+                    chunk_index: index,
+                    position: ChunkPosition {
+                        page: None,
+                        line: Some(line_number as u32),
+                        start_offset: Some(start_offset),
+                        end_offset: Some(start_offset + chunk_text.len()),
+                    },
+                    metadata: ChunkMetadata {
+                        size: chunk_text.len(),
+                        language: if params.language_detection { text_meta.detected_language } else { None },
+                        confidence: Some(1.0), // Confiance maximale pour l'extraction JSON
+                        format_specific: Some(with_text_pointers(json!({
+                            "word_count": text_meta.word_count,
+                            "estimated_tokens": text_meta.estimated_tokens,
+                            "json_type": "lines",
+                            "line": line_number,
+                            "element_index": index,
+                            "pointer": pointer,
+                            "fields": fields
+                        }), text_pointers)),
+                    },
+                });
+            }
         }
         
-        // Métadonnées du fichier
-        let file_metadata = fs::metadata(file_path)?;
-        let json_metadata = self.extract_json_metadata(&json_value);
-        let text_meta = extract_text_metadata(&text);
-        
-        let document_metadata = DocumentMetadata {
-            filename: file_path.file_name()
-                .and_then(|name| name.to_str())
-                .unwrap_or("unknown.json")
-                .to_string(),
-            filepath: file_path.to_string_lossy().to_string(),
-            document_type: DocumentType::JSON,
-            file_size: file_metadata.len(),
-            created_at: file_metadata.created().ok().map(|t| t.into()),
-            modified_at: file_metadata.modified().ok().map(|t| t.into()),
-            title: None, // Les fichiers JSON n'ont généralement pas de titre
-            author: None, // Les fichiers JSON n'ont généralement pas d'auteur
-            format_metadata: json!({
-                "json_metadata": json_metadata,
-                "total_words": text_meta.word_count,
-                "total_characters": text_meta.character_count,
-                "estimated_tokens": text_meta.estimated_tokens,
-                "detected_language": text_meta.detected_language
-            }),
-        };
+        let document_metadata = document_metadata(json!({
+            "json_metadata": {
+                "type": "lines",
+                "record_count": chunks.len(),
+                "skipped_lines": warnings.skipped_count(),
+                "element_types": element_types,
+                "max_depth": max_depth
+            },
+            "total_words": total_words,
+            "total_characters": offset,
+            "estimated_tokens": estimated_tokens,
+            "detected_language": detect_language(&language_sample)
+        }));
         
         let processing_time = start_time.elapsed();
         let processing_info = ProcessingInfo {
@@ -225,8 +776,9 @@ impl DocumentProcessor for JsonProcessor {
             processed_at: Utc::now(),
             processing_time_ms: processing_time.as_millis() as u64,
             total_chunks: chunks.len(),
-            total_content_size: text.len(),
+            total_content_size: offset,
             processing_params: params.clone(),
+            warnings: warnings.into_warnings(),
         };
         
         Ok(UniversalOutput {
@@ -236,38 +788,78 @@ impl DocumentProcessor for JsonProcessor {
         })
     }
     
-    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
-        let start_time = std::time::Instant::now();
-        
-        // Parser le JSON depuis les bytes
-        let json_value: Value = serde_json::from_slice(content)?;
-        
-        // Convertir en texte pour l'extraction
-        let raw_text = self.json_to_text(&json_value, "", 0);
+    /// Traite un contenu en mémoire : document JSON unique, ou JSON Lines selon les options,
+    /// l'extension ou le contenu
+    fn process_bytes(
+        &self,
+        content: &[u8],
+        name: &str,
+        document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> Result<UniversalOutput, DocLoaderError> {
+        let options = JsonOptions::from_params(params)?;
+        if options.lines.unwrap_or_else(|| is_json_lines_name(name)) {
+            return self.process_lines(content, &options, document_metadata, params, start_time);
+        }
         
-        // Nettoyer le texte si demandé
-        let text = if params.text_cleaning {
-            clean_text(&raw_text)
-        } else {
-            raw_text
+        match serde_json::from_slice::<Value>(content) {
+            Ok(json_value) => self.build_output(&json_value, &options, document_metadata, params, start_time),
+            Err(_) if options.lines.is_none() && looks_like_json_lines(content) => {
+                self.process_lines(content, &options, document_metadata, params, start_time)
+            },
+            Err(e) => Err(e.into()),
+        }
+    }
+    
+    fn build_output(
+        &self,
+        json_value: &Value,
+        options: &JsonOptions,
+        document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> Result<UniversalOutput, DocLoaderError> {
+        let json_type = match json_value {
+            Value::Object(_) => "object",
+            Value::Array(_) => "array",
+            _ => "primitive"
         };
         
-        // Découper en chunks
-        let chunks_text = chunk_text(&text, params.max_chunk_size, params.chunk_overlap);
+        let selection = Selection::from_options(options)?;
+        let (json_chunks, text) = match (&selection, json_value) {
+            (Some(selection), value) => {
+                self.record_chunks(selection.records(value, options.record_mode), Some(selection), options, params)
+            },
+            (None, Value::Array(elements)) if options.record_mode => {
+                let elements = elements.iter().enumerate().map(|(index, element)| (format!("/{}", index), element)).collect();
+                self.record_chunks(elements, None, options, params)
+            },
+            _ => {
+                if options.record_mode {
+                    log::warn!("JSON record mode requires a top-level array, falling back to flattened text");
+                }
+                self.flattened_chunks(json_value, options, params)
+            },
+        };
         
         // Créer les chunks avec métadonnées
         let mut chunks = Vec::new();
         
-        for (index, chunk_text) in chunks_text.iter().enumerate() {
-            let text_meta = extract_text_metadata(chunk_text);
-            
-            let start_offset = if index == 0 { 
-                0 
-            } else { 
-                text.find(chunk_text).unwrap_or(0)
-            };
+        for (index, JsonChunk { text: chunk_text, offset: start_offset, pointer, text_pointers, record }) in json_chunks.into_iter().enumerate() {
+            let text_meta = extract_text_metadata(&chunk_text);
+            let mut format_specific = with_text_pointers(json!({
+                "word_count": text_meta.word_count,
+                "estimated_tokens": text_meta.estimated_tokens,
+                "json_type": json_type,
+                "pointer": pointer
+            }), text_pointers);
+            if let Some((element_index, fields)) = record {
+                format_specific["element_index"] = json!(element_index);
+                format_specific["fields"] = Value::Object(fields);
+            }
             
-            let chunk = DocumentChunk {
+            chunks.push(DocumentChunk {
                 id: format!("json_chunk_{}", index),
                 content: chunk_text.clone(),
                 chunk_index: index,
@@ -280,42 +872,21 @@ impl DocumentProcessor for JsonProcessor {
                 metadata: ChunkMetadata {
                     size: chunk_text.len(),
                     language: if params.language_detection { text_meta.detected_language } else { None },
-                    confidence: Some(1.0),
-                    format_specific: Some(json!({
-                        "word_count": text_meta.word_count,
-                        "estimated_tokens": text_meta.estimated_tokens,
-                        "json_type": match &json_value {
-                            Value::Object(_) => "object",
-                            Value::Array(_) => "array",
-                            _ => "primitive"
-                        }
-                    })),
+                    confidence: Some(1.0), // Confiance maximale pour l'extraction JSON
+                    format_specific: Some(format_specific),
                 },
-            };
-            
-            chunks.push(chunk);
+            });
         }
         
-        let json_metadata = self.extract_json_metadata(&json_value);
+        let json_metadata = self.extract_json_metadata(json_value);
         let text_meta = extract_text_metadata(&text);
-        
-        let document_metadata = DocumentMetadata {
-            filename: filename.to_string(),
-            filepath: format!("memory://{}", filename),
-            document_type: DocumentType::JSON,
-            file_size: content.len() as u64,
-            created_at: Some(Utc::now()),
-            modified_at: Some(Utc::now()),
-            title: None,
-            author: None,
-            format_metadata: json!({
-                "json_metadata": json_metadata,
-                "total_words": text_meta.word_count,
-                "total_characters": text_meta.character_count,
-                "estimated_tokens": text_meta.estimated_tokens,
-                "detected_language": text_meta.detected_language
-            }),
-        };
+        let document_metadata = document_metadata(json!({
+            "json_metadata": json_metadata,
+            "total_words": text_meta.word_count,
+            "total_characters": text_meta.character_count,
+            "estimated_tokens": text_meta.estimated_tokens,
+            "detected_language": text_meta.detected_language
+        }));
         
         let processing_time = start_time.elapsed();
         let processing_info = ProcessingInfo {
@@ -326,6 +897,7 @@ impl DocumentProcessor for JsonProcessor {
             total_chunks: chunks.len(),
             total_content_size: text.len(),
             processing_params: params.clone(),
+            warnings: Vec::new(),
         };
         
         Ok(UniversalOutput {
@@ -336,6 +908,100 @@ impl DocumentProcessor for JsonProcessor {
     }
 }
 
+impl DocumentProcessor for JsonProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::JSON
+    }
+    
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+        
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+        
+        let file_metadata = fs::metadata(file_path)?;
+        let document_metadata = |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.json")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::JSON,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None, // Les fichiers JSON n'ont généralement pas de titre
+            author: None, // Les fichiers JSON n'ont généralement pas d'auteur
+            format_metadata,
+        };
+        
+        // Les fichiers JSON Lines sont lus en flux, ligne par ligne
+        let options = JsonOptions::from_params(params)?;
+        let name = file_path.to_string_lossy();
+        if options.lines.unwrap_or_else(|| is_json_lines_name(&name)) {
+            let reader = BufReader::new(File::open(file_path)?);
+            return self.process_lines(reader, &options, document_metadata, params, start_time);
+        }
+        
+        let content = fs::read(file_path)?;
+        self.process_bytes(&content, &name, document_metadata, params, start_time)
+    }
+    
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+        
+        self.process_bytes(content, filename, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::JSON,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time)
+    }
+    
+    fn process_decompressed(&self, reader: &mut dyn Read, filename: &str, params: &ProcessingParams, registry: &ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+        
+        // Les flux JSON Lines sont lus ligne par ligne ; un document JSON unique est lu en mémoire.
+        // Dans les deux cas, le flux est borné par `max_decompressed_size`
+        let options = JsonOptions::from_params(params)?;
+        let mut bounded = BoundedReader::new(reader, params)?;
+        let mut reader = BufReader::new(&mut bounded);
+        let lines = match options.lines {
+            Some(lines) => lines,
+            None => is_json_lines_name(filename) || looks_like_json_lines(reader.fill_buf()?),
+        };
+        if !lines {
+            let content = compression::read_decompressed(&mut reader, filename, params)?;
+            return self.process_content_with_registry(&content, filename, params, registry);
+        }
+        
+        let result = self.process_lines(reader, &options, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::JSON,
+            file_size: 0, // Complétée une fois le flux lu
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time);
+        let file_size = bounded.check(filename)?;
+        let mut output = result?;
+        output.document_metadata.file_size = file_size;
+        Ok(output)
+    }
+}
+
 impl Default for JsonProcessor {
     fn default() -> Self {
         Self::new()
@@ -386,4 +1052,177 @@ mod tests {
         assert_eq!(output.document_metadata.document_type.to_string(), "JSON");
         assert!(!output.chunks.is_empty());
     }
+
+    #[test]
+    fn test_record_mode() {
+        let processor = JsonProcessor::new();
+        let content = serde_json::to_vec(&json!([
+            { "title": "Effective Java", "author": "Joshua Bloch", "year": 2018, "tags": ["java"] },
+            { "title": "Clean Code", "author": "Robert C. Martin", "year": 2008 },
+            "loose note"
+        ])).unwrap();
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "json": { "record_mode": true, "text_fields": ["title", "author"] } }));
+        
+        let output = processor.process_content(&content, "books.json", &params).unwrap();
+        assert_eq!(output.chunks.len(), 3);
+        assert_eq!(output.chunks[0].content, "title: Effective Java\nauthor: Joshua Bloch");
+        assert_eq!(output.chunks[2].content, "loose note");
+        
+        let metadata = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(metadata["element_index"], 1);
+        assert_eq!(metadata["fields"], json!({ "year": 2008 }));
+        let first = output.chunks[0].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(first["fields"], json!({ "year": 2018, "tags": ["java"] }));
+
+        // Sans `text_fields`, les champs suivent l'ordre du document
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "json": { "record_mode": true } }));
+        let output = processor.process_content(br#"[{"title": "T", "author": "A", "year": 2000}]"#, "books.json", &params).unwrap();
+        assert_eq!(output.chunks[0].content, "title: T\nauthor: A\nyear: 2000");
+        
+        let params = ProcessingParams::default().with_format_specific(json!({ "json": { "record_mode": "yes" } }));
+        let result = processor.process_content(&content, "books.json", &params);
+        assert!(matches!(result, Err(DocLoaderError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_templates() {
+        let processor = JsonProcessor::new();
+        let content = serde_json::to_vec(&json!([
+            { "id": 1, "title": "Effective Java", "author": { "name": "Joshua Bloch" }, "year": 2018, "summary": "Best practices" },
+            { "id": 2, "title": "Clean Code", "author": { "name": "Robert C. Martin" } }
+        ])).unwrap();
+        let template = "{title} by {author.name} ({year|n.d.}): {summary}";
+        
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "json": { "template": template, "missing_value": "-" } }));
+        let output = processor.process_content(&content, "books.json", &params).unwrap();
+        assert_eq!(output.chunks[0].content,
+            "Effective Java by Joshua Bloch (2018): Best practices\nClean Code by Robert C. Martin (n.d.): -");
+        
+        // En mode enregistrement, les champs non référencés et non exclus restent en métadonnées
+        let params = ProcessingParams::default().with_format_specific(json!({ "json": {
+            "record_mode": true, "template": "{title} ({year|n.d.})", "exclude_fields": ["id"]
+        } }));
+        let output = processor.process_content(&content, "books.json", &params).unwrap();
+        assert_eq!(output.chunks[1].content, "Clean Code (n.d.)");
+        let metadata = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(metadata["fields"], json!({ "author": { "name": "Robert C. Martin" } }));
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let processor = JsonProcessor::new();
+        let content = b"{\"level\": \"info\", \"message\": \"service started\", \"pid\": 42}\n\
+                        \n\
+                        {\"level\": \"error\", \"message\": \"disk full\"\n\
+                        {\"level\": \"warn\", \"message\": \"slow request\", \"pid\": 42}\n";
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "json": { "text_fields": ["message"] } }));
+        
+        let output = processor.process_content(content, "service.jsonl", &params).unwrap();
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[1].content, "message: slow request");
+        assert_eq!(output.chunks[1].position.line, Some(4));
+        let metadata = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(metadata["line"], 4);
+        assert_eq!(metadata["fields"], json!({ "level": "warn", "pid": 42 }));
+        
+        let warnings: Vec<Option<u64>> = output.processing_info.warnings.iter().map(|warning| warning.line).collect();
+        assert_eq!(warnings, vec![Some(2), Some(3)]);
+        assert!(output.processing_info.warnings[1].message.starts_with("Record skipped: invalid JSON"));
+        assert_eq!(output.document_metadata.format_metadata["json_metadata"]["skipped_lines"], 2);
+        
+        // Sans extension explicite, le contenu suffit à reconnaître le JSON Lines
+        let output = processor.process_content(b"{\"a\": 1}\n{\"a\": 2}\n", "export.json", &ProcessingParams::default()).unwrap();
+        assert_eq!(output.document_metadata.format_metadata["json_metadata"]["type"], "lines");
+        assert_eq!(output.chunks.len(), 2);
+        
+        // Une ligne trop longue est consommée sans être conservée
+        let mut reader = std::io::Cursor::new(b"{\"a\":1}\n{\"message\": \"far too long\"}\n{\"b\":2}".to_vec());
+        let mut line = Vec::new();
+        let mut lines = Vec::new();
+        while let Some(complete) = read_line(&mut reader, &mut line, 8).unwrap() {
+            lines.push((complete, String::from_utf8(line.clone()).unwrap()));
+        }
+        assert_eq!(lines, vec![(true, "{\"a\":1}\n".to_string()), (false, String::new()), (true, "{\"b\":2}".to_string())]);
+    }
+
+    #[test]
+    fn test_selectors() {
+        let processor = JsonProcessor::new();
+        let content = serde_json::to_vec(&json!({
+            "source": "crossref",
+            "articles": [
+                { "doi": "10.1000/a", "title": "Rust in production", "abstract": "Memory safety without garbage collection.", "pages": 12 },
+                { "doi": "10.1000/b", "title": "Typed JSON", "pages": 8 }
+            ]
+        })).unwrap();
+        
+        let params = ProcessingParams::default().with_format_specific(json!({ "json": {
+            "text_paths": ["$.articles[*].abstract"], "metadata_paths": ["$.articles[*].doi"]
+        } }));
+        let output = processor.process_content(&content, "articles.json", &params).unwrap();
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[0].content, "abstract: Memory safety without garbage collection.");
+        let metadata = output.chunks[0].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(metadata["pointer"], "/articles/0");
+        assert_eq!(metadata["text_pointers"], json!(["/articles/0/abstract"]));
+        assert_eq!(metadata["fields"], json!({ "doi": "10.1000/a" }));
+        
+        // JSON Pointer des enregistrements, sélecteurs relatifs, métadonnées par défaut
+        let params = ProcessingParams::default().with_format_specific(json!({ "json": {
+            "record_path": "/articles", "text_paths": ["/title"], "exclude_fields": ["pages"]
+        } }));
+        let output = processor.process_content(&content, "articles.json", &params).unwrap();
+        assert_eq!(output.chunks[1].content, "title: Typed JSON");
+        let metadata = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(metadata["pointer"], "/articles/1");
+        assert_eq!(metadata["fields"], json!({ "doi": "10.1000/b" }));
+        
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "json": { "text_paths": ["$..abstract"] } }));
+        let error = processor.process_content(&content, "articles.json", &params).unwrap_err();
+        assert!(error.to_string().contains("Invalid JSON selector '$..abstract'"));
+    }
+
+    #[test]
+    fn test_flatten_styles() {
+        let processor = JsonProcessor::new();
+        let document = json!({
+            "id": 7,
+            "title": "Clean Code",
+            "author": { "name": "Robert C. Martin", "_meta": { "source": "import" }, "homepage_url": "https://example.com" },
+            "tags": ["craft", "java"],
+            "editions": [{ "year": 2008, "publisher": { "name": "Prentice Hall" } }]
+        });
+        let options = |style: &str| serde_json::from_value::<JsonOptions>(json!({
+            "style": style, "drop_keys": ["id", "_meta", "*_url"]
+        })).unwrap();
+        
+        assert_eq!(processor.flatten(&document, &options("values"), true),
+            "Clean Code\nRobert C. Martin\ncraft\njava\n2008\nPrentice Hall");
+        assert_eq!(processor.flatten(&document, &options("yaml"), true), [
+            "title: Clean Code",
+            "author:", "  name: Robert C. Martin",
+            "tags:", "  - craft", "  - java",
+            "editions:", "  - year: 2008", "    publisher:", "      name: Prentice Hall",
+        ].join("\n"));
+        assert_eq!(processor.flatten(&document, &options("markdown"), true), [
+            "- **title**: Clean Code",
+            "# author", "- **name**: Robert C. Martin",
+            "# tags", "- craft\n- java",
+            "# editions", "## editions 1", "- **year**: 2008", "### publisher", "- **name**: Prentice Hall",
+        ].join("\n\n"));
+        
+        // La profondeur maximale s'applique à tous les styles
+        let shallow: JsonOptions = serde_json::from_value(json!({ "max_depth": 1, "drop_keys": ["editions"] })).unwrap();
+        let text = processor.flatten(&document, &shallow, false);
+        assert!(text.contains("author.name=[MAX_DEPTH_REACHED]"));
+        assert!(text.contains("id=7") && !text.contains("editions"));
+        let shallow = JsonOptions { style: FlattenStyle::Yaml, ..shallow };
+        assert!(!processor.flatten(&document, &shallow, false).contains("Robert"));
+        assert_eq!(JsonOptions::default().max_depth, 10);
+    }
 }
@@ -0,0 +1,988 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata, normalize_line_breaks};
+
+use std::path::{Path, PathBuf};
+use std::fs;
+use chrono::Utc;
+use serde_json::json;
+
+/// Profondeur maximale d'imbrication des `\input` / `\include`
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// Commandes de sectionnement et leur niveau LaTeX (`\part` = -1, `\section` = 1, ...)
+const SECTIONING: &[(&str, i32)] = &[
+    ("part", -1), ("chapter", 0), ("section", 1), ("subsection", 2),
+    ("subsubsection", 3), ("paragraph", 4), ("subparagraph", 5),
+];
+
+/// Environnements mathématiques conservés tels quels
+const MATH_ENVIRONMENTS: &[&str] = &[
+    "equation", "equation*", "align", "align*", "alignat", "alignat*", "gather", "gather*",
+    "multline", "multline*", "flalign", "flalign*", "eqnarray", "eqnarray*", "math", "displaymath",
+];
+
+/// Environnements dont le contenu est recopié sans interprétation
+const VERBATIM_ENVIRONMENTS: &[&str] = &["verbatim", "verbatim*", "Verbatim", "lstlisting", "minted", "alltt"];
+
+/// Environnements ignorés (commentaires, dessins)
+const SKIPPED_ENVIRONMENTS: &[&str] = &["comment", "tikzpicture", "pspicture", "picture"];
+
+/// Commandes de mise en forme supprimées avec leurs arguments (nombre d'arguments obligatoires)
+const DROPPED_COMMANDS: &[(&str, usize)] = &[
+    ("label", 1), ("index", 1), ("vspace", 1), ("vspace*", 1), ("hspace", 1), ("hspace*", 1),
+    ("includegraphics", 1), ("bibliographystyle", 1), ("bibliography", 1), ("addbibresource", 1),
+    ("pagestyle", 1), ("thispagestyle", 1), ("setlength", 2), ("addtolength", 2), ("setcounter", 2),
+    ("newcommand", 2), ("renewcommand", 2), ("providecommand", 2), ("newenvironment", 3),
+    ("renewenvironment", 3), ("usepackage", 1), ("documentclass", 1), ("thanks", 1),
+    ("hypersetup", 1), ("graphicspath", 1), ("input", 1), ("include", 1), ("color", 1),
+    ("definecolor", 3), ("fontsize", 2), ("linespread", 1),
+];
+
+/// Commandes de citation : les clés sont conservées entre crochets
+const CITE_COMMANDS: &[&str] = &[
+    "cite", "citep", "citet", "citealp", "citeauthor", "citeyear", "parencite", "textcite", "autocite", "footcite",
+    "ref", "eqref", "autoref", "cref", "Cref", "pageref", "nameref",
+];
+
+/// Lettres accentuées : (accent, lettres de base, lettres accentuées)
+const ACCENTS: &[(char, &str, &str)] = &[
+    ('\'', "aeiouyAEIOUYcnszCNSZ", "áéíóúýÁÉÍÓÚÝćńśźĆŃŚŹ"),
+    ('`', "aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+    ('^', "aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+    ('"', "aeiouyAEIOU", "äëïöüÿÄËÏÖÜ"),
+    ('~', "anoANO", "ãñõÃÑÕ"),
+    ('c', "cC", "çÇ"),
+];
+
+/// Section d'un document LaTeX
+#[derive(Debug, Clone, Default)]
+pub struct LatexSection {
+    pub heading: Option<String>,
+    /// Niveau LaTeX du titre (`\chapter` = 0, `\section` = 1, `\subsection` = 2, ...)
+    pub heading_level: Option<i32>,
+    /// Chemin des titres englobants
+    pub section_path: Vec<String>,
+    pub text: String,
+}
+
+/// Métadonnées d'un document LaTeX
+#[derive(Debug, Clone, Default)]
+pub struct LatexMetadata {
+    pub document_class: Option<String>,
+    pub packages: Vec<String>,
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    pub date: Option<String>,
+    pub abstract_text: Option<String>,
+    /// Fichiers inclus via `\input` / `\include`
+    pub includes: Vec<String>,
+    /// Inclusions non résolues (fichier absent, hors du répertoire du document, contenu en mémoire)
+    pub unresolved_includes: Vec<String>,
+    pub math_count: usize,
+}
+
+/// Supprime les commentaires `%` (hors `\%`)
+fn strip_comments(source: &str) -> String {
+    source.lines().map(|line| {
+        let mut backslashes = 0;
+        for (i, c) in line.char_indices() {
+            if c == '%' && backslashes % 2 == 0 {
+                return &line[..i];
+            }
+            backslashes = if c == '\\' { backslashes + 1 } else { 0 };
+        }
+        line
+    }).collect::<Vec<_>>().join("\n")
+}
+
+/// Lit un groupe `{...}` commençant à `pos` (espaces ignorés) ; retourne le contenu et la position suivante
+fn read_group(chars: &[char], pos: usize) -> Option<(String, usize)> {
+    read_delimited(chars, pos, '{', '}')
+}
+
+/// Lit un argument optionnel `[...]` commençant à `pos`
+fn read_optional(chars: &[char], pos: usize) -> Option<(String, usize)> {
+    read_delimited(chars, pos, '[', ']')
+}
+
+fn read_delimited(chars: &[char], pos: usize, open: char, close: char) -> Option<(String, usize)> {
+    let mut i = pos;
+    while i < chars.len() && chars[i].is_whitespace() {
+        i += 1;
+    }
+    if chars.get(i) != Some(&open) {
+        return None;
+    }
+
+    let mut depth = 0;
+    let start = i + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            c if c == open => depth += 1,
+            c if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((chars[start..i].iter().collect(), i + 1));
+                }
+            },
+            _ => {},
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Nom de commande commençant à `pos` (après la barre oblique inverse) : lettres, éventuellement suivies de `*`
+fn read_command_name(chars: &[char], pos: usize) -> (String, usize) {
+    let mut i = pos;
+    while i < chars.len() && chars[i].is_ascii_alphabetic() {
+        i += 1;
+    }
+    if i > pos && chars.get(i) == Some(&'*') {
+        i += 1;
+    }
+    (chars[pos..i].iter().collect(), i)
+}
+
+/// Premier argument de `\command` dans `source`
+fn command_argument(source: &str, command: &str) -> Option<String> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            let (name, end) = read_command_name(&chars, i + 1);
+            if name == command {
+                let after_optional = read_optional(&chars, end).map_or(end, |(_, next)| next);
+                return read_group(&chars, after_optional).map(|(arg, _)| arg);
+            }
+            i = end.max(i + 1);
+            continue;
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Contenu de `\begin{env}...\end{env}` et plage correspondante dans `source`
+fn environment(source: &str, env: &str) -> Option<(String, std::ops::Range<usize>)> {
+    let begin = format!("\\begin{{{}}}", env);
+    let end = format!("\\end{{{}}}", env);
+    let start = source.find(&begin)?;
+    let content_start = start + begin.len();
+    let content_end = content_start + source[content_start..].find(&end)?;
+    Some((source[content_start..content_end].to_string(), start..content_end + end.len()))
+}
+
+/// Lettre accentuée correspondant à `accent` + `letter`
+fn accented(accent: char, letter: char) -> char {
+    ACCENTS.iter()
+        .find(|(a, _, _)| *a == accent)
+        .and_then(|(_, base, marked)| base.chars().position(|c| c == letter).and_then(|i| marked.chars().nth(i)))
+        .unwrap_or(letter)
+}
+
+/// Passe à la ligne sauf si le texte rendu se termine déjà par un saut de ligne
+fn line_break(out: &mut String) {
+    out.truncate(out.trim_end_matches([' ', '\t']).len());
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+/// Position après les espaces et le saut de ligne qui suivent une commande de bloc
+fn skip_line_end(chars: &[char], pos: usize) -> usize {
+    let mut i = pos;
+    while i < chars.len() && (chars[i] == ' ' || chars[i] == '\t') {
+        i += 1;
+    }
+    if chars.get(i) == Some(&'\n') { i + 1 } else { pos }
+}
+
+/// Convertisseur du corps LaTeX en texte
+struct Renderer {
+    math_count: usize,
+}
+
+impl Renderer {
+    /// Rend du LaTeX en texte : mise en forme supprimée, mathématiques conservées
+    fn render(&mut self, source: &str) -> String {
+        let chars: Vec<char> = source.chars().collect();
+        let mut out = String::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            match c {
+                '\\' => i = self.render_command(&chars, i + 1, &mut out),
+                '$' => {
+                    // `$...$` ou `$$...$$`, recopiés tels quels
+                    let delimiter = if chars.get(i + 1) == Some(&'$') { "$$" } else { "$" };
+                    let start = i;
+                    let mut j = i + delimiter.len();
+                    while j < chars.len() {
+                        if chars[j] == '\\' {
+                            j += 2;
+                            continue;
+                        }
+                        if chars[j] == '$' && (delimiter == "$" || chars.get(j + 1) == Some(&'$')) {
+                            break;
+                        }
+                        j += 1;
+                    }
+                    let end = (j + delimiter.len()).min(chars.len());
+                    out.extend(&chars[start..end]);
+                    self.math_count += 1;
+                    i = end;
+                },
+                '{' | '}' => i += 1,
+                '~' => {
+                    out.push(' ');
+                    i += 1;
+                },
+                '-' if chars.get(i + 1) == Some(&'-') => {
+                    if chars.get(i + 2) == Some(&'-') {
+                        out.push('—');
+                        i += 3;
+                    } else {
+                        out.push('–');
+                        i += 2;
+                    }
+                },
+                '`' | '\'' if chars.get(i + 1) == Some(&c) => {
+                    out.push('"');
+                    i += 2;
+                },
+                '&' => {
+                    // Séparateur de colonnes des tableaux
+                    out.push_str(" | ");
+                    i += 1;
+                },
+                _ => {
+                    out.push(c);
+                    i += 1;
+                },
+            }
+        }
+        out
+    }
+
+    /// Rend une commande commençant après `\` ; retourne la position suivante
+    fn render_command(&mut self, chars: &[char], pos: usize, out: &mut String) -> usize {
+        let Some(&first) = chars.get(pos) else { return pos };
+
+        // Symboles de contrôle
+        if !first.is_ascii_alphabetic() {
+            let next = pos + 1;
+            match first {
+                '\\' => {
+                    out.push('\n');
+                    return read_optional(chars, next).map_or(next, |(_, end)| end);
+                },
+                ',' | ';' | ':' | '!' | ' ' => out.push(' '),
+                '(' | '[' => {
+                    // `\(...\)` et `\[...\]`, recopiés tels quels
+                    let close = if first == '(' { ')' } else { ']' };
+                    let mut j = next;
+                    while j + 1 < chars.len() && !(chars[j] == '\\' && chars[j + 1] == close) {
+                        j += 1;
+                    }
+                    let end = (j + 2).min(chars.len());
+                    out.extend(&chars[pos - 1..end]);
+                    self.math_count += 1;
+                    return end;
+                },
+                '\'' | '`' | '^' | '"' | '~' | '=' | '.' => {
+                    let (letter, end) = match read_group(chars, next) {
+                        Some((group, end)) => (group.chars().next(), end),
+                        None => (chars.get(next).copied(), next + 1),
+                    };
+                    if let Some(letter) = letter {
+                        out.push(accented(first, letter));
+                    }
+                    return end;
+                },
+                '-' | '@' | '/' => {},
+                _ => out.push(first),
+            }
+            return next;
+        }
+
+        let (name, mut end) = read_command_name(chars, pos);
+        let skip_optionals = |mut end: usize| {
+            while let Some((_, next)) = read_optional(chars, end) {
+                end = next;
+            }
+            end
+        };
+
+        match name.as_str() {
+            "begin" => {
+                let Some((env, after)) = read_group(chars, end) else { return end };
+                let env = env.trim();
+                let end_marker: Vec<char> = format!("\\end{{{}}}", env).chars().collect();
+                let find_end = |from: usize| (from..chars.len())
+                    .find(|&k| chars[k..].starts_with(&end_marker))
+                    .unwrap_or(chars.len());
+
+                if MATH_ENVIRONMENTS.contains(&env) {
+                    let close = find_end(after);
+                    let stop = (close + end_marker.len()).min(chars.len());
+                    line_break(out);
+                    out.extend(&chars[pos - 1..stop]);
+                    out.push('\n');
+                    self.math_count += 1;
+                    return skip_line_end(chars, stop);
+                }
+                if VERBATIM_ENVIRONMENTS.contains(&env) {
+                    let mut content_start = skip_optionals(after);
+                    if env == "minted" {
+                        content_start = read_group(chars, content_start).map_or(content_start, |(_, next)| next);
+                    }
+                    let close = find_end(content_start);
+                    out.extend(&chars[content_start..close]);
+                    return (close + end_marker.len()).min(chars.len());
+                }
+                if SKIPPED_ENVIRONMENTS.contains(&env) {
+                    return (find_end(after) + end_marker.len()).min(chars.len());
+                }
+
+                // Spécification de colonnes des tableaux
+                let mut next = skip_optionals(after);
+                let spec_groups = match env {
+                    "tabular" | "tabular*" | "array" | "longtable" => 1,
+                    "tabularx" | "tabulary" => 2,
+                    _ => 0,
+                };
+                for _ in 0..spec_groups {
+                    next = read_group(chars, next).map_or(next, |(_, end)| end);
+                }
+                line_break(out);
+                skip_line_end(chars, next)
+            },
+            "end" => {
+                line_break(out);
+                skip_line_end(chars, read_group(chars, end).map_or(end, |(_, next)| next))
+            },
+            "item" => {
+                line_break(out);
+                out.push_str("- ");
+                if let Some((label, next)) = read_optional(chars, end) {
+                    out.push_str(self.render(&label).trim());
+                    out.push(' ');
+                    end = next;
+                }
+                (end..chars.len()).find(|&k| !chars[k].is_whitespace()).unwrap_or(chars.len())
+            },
+            "bibitem" => {
+                let end = skip_optionals(end);
+                let Some((key, next)) = read_group(chars, end) else { return end };
+                out.push_str(&format!("\n[{}] ", key.trim()));
+                next
+            },
+            "footnote" => {
+                let end = skip_optionals(end);
+                let Some((text, next)) = read_group(chars, end) else { return end };
+                out.push_str(&format!(" ({})", self.render(&text).trim()));
+                next
+            },
+            "url" => {
+                let Some((url, next)) = read_group(chars, end) else { return end };
+                out.push_str(&url);
+                next
+            },
+            "href" => {
+                let after_url = read_group(chars, end).map_or(end, |(_, next)| next);
+                let Some((text, next)) = read_group(chars, after_url) else { return after_url };
+                out.push_str(&self.render(&text));
+                next
+            },
+            "caption" => {
+                let end = skip_optionals(end);
+                let Some((text, next)) = read_group(chars, end) else { return end };
+                out.push('\n');
+                out.push_str(self.render(&text).trim());
+                out.push('\n');
+                next
+            },
+            "c" => {
+                let (letter, next) = match read_group(chars, end) {
+                    Some((group, next)) => (group.trim().chars().next(), next),
+                    None => {
+                        let start = (end..chars.len()).find(|&k| !chars[k].is_whitespace()).unwrap_or(end);
+                        (chars.get(start).copied(), start + 1)
+                    },
+                };
+                if let Some(letter) = letter {
+                    out.push(accented('c', letter));
+                }
+                next
+            },
+            "ldots" | "dots" | "textellipsis" => {
+                out.push('…');
+                end
+            },
+            "LaTeX" | "TeX" | "LaTeXe" => {
+                out.push_str(if name == "TeX" { "TeX" } else { "LaTeX" });
+                end
+            },
+            "textbackslash" => {
+                out.push('\\');
+                end
+            },
+            "ss" => { out.push('ß'); end },
+            "ae" => { out.push('æ'); end },
+            "oe" => { out.push('œ'); end },
+            "o" => { out.push('ø'); end },
+            "aa" => { out.push('å'); end },
+            "l" => { out.push('ł'); end },
+            "S" => { out.push('§'); end },
+            "copyright" => { out.push('©'); end },
+            "par" | "newline" | "linebreak" => {
+                out.push('\n');
+                end
+            },
+            _ if CITE_COMMANDS.contains(&name.as_str()) => {
+                let end = skip_optionals(end);
+                let Some((keys, next)) = read_group(chars, end) else { return end };
+                let keys: Vec<&str> = keys.split(',').map(str::trim).collect();
+                out.push_str(&format!("[{}]", keys.join(", ")));
+                next
+            },
+            _ => {
+                if let Some((_, count)) = DROPPED_COMMANDS.iter().find(|(command, _)| *command == name) {
+                    let mut next = skip_optionals(end);
+                    for _ in 0..*count {
+                        next = skip_optionals(read_group(chars, next).map_or(next, |(_, end)| end));
+                    }
+                    return next;
+                }
+                // Commande inconnue ou de mise en forme (`\textbf`, `\emph`, ...) : seul son argument est conservé
+                end
+            },
+        }
+    }
+}
+
+/// Nettoie le texte rendu : espaces de fin de ligne et lignes vides multiples
+fn tidy(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank = 0;
+    for line in text.lines().map(str::trim) {
+        if line.is_empty() {
+            blank += 1;
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank > 0 { "\n\n" } else { "\n" });
+        }
+        out.push_str(line);
+        blank = 0;
+    }
+    out
+}
+
+pub struct LatexProcessor;
+
+impl LatexProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lit un fichier .tex en résolvant ses inclusions
+    fn read_latex_file(&self, file_path: &Path, metadata: &mut LatexMetadata) -> Result<String, DocLoaderError> {
+        let content = fs::read(file_path)?;
+        let base_dir = file_path.parent().map(Path::to_path_buf).unwrap_or_else(|| PathBuf::from("."));
+        let root = base_dir.canonicalize().unwrap_or_else(|_| base_dir.clone());
+        let mut ancestors = vec![file_path.canonicalize().unwrap_or_else(|_| file_path.to_path_buf())];
+        Ok(self.expand_includes(&String::from_utf8_lossy(&content), Some((&base_dir, &root)), &mut ancestors, 0, metadata))
+    }
+
+    /// Remplace `\input{...}` et `\include{...}` par le contenu du fichier, relatif au document.
+    ///
+    /// Les fichiers hors du répertoire du document principal, absents ou qui s'incluraient
+    /// eux-mêmes (cycle parmi `ancestors`, la chaîne d'inclusion en cours) ne sont pas lus ; un
+    /// même fichier inclus à deux endroits est développé deux fois.
+    fn expand_includes(
+        &self,
+        source: &str,
+        base: Option<(&Path, &Path)>,
+        ancestors: &mut Vec<PathBuf>,
+        depth: usize,
+        metadata: &mut LatexMetadata,
+    ) -> String {
+        let source = strip_comments(&normalize_line_breaks(source));
+        let chars: Vec<char> = source.chars().collect();
+        let mut out = String::with_capacity(source.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] != '\\' {
+                out.push(chars[i]);
+                i += 1;
+                continue;
+            }
+
+            let (name, end) = read_command_name(&chars, i + 1);
+            let group = if name == "input" || name == "include" { read_group(&chars, end) } else { None };
+            let Some((target, next)) = group else {
+                out.extend(&chars[i..end.max(i + 1)]);
+                i = end.max(i + 1);
+                continue;
+            };
+
+            let target = target.trim().to_string();
+            let resolved = base.filter(|_| depth < MAX_INCLUDE_DEPTH).and_then(|(dir, root)| {
+                let mut path = dir.join(&target);
+                if !path.exists() && path.extension().is_none() {
+                    path.set_extension("tex");
+                }
+                let canonical = path.canonicalize().ok()?;
+                (canonical.starts_with(root) && !ancestors.contains(&canonical)).then_some((path, canonical))
+            });
+
+            match resolved.and_then(|(path, canonical)| fs::read(&path).ok().map(|content| (path, canonical, content))) {
+                Some((path, canonical, content)) => {
+                    ancestors.push(canonical);
+                    metadata.includes.push(target);
+                    let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+                    let root = base.map(|(_, root)| root.to_path_buf()).unwrap_or_default();
+                    let included = self.expand_includes(
+                        &String::from_utf8_lossy(&content), Some((&dir, &root)), ancestors, depth + 1, metadata
+                    );
+                    ancestors.pop();
+                    out.push('\n');
+                    out.push_str(&included);
+                    out.push('\n');
+                },
+                None => {
+                    log::warn!("LaTeX include {} could not be resolved", target);
+                    metadata.unresolved_includes.push(target);
+                },
+            }
+            i = next;
+        }
+        out
+    }
+
+    /// Analyse un source LaTeX (inclusions déjà résolues) en sections et métadonnées
+    fn parse_latex(&self, source: &str, metadata: &mut LatexMetadata) -> Vec<LatexSection> {
+        let mut renderer = Renderer { math_count: 0 };
+        let render_inline = |renderer: &mut Renderer, text: &str| clean_text(&renderer.render(text));
+
+        // Préambule : tout ce qui précède `\begin{document}`
+        let (preamble, body) = match environment(source, "document") {
+            Some((body, range)) => (&source[..range.start], body),
+            None => ("", source.to_string()),
+        };
+
+        metadata.document_class = command_argument(preamble, "documentclass").map(|class| class.trim().to_string());
+        let chars: Vec<char> = preamble.chars().collect();
+        let mut i = 0;
+        while let Some(offset) = chars[i..].iter().position(|c| *c == '\\') {
+            let (name, end) = read_command_name(&chars, i + offset + 1);
+            if name == "usepackage" {
+                let after_optional = read_optional(&chars, end).map_or(end, |(_, next)| next);
+                if let Some((packages, _)) = read_group(&chars, after_optional) {
+                    metadata.packages.extend(packages.split(',').map(|p| p.trim().to_string()).filter(|p| !p.is_empty()));
+                }
+            }
+            i = end.max(i + offset + 1);
+        }
+
+        // `\title`, `\author` et `\date` peuvent figurer dans le préambule comme dans le corps
+        metadata.title = command_argument(source, "title").map(|title| render_inline(&mut renderer, &title));
+        metadata.authors = command_argument(source, "author")
+            .map(|authors| authors.split("\\and")
+                .map(|author| render_inline(&mut renderer, author))
+                .filter(|author| !author.is_empty())
+                .collect())
+            .unwrap_or_default();
+        metadata.date = command_argument(source, "date")
+            .map(|date| render_inline(&mut renderer, &date))
+            .filter(|date| !date.is_empty());
+
+        let mut body = body;
+        for command in ["title", "author", "date"] {
+            body = remove_command(&body, command);
+        }
+
+        // Le résumé forme sa propre section, avant le premier titre
+        let mut sections = Vec::new();
+        if let Some((abstract_source, range)) = environment(&body, "abstract") {
+            let text = tidy(&renderer.render(&abstract_source));
+            metadata.abstract_text = Some(clean_text(&text));
+            sections.push(LatexSection {
+                heading: Some("Abstract".to_string()),
+                heading_level: None,
+                section_path: vec!["Abstract".to_string()],
+                text,
+            });
+            body.replace_range(range, "");
+        }
+
+        // Découpage aux commandes de sectionnement
+        let chars: Vec<char> = body.chars().collect();
+        let mut headings: Vec<(i32, String)> = Vec::new();
+        let mut current = LatexSection::default();
+        let mut segment_start = 0;
+        let mut i = 0;
+
+        while i < chars.len() {
+            if chars[i] != '\\' {
+                i += 1;
+                continue;
+            }
+            let (name, end) = read_command_name(&chars, i + 1);
+            let Some(&(_, level)) = SECTIONING.iter().find(|(command, _)| *command == name.trim_end_matches('*')) else {
+                i = end.max(i + 1);
+                continue;
+            };
+            let after_optional = read_optional(&chars, end).map_or(end, |(_, next)| next);
+            let Some((title, next)) = read_group(&chars, after_optional) else {
+                i = end;
+                continue;
+            };
+
+            current.text = tidy(&renderer.render(&chars[segment_start..i].iter().collect::<String>()));
+            if !current.text.is_empty() || current.heading.is_some() {
+                sections.push(std::mem::take(&mut current));
+            }
+
+            let heading = render_inline(&mut renderer, &title);
+            while headings.last().is_some_and(|(last_level, _)| *last_level >= level) {
+                headings.pop();
+            }
+            headings.push((level, heading.clone()));
+            current = LatexSection {
+                heading: Some(heading),
+                heading_level: Some(level),
+                section_path: headings.iter().map(|(_, heading)| heading.clone()).collect(),
+                text: String::new(),
+            };
+            segment_start = next;
+            i = next;
+        }
+
+        current.text = tidy(&renderer.render(&chars[segment_start..].iter().collect::<String>()));
+        if !current.text.is_empty() || current.heading.is_some() {
+            sections.push(current);
+        }
+
+        metadata.math_count = renderer.math_count;
+        sections
+    }
+
+    /// Construit la sortie universelle : une section n'est jamais partagée entre deux chunks
+    fn build_output(
+        &self,
+        sections: &[LatexSection],
+        latex_meta: &LatexMetadata,
+        document_metadata: impl FnOnce(serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let mut chunks = Vec::new();
+        let mut full_text = String::new();
+
+        for section in sections {
+            let raw_text = match &section.heading {
+                Some(heading) if section.text.is_empty() => heading.clone(),
+                Some(heading) => format!("{}\n\n{}", heading, section.text),
+                None => section.text.clone(),
+            };
+
+            // Nettoyer le texte si demandé
+            let section_text = if params.text_cleaning {
+                clean_text(&raw_text)
+            } else {
+                raw_text
+            };
+            if section_text.is_empty() {
+                continue;
+            }
+
+            if !full_text.is_empty() {
+                full_text.push_str("\n\n");
+            }
+            let section_offset = full_text.len();
+            full_text.push_str(&section_text);
+
+            for chunk_text in chunk_text(&section_text, params.max_chunk_size, params.chunk_overlap) {
+                let text_meta = extract_text_metadata(&chunk_text);
+                let start_offset = section_offset + section_text.find(&chunk_text).unwrap_or(0);
+                let index = chunks.len();
+
+                chunks.push(DocumentChunk {
+                    id: format!("tex_chunk_{}", index),
+                    content: chunk_text.clone(),
+                    chunk_index: index,
+                    position: ChunkPosition {
+                        page: None,
+                        line: None,
+                        start_offset: Some(start_offset),
+                        end_offset: Some(start_offset + chunk_text.len()),
+                    },
+                    metadata: ChunkMetadata {
+                        size: chunk_text.len(),
+                        language: if params.language_detection { text_meta.detected_language } else { None },
+                        confidence: Some(0.9), // Conversion heuristique des macros
+                        format_specific: Some(json!({
+                            "word_count": text_meta.word_count,
+                            "estimated_tokens": text_meta.estimated_tokens,
+                            "heading": section.heading,
+                            "heading_level": section.heading_level,
+                            "section_path": section.section_path
+                        })),
+                    },
+                });
+            }
+        }
+
+        let text_meta = extract_text_metadata(&full_text);
+        let headings: Vec<&String> = sections.iter().filter_map(|section| section.heading.as_ref()).collect();
+        let format_metadata = json!({
+            "latex_metadata": {
+                "document_class": latex_meta.document_class,
+                "packages": latex_meta.packages,
+                "title": latex_meta.title,
+                "authors": latex_meta.authors,
+                "date": latex_meta.date,
+                "abstract": latex_meta.abstract_text,
+                "headings": headings,
+                "section_count": sections.len(),
+                "math_count": latex_meta.math_count,
+                "includes": latex_meta.includes,
+                "unresolved_includes": latex_meta.unresolved_includes
+            },
+            "total_words": text_meta.word_count,
+            "total_characters": text_meta.character_count,
+            "estimated_tokens": text_meta.estimated_tokens,
+            "detected_language": text_meta.detected_language
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "LatexProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: full_text.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+/// Retire toutes les occurrences de `\command[...]{...}`
+fn remove_command(source: &str, command: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] == '\\' {
+            let (name, end) = read_command_name(&chars, i + 1);
+            if name == command {
+                let after_optional = read_optional(&chars, end).map_or(end, |(_, next)| next);
+                if let Some((_, next)) = read_group(&chars, after_optional) {
+                    i = next;
+                    continue;
+                }
+            }
+            out.extend(&chars[i..end.max(i + 1)]);
+            i = end.max(i + 1);
+            continue;
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+    out
+}
+
+impl DocumentProcessor for LatexProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::TEX
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire le source avec ses inclusions, puis l'analyser
+        let mut latex_meta = LatexMetadata::default();
+        let source = self.read_latex_file(file_path, &mut latex_meta)?;
+        let sections = self.parse_latex(&source, &mut latex_meta);
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&sections, &latex_meta, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.tex")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::TEX,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: latex_meta.title.clone(),
+            author: if latex_meta.authors.is_empty() { None } else { Some(latex_meta.authors.join(", ")) },
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Sans répertoire de référence, les inclusions restent non résolues
+        let mut latex_meta = LatexMetadata::default();
+        let source = self.expand_includes(&String::from_utf8_lossy(content), None, &mut Vec::new(), 0, &mut latex_meta);
+        let sections = self.parse_latex(&source, &mut latex_meta);
+
+        Ok(self.build_output(&sections, &latex_meta, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::TEX,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: latex_meta.title.clone(),
+            author: if latex_meta.authors.is_empty() { None } else { Some(latex_meta.authors.join(", ")) },
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for LatexProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAPER: &str = r#"\documentclass[11pt]{article}
+\usepackage{amsmath, graphicx}
+\newcommand{\R}{\mathbb{R}}
+\title{On \emph{Fast} Loaders}
+\author{Ada Lovelace \thanks{Analytical Engine} \and Alan Turing}
+
+\begin{document}
+\maketitle
+\begin{abstract}
+We study loaders. % a comment
+\end{abstract}
+
+\section{Introduction}\label{sec:intro}
+Loading is \textbf{hard}, see~\cite{knuth84, lamport94} and 50\% of Section~\ref{sec:model}.
+
+\subsection[Short]{Model}
+Let $f: \R \to \R$ be smooth:
+\begin{equation}
+  f(x) = x^2
+\end{equation}
+\begin{itemize}
+  \item Caf\'e --- first
+\end{itemize}
+
+\section*{Conclusion}
+Done.
+\end{document}
+"#;
+
+    #[test]
+    fn test_latex_processor_creation() {
+        let processor = LatexProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "TEX");
+        assert_eq!(strip_comments("50\\% done % note"), "50\\% done ");
+    }
+
+    #[test]
+    fn test_parse_sections_and_metadata() {
+        let processor = LatexProcessor::new();
+        let mut metadata = LatexMetadata::default();
+        let sections = processor.parse_latex(&strip_comments(PAPER), &mut metadata);
+
+        assert_eq!(metadata.document_class.as_deref(), Some("article"));
+        assert_eq!(metadata.packages, vec!["amsmath", "graphicx"]);
+        assert_eq!(metadata.title.as_deref(), Some("On Fast Loaders"));
+        assert_eq!(metadata.authors, vec!["Ada Lovelace", "Alan Turing"]);
+        assert_eq!(metadata.abstract_text.as_deref(), Some("We study loaders."));
+
+        let paths: Vec<Vec<String>> = sections.iter().map(|section| section.section_path.clone()).collect();
+        assert_eq!(paths, vec![
+            vec!["Abstract".to_string()],
+            vec!["Introduction".to_string()],
+            vec!["Introduction".to_string(), "Model".to_string()],
+            vec!["Conclusion".to_string()],
+        ]);
+        assert_eq!(sections[2].heading_level, Some(2));
+    }
+
+    #[test]
+    fn test_render_keeps_math() {
+        let processor = LatexProcessor::new();
+        let mut metadata = LatexMetadata::default();
+        let sections = processor.parse_latex(&strip_comments(PAPER), &mut metadata);
+
+        assert_eq!(sections[1].text, "Loading is hard, see [knuth84, lamport94] and 50% of Section [sec:model].");
+        assert!(sections[2].text.starts_with("Let $f: \\R \\to \\R$ be smooth:\n\\begin{equation}"));
+        assert!(sections[2].text.ends_with("\\end{equation}\n- Café — first"));
+        assert_eq!(metadata.math_count, 2);
+    }
+
+    #[test]
+    fn test_includes_resolved_relative_to_file() {
+        let dir = std::env::temp_dir().join(format!("doc_loader_latex_{}", std::process::id()));
+        fs::create_dir_all(dir.join("chapters")).unwrap();
+        fs::write(dir.join("main.tex"), "\\begin{document}\n\\input{chapters/one}\n\\include{../outside}\n\\end{document}").unwrap();
+        fs::write(dir.join("chapters/one.tex"), "\\section{One}\nFirst chapter.").unwrap();
+
+        let processor = LatexProcessor::new();
+        let output = processor.process_file(&dir.join("main.tex"), &ProcessingParams::default()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(output.chunks.len(), 1);
+        assert_eq!(output.chunks[0].content, "One First chapter.");
+        let latex_meta = &output.document_metadata.format_metadata["latex_metadata"];
+        assert_eq!(latex_meta["includes"], json!(["chapters/one"]));
+        assert_eq!(latex_meta["unresolved_includes"], json!(["../outside"]));
+
+        // Un fichier inclus deux fois est développé deux fois ; seul un cycle est refusé
+        let dir = std::env::temp_dir().join(format!("doc_loader_latex_twice_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("main.tex"), "\\begin{document}\n\\section{A}\n\\input{sym}\n\\section{B}\n\\input{sym}\n\\input{loop}\n\\end{document}").unwrap();
+        fs::write(dir.join("sym.tex"), "Symbols.").unwrap();
+        fs::write(dir.join("loop.tex"), "Loop.\n\\input{loop}").unwrap();
+
+        let output = processor.process_file(&dir.join("main.tex"), &ProcessingParams::default()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let contents: Vec<&str> = output.chunks.iter().map(|chunk| chunk.content.as_str()).collect();
+        assert_eq!(contents, vec!["A Symbols.", "B Symbols. Loop."]);
+        let latex_meta = &output.document_metadata.format_metadata["latex_metadata"];
+        assert_eq!(latex_meta["includes"], json!(["sym", "sym", "loop"]));
+        assert_eq!(latex_meta["unresolved_includes"], json!(["loop"]));
+    }
+}
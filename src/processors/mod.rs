@@ -1,12 +1,34 @@
 use crate::core::{UniversalOutput, DocumentType, ProcessingParams};
 use crate::error::DocLoaderError;
+use std::io::Read;
 use std::path::Path;
+use std::sync::LazyLock;
 
 pub mod pdf;
 pub mod txt;
 pub mod json;
 pub mod csv;
 pub mod docx;
+pub mod pptx;
+pub mod odf;
+pub mod epub;
+pub mod rtf;
+pub mod email;
+pub mod xml;
+pub mod config;
+pub mod code;
+pub mod notebook;
+pub mod subtitle;
+pub mod latex;
+pub mod archive;
+pub mod compression;
+pub mod detect;
+pub mod registry;
+pub mod profile;
+
+pub(crate) mod ooxml;
+pub(crate) mod records;
+pub(crate) mod selector;
 
 /// Trait pour tous les processeurs de documents
 pub trait DocumentProcessor {
@@ -19,65 +41,197 @@ pub trait DocumentProcessor {
     /// Traite du contenu brut et retourne le résultat universel
     fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError>;
     
+    /// Traite un fichier en confiant les documents qu'il contient (pièces jointes) aux processeurs
+    /// de `registry` ; seuls les formats conteneurs ont besoin de la redéfinir
+    fn process_file_with_registry(&self, file_path: &Path, params: &ProcessingParams, registry: &registry::ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let _ = registry;
+        self.process_file(file_path, params)
+    }
+    
+    /// Équivalent de `process_file_with_registry` pour du contenu brut
+    fn process_content_with_registry(&self, content: &[u8], filename: &str, params: &ProcessingParams, registry: &registry::ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let _ = registry;
+        self.process_content(content, filename, params)
+    }
+    
+    /// Traite un flux décompressé à la volée, lu une seule fois, dans la limite de
+    /// `max_decompressed_size`. Par défaut le flux est lu en mémoire ; les formats lisibles en
+    /// flux (CSV, JSON Lines) la redéfinissent pour l'analyser sans copie intermédiaire
+    fn process_decompressed(&self, reader: &mut dyn Read, filename: &str, params: &ProcessingParams, registry: &registry::ProcessorRegistry) -> Result<UniversalOutput, DocLoaderError> {
+        let content = compression::read_decompressed(reader, filename, params)?;
+        self.process_content_with_registry(&content, filename, params, registry)
+    }
+    
     /// Retourne la version du processeur
     fn version(&self) -> &'static str {
         "1.0.0"
     }
 }
 
-/// Processeur universel qui délègue aux processeurs spécialisés
+/// Processeur universel qui délègue aux processeurs spécialisés du registre
 pub struct UniversalProcessor {
-    pdf_processor: pdf::PdfProcessor,
-    txt_processor: txt::TxtProcessor,
-    json_processor: json::JsonProcessor,
-    csv_processor: csv::CsvProcessor,
-    docx_processor: docx::DocxProcessor,
+    registry: registry::ProcessorRegistry,
 }
 
 impl UniversalProcessor {
     pub fn new() -> Self {
         Self {
-            pdf_processor: pdf::PdfProcessor::new(),
-            txt_processor: txt::TxtProcessor::new(),
-            json_processor: json::JsonProcessor::new(),
-            csv_processor: csv::CsvProcessor::new(),
-            docx_processor: docx::DocxProcessor::new(),
+            registry: registry::ProcessorRegistry::with_builtins(),
         }
     }
     
+    /// Processeur universel construit sur un registre personnalisé
+    pub fn with_registry(registry: registry::ProcessorRegistry) -> Self {
+        Self { registry }
+    }
+    
+    /// Enregistre un processeur (format personnalisé ou remplacement d'un processeur intégré).
+    ///
+    /// ```rust
+    /// # use doc_loader::UniversalProcessor;
+    /// # use doc_loader::processors::txt::TxtProcessor;
+    /// let mut processor = UniversalProcessor::new();
+    /// processor.register(TxtProcessor::new()).extensions(&["log"]);
+    /// ```
+    pub fn register<P>(&mut self, processor: P) -> registry::Registration<'_>
+    where
+        P: DocumentProcessor + Send + Sync + 'static,
+    {
+        self.registry.register(processor)
+    }
+    
+    pub fn registry(&self) -> &registry::ProcessorRegistry {
+        &self.registry
+    }
+    
     pub fn process_file(&self, file_path: &Path, params: Option<ProcessingParams>) -> Result<UniversalOutput, DocLoaderError> {
         let params = params.unwrap_or_default();
         
-        let extension = file_path
+        if archive::ArchiveKind::from_name(&file_path.to_string_lossy()).is_some() {
+            return Err(DocLoaderError::UnsupportedFormat(
+                format!("{} is an archive, use process_archive instead", file_path.display())
+            ));
+        }
+        
+        // Un suffixe de compression sans signature (`notes.txt.gz` en clair) laisse l'extension interne servir d'indice
+        let name = file_path.to_string_lossy();
+        let uncompressed_name = compression::Compression::from_name(&name).map(|(_, inner)| inner);
+        let extension = Path::new(uncompressed_name.as_deref().unwrap_or(&name)).extension().and_then(|ext| ext.to_str());
+        let hint = extension.and_then(|ext| self.registry.type_for_extension(ext));
+        
+        // Le contenu fait foi, l'extension n'est qu'un indice
+        let doc_type = if file_path.is_file() {
+            let head = detect::read_head(file_path)?;
+            
+            // Entrées compressées (`data.csv.gz`) : format détecté après décompression
+            if let Some((kind, inner_name)) = compression::detect_compression(&name, &head) {
+                return compression::process_compressed_file(self, file_path, kind, &inner_name, &params);
+            }
+            if uncompressed_name.is_some() {
+                log::warn!("{} has a compression suffix but no matching signature, processing it uncompressed", name);
+            }
+            self.registry.sniff(&head).or_else(|| detect::detect_file_type(file_path, &head, hint))
+        } else {
+            hint
+        };
+        
+        let doc_type = doc_type.ok_or_else(|| match extension {
+            Some(extension) => DocLoaderError::UnsupportedFormat(format!("Unsupported extension: {}", extension)),
+            None => DocLoaderError::UnsupportedFormat(format!("Could not detect the format of {}", file_path.display())),
+        })?;
+        
+        self.registry.processor(&doc_type)?.process_file_with_registry(file_path, &params, &self.registry)
+    }
+    
+    /// Processeur enregistré pour un type de document
+    pub fn processor(&self, doc_type: &DocumentType) -> Result<&dyn DocumentProcessor, DocLoaderError> {
+        self.registry.processor(doc_type)
+    }
+    
+    /// Processeur enregistré pour un type de document.
+    ///
+    /// # Panics
+    ///
+    /// Si aucun processeur n'est enregistré pour `doc_type` (format personnalisé inconnu,
+    /// registre construit sans les processeurs intégrés).
+    #[deprecated(note = "use `processor`, which returns an error for unregistered types")]
+    pub fn get_processor(&self, doc_type: &DocumentType) -> Box<&dyn DocumentProcessor> {
+        Box::new(self.processor(doc_type).unwrap_or_else(|e| panic!("{}", e)))
+    }
+    
+    /// Traite du contenu brut dont le format est détecté par signature ; l'extension de `filename` sert d'indice
+    pub fn process_content(&self, content: &[u8], filename: &str, params: Option<ProcessingParams>) -> Result<UniversalOutput, DocLoaderError> {
+        self.dispatch_content(content, filename, None, params.unwrap_or_default())
+    }
+    
+    /// Comme `process_content`, avec le type MIME déclaré par la source (en-tête HTTP, pièce jointe) :
+    /// il l'emporte sur l'extension de `filename`, mais pas sur une signature reconnue.
+    ///
+    /// ```rust
+    /// # use doc_loader::UniversalProcessor;
+    /// let output = UniversalProcessor::new()
+    ///     .process_content_with_mime(b"id,name\n1,Ada\n", "upload", "text/csv; charset=utf-8", None)
+    ///     .unwrap();
+    /// assert_eq!(output.document_metadata.document_type.to_string(), "CSV");
+    /// ```
+    pub fn process_content_with_mime(&self, content: &[u8], filename: &str, mime_type: &str, params: Option<ProcessingParams>) -> Result<UniversalOutput, DocLoaderError> {
+        self.dispatch_content(content, filename, self.registry.type_for_mime_type(mime_type), params.unwrap_or_default())
+    }
+    
+    fn dispatch_content(&self, content: &[u8], filename: &str, declared: Option<DocumentType>, params: ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let hint = declared.or_else(|| Path::new(filename)
             .extension()
             .and_then(|ext| ext.to_str())
-            .ok_or_else(|| DocLoaderError::UnsupportedFormat("No file extension".to_string()))?;
+            .and_then(|ext| self.registry.type_for_extension(ext)));
+        let head = &content[..content.len().min(detect::HEAD_SIZE)];
+        let doc_type = self.registry.sniff(head)
+            .or_else(|| detect::detect_type_with_hint(content, hint))
+            .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!("Could not detect the format of {}", filename)))?;
         
-        let doc_type = DocumentType::from_extension(extension)
-            .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!("Unsupported extension: {}", extension)))?;
+        self.registry.processor(&doc_type)?.process_content_with_registry(content, filename, &params, &self.registry)
+    }
+    
+    /// Traite une archive ZIP ou TAR (`.tar`, `.tar.gz`, `.tgz`) et chacun de ses membres supportés.
+    ///
+    /// Le `filepath` de chaque résultat désigne le membre dans l'archive : `archive.zip!/inner/path.pdf`.
+    pub fn process_archive(&self, file_path: &Path, params: Option<ProcessingParams>) -> Result<Vec<UniversalOutput>, DocLoaderError> {
+        let params = params.unwrap_or_default();
         
-        match doc_type {
-            DocumentType::PDF => self.pdf_processor.process_file(file_path, &params),
-            DocumentType::TXT => self.txt_processor.process_file(file_path, &params),
-            DocumentType::JSON => self.json_processor.process_file(file_path, &params),
-            DocumentType::CSV => self.csv_processor.process_file(file_path, &params),
-            DocumentType::DOCX => self.docx_processor.process_file(file_path, &params),
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
         }
+        
+        let label = file_path.to_string_lossy().to_string();
+        let kind = archive::ArchiveKind::from_name(&label)
+            .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!("Not an archive: {}", label)))?;
+        
+        let content = std::fs::read(file_path)?;
+        archive::process_archive(self, &content, &label, kind, &params)
     }
     
-    pub fn get_processor(&self, doc_type: &DocumentType) -> Box<&dyn DocumentProcessor> {
-        match doc_type {
-            DocumentType::PDF => Box::new(&self.pdf_processor),
-            DocumentType::TXT => Box::new(&self.txt_processor),
-            DocumentType::JSON => Box::new(&self.json_processor),
-            DocumentType::CSV => Box::new(&self.csv_processor),
-            DocumentType::DOCX => Box::new(&self.docx_processor),
-        }
+    /// Traite une archive en mémoire ; les chemins sont préfixés par `memory://{filename}!/`
+    pub fn process_archive_content(&self, content: &[u8], filename: &str, params: Option<ProcessingParams>) -> Result<Vec<UniversalOutput>, DocLoaderError> {
+        let params = params.unwrap_or_default();
+        
+        let kind = archive::ArchiveKind::from_name(filename)
+            .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!("Not an archive: {}", filename)))?;
+        
+        archive::process_archive(self, content, &format!("memory://{}", filename), kind, &params)
     }
     
-    /// Get list of supported file extensions
+    /// Get list of built-in file extensions, sorted (see `registry().extensions()` for registered ones)
     pub fn supported_extensions() -> &'static [&'static str] {
-        &["pdf", "txt", "json", "csv", "docx"]
+        // Dérivée du registre intégré, calculée une seule fois pour conserver la signature `'static`
+        static EXTENSIONS: LazyLock<Vec<&'static str>> = LazyLock::new(|| {
+            registry::ProcessorRegistry::with_builtins()
+                .extensions()
+                .into_iter()
+                .map(|extension| &*Box::leak(extension.into_boxed_str()))
+                .collect()
+        });
+        &EXTENSIONS
     }
 }
 
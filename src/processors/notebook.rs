@@ -0,0 +1,448 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata, normalize_line_breaks};
+
+use std::path::Path;
+use std::fs;
+use chrono::Utc;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// Options du processeur Jupyter, lues depuis `params.format_specific["notebook"]`
+///
+/// ```json
+/// { "notebook": { "include_outputs": true, "max_output_chars": 2000 } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NotebookOptions {
+    /// Inclure les sorties textuelles des cellules de code (flux, résultats, erreurs)
+    pub include_outputs: bool,
+    /// Longueur maximale conservée pour la sortie d'une cellule
+    pub max_output_chars: usize,
+}
+
+impl Default for NotebookOptions {
+    fn default() -> Self {
+        Self {
+            include_outputs: false,
+            max_output_chars: 2000,
+        }
+    }
+}
+
+impl NotebookOptions {
+    pub fn from_params(params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        params.format_options("notebook")
+    }
+}
+
+/// Cellule de notebook (markdown, code ou brute)
+#[derive(Debug, Clone)]
+pub struct NotebookCell {
+    pub index: usize,
+    pub cell_type: String,
+    pub source: String,
+    pub execution_count: Option<u64>,
+    /// Sorties textuelles (vide si non demandées ou absentes)
+    pub outputs: Vec<String>,
+}
+
+/// Notebook analysé : cellules dans l'ordre et métadonnées du noyau
+#[derive(Debug, Clone, Default)]
+pub struct Notebook {
+    pub nbformat: Option<u64>,
+    pub nbformat_minor: Option<u64>,
+    pub kernel_name: Option<String>,
+    pub kernel_display_name: Option<String>,
+    pub language: Option<String>,
+    pub language_version: Option<String>,
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    pub cells: Vec<NotebookCell>,
+}
+
+/// Texte d'un champ multiligne : chaîne simple ou liste de lignes
+fn multiline_text(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Array(lines)) => lines.iter().filter_map(|line| line.as_str()).collect(),
+        _ => String::new(),
+    }
+}
+
+pub struct NotebookProcessor;
+
+impl NotebookProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lit et analyse un fichier .ipynb
+    fn read_notebook_file(&self, file_path: &Path, options: &NotebookOptions) -> Result<Notebook, DocLoaderError> {
+        let content = fs::read(file_path)?;
+        self.parse_notebook(&content, options)
+    }
+
+    /// Analyse le JSON d'un notebook (nbformat 4, ainsi que les feuilles de calcul nbformat 3)
+    fn parse_notebook(&self, content: &[u8], options: &NotebookOptions) -> Result<Notebook, DocLoaderError> {
+        let value: Value = serde_json::from_slice(content)?;
+        let metadata = &value["metadata"];
+
+        // nbformat 4 : `cells` ; nbformat 3 : `worksheets[].cells`
+        let raw_cells: Vec<&Value> = match (value.get("cells"), value.get("worksheets")) {
+            (Some(Value::Array(cells)), _) => cells.iter().collect(),
+            (_, Some(Value::Array(worksheets))) => worksheets.iter()
+                .filter_map(|sheet| sheet["cells"].as_array())
+                .flatten()
+                .collect(),
+            _ => return Err(DocLoaderError::InvalidFormat("Notebook has no cells".to_string())),
+        };
+
+        let cells = raw_cells.into_iter().enumerate().map(|(index, cell)| {
+            let cell_type = cell["cell_type"].as_str().unwrap_or("raw").to_string();
+            // nbformat 3 stocke le code dans `input`
+            let source = normalize_line_breaks(&multiline_text(cell.get("source").or_else(|| cell.get("input"))));
+            let outputs = if options.include_outputs {
+                cell["outputs"].as_array()
+                    .map(|outputs| outputs.iter()
+                        .filter_map(|output| self.output_text(output, options))
+                        .collect())
+                    .unwrap_or_default()
+            } else {
+                Vec::new()
+            };
+
+            NotebookCell {
+                index,
+                cell_type,
+                source,
+                execution_count: cell["execution_count"].as_u64().or_else(|| cell["prompt_number"].as_u64()),
+                outputs,
+            }
+        }).collect::<Vec<_>>();
+
+        let kernelspec = &metadata["kernelspec"];
+        let language_info = &metadata["language_info"];
+        let as_string = |value: &Value| value.as_str().map(str::to_string);
+
+        // Titre : métadonnées, sinon premier titre de niveau 1 d'une cellule markdown
+        let title = as_string(&metadata["title"]).or_else(|| cells.iter()
+            .filter(|cell| cell.cell_type == "markdown")
+            .flat_map(|cell| cell.source.lines())
+            .find_map(|line| line.strip_prefix("# ").map(|title| title.trim().to_string())));
+
+        Ok(Notebook {
+            nbformat: value["nbformat"].as_u64(),
+            nbformat_minor: value["nbformat_minor"].as_u64(),
+            kernel_name: as_string(&kernelspec["name"]),
+            kernel_display_name: as_string(&kernelspec["display_name"]),
+            language: as_string(&kernelspec["language"])
+                .or_else(|| as_string(&language_info["name"]))
+                .or_else(|| as_string(&metadata["language"])),
+            language_version: as_string(&language_info["version"]),
+            title,
+            authors: metadata["authors"].as_array()
+                .map(|authors| authors.iter()
+                    .filter_map(|author| author["name"].as_str().or_else(|| author.as_str()))
+                    .map(str::to_string)
+                    .collect())
+                .unwrap_or_default(),
+            cells,
+        })
+    }
+
+    /// Texte d'une sortie de cellule ; les sorties binaires (images, HTML seul) sont ignorées
+    fn output_text(&self, output: &Value, options: &NotebookOptions) -> Option<String> {
+        let text = match output["output_type"].as_str()? {
+            "stream" => multiline_text(output.get("text")),
+            "execute_result" | "display_data" | "pyout" => multiline_text(
+                output["data"].get("text/plain").or_else(|| output.get("text"))
+            ),
+            "error" | "pyerr" => format!(
+                "{}: {}",
+                output["ename"].as_str().unwrap_or("Error"),
+                output["evalue"].as_str().unwrap_or("")
+            ),
+            _ => return None,
+        };
+
+        let text = normalize_line_breaks(text.trim_end());
+        if text.trim().is_empty() {
+            return None;
+        }
+        match text.char_indices().nth(options.max_output_chars) {
+            Some((cut, _)) => Some(format!("{}…", &text[..cut])),
+            None => Some(text),
+        }
+    }
+
+    /// Construit la sortie universelle : les cellules sont découpées dans l'ordre, sans jamais en chevaucher deux
+    fn build_output(
+        &self,
+        notebook: &Notebook,
+        options: &NotebookOptions,
+        document_metadata: impl FnOnce(serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let mut chunks = Vec::new();
+        let mut full_text = String::new();
+
+        for cell in &notebook.cells {
+            let mut sections = vec![("source", cell.source.clone())];
+            if !cell.outputs.is_empty() {
+                sections.push(("output", cell.outputs.join("\n")));
+            }
+
+            for (part, raw_text) in sections {
+                // Le code garde son indentation ; seuls le markdown et les sorties sont nettoyés
+                let section_text = if params.text_cleaning && !(cell.cell_type == "code" && part == "source") {
+                    clean_text(&raw_text)
+                } else {
+                    raw_text.trim_end().to_string()
+                };
+                if section_text.trim().is_empty() {
+                    continue;
+                }
+
+                if !full_text.is_empty() {
+                    full_text.push_str("\n\n");
+                }
+                let section_offset = full_text.len();
+                full_text.push_str(&section_text);
+
+                for chunk_text in chunk_text(&section_text, params.max_chunk_size, params.chunk_overlap) {
+                    let text_meta = extract_text_metadata(&chunk_text);
+                    let start_offset = section_offset + section_text.find(&chunk_text).unwrap_or(0);
+                    let index = chunks.len();
+                    let is_prose = cell.cell_type == "markdown";
+
+                    let mut format_specific = json!({
+                        "cell_index": cell.index,
+                        "cell_type": cell.cell_type,
+                        "part": part,
+                        "word_count": text_meta.word_count,
+                        "estimated_tokens": text_meta.estimated_tokens
+                    });
+                    if cell.cell_type == "code" {
+                        format_specific["execution_count"] = json!(cell.execution_count);
+                        format_specific["language"] = json!(notebook.language);
+                    }
+
+                    chunks.push(DocumentChunk {
+                        id: format!("ipynb_chunk_{}", index),
+                        content: chunk_text.clone(),
+                        chunk_index: index,
+                        position: ChunkPosition {
+                            page: None,
+                            line: None,
+                            start_offset: Some(start_offset),
+                            end_offset: Some(start_offset + chunk_text.len()),
+                        },
+                        metadata: ChunkMetadata {
+                            size: chunk_text.len(),
+                            language: if params.language_detection && is_prose { text_meta.detected_language } else { None },
+                            confidence: Some(1.0), // Cellules lues directement depuis le JSON
+                            format_specific: Some(format_specific),
+                        },
+                    });
+                }
+            }
+        }
+
+        let count_cells = |cell_type: &str| notebook.cells.iter().filter(|cell| cell.cell_type == cell_type).count();
+        let text_meta = extract_text_metadata(&full_text);
+        let format_metadata = json!({
+            "notebook_metadata": {
+                "nbformat": notebook.nbformat,
+                "nbformat_minor": notebook.nbformat_minor,
+                "kernel_name": notebook.kernel_name,
+                "kernel_display_name": notebook.kernel_display_name,
+                "language": notebook.language,
+                "language_version": notebook.language_version,
+                "authors": notebook.authors,
+                "cell_count": notebook.cells.len(),
+                "markdown_cells": count_cells("markdown"),
+                "code_cells": count_cells("code"),
+                "raw_cells": count_cells("raw"),
+                "outputs_included": options.include_outputs
+            },
+            "total_words": text_meta.word_count,
+            "total_characters": text_meta.character_count,
+            "estimated_tokens": text_meta.estimated_tokens,
+            "detected_language": text_meta.detected_language
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "NotebookProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: full_text.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+impl DocumentProcessor for NotebookProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::IPYNB
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et analyser le notebook
+        let options = NotebookOptions::from_params(params)?;
+        let notebook = self.read_notebook_file(file_path, &options)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&notebook, &options, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.ipynb")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::IPYNB,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: notebook.title.clone(),
+            author: if notebook.authors.is_empty() { None } else { Some(notebook.authors.join(", ")) },
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Analyser le notebook depuis les bytes
+        let options = NotebookOptions::from_params(params)?;
+        let notebook = self.parse_notebook(content, &options)?;
+
+        Ok(self.build_output(&notebook, &options, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::IPYNB,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: notebook.title.clone(),
+            author: if notebook.authors.is_empty() { None } else { Some(notebook.authors.join(", ")) },
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for NotebookProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTEBOOK: &str = r##"{
+  "cells": [
+    {"cell_type": "markdown", "metadata": {}, "source": ["# Sales analysis\n", "\n", "Quarterly **revenue**."]},
+    {"cell_type": "code", "execution_count": 1, "metadata": {}, "source": ["import pandas as pd\n", "def total(df):\n", "    return df.sum()"],
+     "outputs": [
+       {"output_type": "stream", "name": "stdout", "text": ["loaded\n"]},
+       {"output_type": "execute_result", "execution_count": 1, "metadata": {}, "data": {"text/plain": ["42"], "image/png": "iVBORw0KGgo="}}
+     ]},
+    {"cell_type": "code", "execution_count": 2, "metadata": {}, "source": "1 / 0",
+     "outputs": [{"output_type": "error", "ename": "ZeroDivisionError", "evalue": "division by zero", "traceback": []}]}
+  ],
+  "metadata": {
+    "kernelspec": {"name": "python3", "display_name": "Python 3", "language": "python"},
+    "language_info": {"name": "python", "version": "3.11.4"}
+  },
+  "nbformat": 4,
+  "nbformat_minor": 5
+}"##;
+
+    #[test]
+    fn test_notebook_processor_creation() {
+        let processor = NotebookProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "IPYNB");
+    }
+
+    #[test]
+    fn test_parse_notebook() {
+        let processor = NotebookProcessor::new();
+        let notebook = processor.parse_notebook(NOTEBOOK.as_bytes(), &NotebookOptions::default()).unwrap();
+
+        assert_eq!(notebook.cells.len(), 3);
+        assert_eq!(notebook.cells[1].source, "import pandas as pd\ndef total(df):\n    return df.sum()");
+        assert_eq!(notebook.cells[1].execution_count, Some(1));
+        assert!(notebook.cells[1].outputs.is_empty());
+        assert_eq!(notebook.kernel_name.as_deref(), Some("python3"));
+        assert_eq!(notebook.language.as_deref(), Some("python"));
+        assert_eq!(notebook.title.as_deref(), Some("Sales analysis"));
+    }
+
+    #[test]
+    fn test_cells_in_order() {
+        let processor = NotebookProcessor::new();
+        let output = processor.process_content(NOTEBOOK.as_bytes(), "sales.ipynb", &ProcessingParams::default()).unwrap();
+
+        let cells: Vec<(u64, &str)> = output.chunks.iter()
+            .map(|chunk| {
+                let meta = chunk.metadata.format_specific.as_ref().unwrap();
+                (meta["cell_index"].as_u64().unwrap(), meta["cell_type"].as_str().unwrap())
+            })
+            .collect();
+        assert_eq!(cells, vec![(0, "markdown"), (1, "code"), (2, "code")]);
+        assert!(output.chunks[1].content.contains("\n    return df.sum()"));
+        assert_eq!(output.chunks[1].metadata.format_specific.as_ref().unwrap()["language"], "python");
+
+        let notebook_meta = &output.document_metadata.format_metadata["notebook_metadata"];
+        assert_eq!(notebook_meta["kernel_display_name"], "Python 3");
+        assert_eq!(notebook_meta["language_version"], "3.11.4");
+        assert_eq!(output.document_metadata.title.as_deref(), Some("Sales analysis"));
+    }
+
+    #[test]
+    fn test_include_outputs() {
+        let processor = NotebookProcessor::new();
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "notebook": { "include_outputs": true } }));
+        let output = processor.process_content(NOTEBOOK.as_bytes(), "sales.ipynb", &params).unwrap();
+
+        let outputs: Vec<&str> = output.chunks.iter()
+            .filter(|chunk| chunk.metadata.format_specific.as_ref().unwrap()["part"] == "output")
+            .map(|chunk| chunk.content.as_str())
+            .collect();
+        assert_eq!(outputs, vec!["loaded 42", "ZeroDivisionError: division by zero"]);
+
+        let params = ProcessingParams::default()
+            .with_format_specific(json!({ "notebook": { "include_outputs": "yes" } }));
+        let result = processor.process_content(NOTEBOOK.as_bytes(), "sales.ipynb", &params);
+        assert!(matches!(result, Err(DocLoaderError::InvalidFormat(_))));
+    }
+}
@@ -0,0 +1,826 @@
+//! Processeurs OpenDocument (ODT, ODS, ODP).
+//!
+//! Les trois formats partagent le même conteneur ZIP : le contenu est lu dans `content.xml`
+//! et les propriétés du document dans `meta.xml`.
+
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::processors::ooxml;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata};
+
+use std::path::Path;
+use std::fs;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde_json::{json, Map, Value};
+
+/// Nombre maximal de répétitions appliqué aux cellules/lignes répétées d'un tableur
+const MAX_REPEAT: usize = 1000;
+
+/// Nombre maximal de cellules d'un tableau, répétitions comprises : les plafonds par ligne et
+/// par colonne se multiplient, seul ce total borne la mémoire
+const MAX_TABLE_CELLS: usize = 1_000_000;
+
+/// Variante de document OpenDocument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OdfKind {
+    Text,
+    Spreadsheet,
+    Presentation,
+}
+
+impl OdfKind {
+    fn document_type(&self) -> DocumentType {
+        match self {
+            OdfKind::Text => DocumentType::ODT,
+            OdfKind::Spreadsheet => DocumentType::ODS,
+            OdfKind::Presentation => DocumentType::ODP,
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            OdfKind::Text => "odt",
+            OdfKind::Spreadsheet => "ods",
+            OdfKind::Presentation => "odp",
+        }
+    }
+
+    fn processor_name(&self) -> &'static str {
+        match self {
+            OdfKind::Text => "OdtProcessor",
+            OdfKind::Spreadsheet => "OdsProcessor",
+            OdfKind::Presentation => "OdpProcessor",
+        }
+    }
+
+    fn mimetype(&self) -> &'static str {
+        match self {
+            OdfKind::Text => "application/vnd.oasis.opendocument.text",
+            OdfKind::Spreadsheet => "application/vnd.oasis.opendocument.spreadsheet",
+            OdfKind::Presentation => "application/vnd.oasis.opendocument.presentation",
+        }
+    }
+}
+
+/// Section logique d'un document ODF : partie sous un titre (ODT), feuille (ODS) ou diapositive (ODP)
+#[derive(Debug, Clone, Default)]
+pub struct OdfSection {
+    /// Numéro de feuille ou de diapositive (à partir de 1)
+    pub number: Option<u32>,
+    /// Titre de la section, de la feuille ou de la diapositive
+    pub title: Option<String>,
+    /// Niveau du titre (ODT uniquement)
+    pub heading_level: Option<u32>,
+    /// Chemin des titres englobants (ODT uniquement)
+    pub section_path: Vec<String>,
+    /// Paragraphes et lignes de tableaux dans l'ordre du document
+    pub blocks: Vec<String>,
+    pub table_count: usize,
+    /// Notes de l'orateur (ODP uniquement)
+    pub notes: Vec<String>,
+}
+
+impl OdfSection {
+    fn is_empty(&self) -> bool {
+        self.title.is_none() && self.blocks.is_empty() && self.notes.is_empty()
+    }
+
+    /// Rend la section en texte
+    pub fn to_text(&self, kind: OdfKind) -> String {
+        let mut text = match (kind, self.number, &self.title) {
+            (OdfKind::Presentation, Some(number), Some(title)) => format!("Slide {}: {}\n", number, title),
+            (OdfKind::Presentation, Some(number), None) => format!("Slide {}\n", number),
+            (OdfKind::Spreadsheet, Some(number), Some(title)) => format!("Sheet {}: {}\n", number, title),
+            (_, _, Some(title)) => format!("{}\n", title),
+            _ => String::new(),
+        };
+
+        for block in &self.blocks {
+            text.push_str(block);
+            text.push('\n');
+        }
+
+        if !self.notes.is_empty() {
+            text.push_str("\nNotes: ");
+            text.push_str(&self.notes.join("\n"));
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+/// Propriétés du document issues de `meta.xml`
+#[derive(Debug, Clone, Default)]
+pub struct OdfMetadata {
+    pub title: Option<String>,
+    pub subject: Option<String>,
+    pub description: Option<String>,
+    pub initial_creator: Option<String>,
+    pub creator: Option<String>,
+    pub creation_date: Option<String>,
+    pub date: Option<String>,
+    pub keywords: Vec<String>,
+    pub generator: Option<String>,
+    /// Attributs de `meta:document-statistic` (page-count, word-count, ...)
+    pub statistics: Map<String, Value>,
+}
+
+impl OdfMetadata {
+    fn parse(xml: &str) -> Result<Self, DocLoaderError> {
+        let mut meta = OdfMetadata::default();
+
+        for (name, value) in ooxml::leaf_elements(xml)? {
+            match name.as_str() {
+                "title" => meta.title = Some(value),
+                "subject" => meta.subject = Some(value),
+                "description" => meta.description = Some(value),
+                "initial-creator" => meta.initial_creator = Some(value),
+                "creator" => meta.creator = Some(value),
+                "creation-date" => meta.creation_date = Some(value),
+                "date" => meta.date = Some(value),
+                "keyword" => meta.keywords.push(value),
+                "generator" => meta.generator = Some(value),
+                _ => {},
+            }
+        }
+
+        // Les statistiques sont portées par les attributs d'un élément vide
+        let mut reader = Reader::from_str(xml);
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"document-statistic" => {
+                    for attr in e.attributes().flatten() {
+                        let key = String::from_utf8_lossy(attr.key.local_name().as_ref()).into_owned();
+                        let value = attr.unescape_value()?.into_owned();
+                        let value = value.parse::<u64>().map(Value::from).unwrap_or(Value::String(value));
+                        meta.statistics.insert(key, value);
+                    }
+                },
+                Event::Eof => break,
+                _ => {},
+            }
+        }
+
+        Ok(meta)
+    }
+
+    /// Auteur du document : créateur initial, sinon dernier auteur
+    fn author(&self) -> Option<String> {
+        self.initial_creator.clone().or_else(|| self.creator.clone())
+    }
+
+    /// Les dates ODF sont en ISO 8601, souvent sans fuseau horaire
+    fn parse_date(value: &Option<String>) -> Option<DateTime<Utc>> {
+        let value = value.as_deref()?;
+        DateTime::parse_from_rfc3339(value)
+            .map(|date| date.with_timezone(&Utc))
+            .ok()
+            .or_else(|| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f").ok().map(|date| date.and_utc()))
+    }
+}
+
+/// Tableau en cours de lecture
+#[derive(Default)]
+struct TableState {
+    name: Option<String>,
+    rows: Vec<Vec<String>>,
+    row: Option<(Vec<String>, usize)>,
+    cell: Option<(Vec<String>, usize)>,
+    /// Cellules des lignes déjà terminées
+    cells: usize,
+    truncated: bool,
+}
+
+impl TableState {
+    /// Ajoute `repeat` fois une cellule à la ligne courante, dans la limite de `MAX_TABLE_CELLS`
+    fn push_cell(&mut self, content: String, repeat: usize) {
+        if let Some((row, _)) = self.row.as_mut() {
+            let available = MAX_TABLE_CELLS.saturating_sub(self.cells + row.len());
+            self.truncated |= repeat > available;
+            row.extend(std::iter::repeat_n(content, repeat.min(available)));
+        }
+    }
+
+    /// Termine la ligne courante ; les cellules vides finales sont ignorées
+    fn finish_row(&mut self) {
+        if let Some((mut row, repeat)) = self.row.take() {
+            while row.last().is_some_and(|cell| cell.is_empty()) {
+                row.pop();
+            }
+            if !row.is_empty() {
+                let available = MAX_TABLE_CELLS.saturating_sub(self.cells) / row.len();
+                self.truncated |= repeat > available;
+                let repeat = repeat.min(available);
+                self.cells += row.len() * repeat;
+                self.rows.extend(std::iter::repeat_n(row, repeat));
+            }
+        }
+    }
+}
+
+/// Parcourt `content.xml` et découpe le document en sections
+fn parse_content_xml(xml: &str, kind: OdfKind) -> Result<Vec<OdfSection>, DocLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut sections = Vec::new();
+    let mut section = OdfSection::default();
+    let mut headings: Vec<(u32, String)> = Vec::new();
+
+    let mut paragraphs: Vec<(String, Option<u32>)> = Vec::new();
+    let mut tables: Vec<TableState> = Vec::new();
+    let mut frame_class: Option<String> = None;
+    let mut in_notes = false;
+    let mut skip_depth = 0usize;
+    let mut slide_count = 0u32;
+
+    fn repeat_attribute(element: &BytesStart<'_>, name: &[u8]) -> usize {
+        ooxml::attribute(element, name)
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(1)
+            .clamp(1, MAX_REPEAT)
+    }
+
+    loop {
+        let event = reader.read_event()?;
+
+        // Ignorer le contenu des modifications suivies et des annotations
+        if skip_depth > 0 {
+            match event {
+                Event::Start(_) => skip_depth += 1,
+                Event::End(_) => skip_depth -= 1,
+                Event::Eof => break,
+                _ => {},
+            }
+            continue;
+        }
+
+        match event {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"tracked-changes" | b"annotation" => skip_depth = 1,
+                b"p" => paragraphs.push((String::new(), None)),
+                b"h" => {
+                    let level = ooxml::attribute(&e, b"outline-level")
+                        .and_then(|level| level.parse().ok())
+                        .unwrap_or(1);
+                    paragraphs.push((String::new(), Some(level)));
+                },
+                b"span" | b"a" => {},
+                b"table" => tables.push(TableState {
+                    name: ooxml::attribute(&e, b"name"),
+                    ..TableState::default()
+                }),
+                b"table-row" => {
+                    if let Some(table) = tables.last_mut() {
+                        table.row = Some((Vec::new(), repeat_attribute(&e, b"number-rows-repeated")));
+                    }
+                },
+                b"table-cell" | b"covered-table-cell" => {
+                    if let Some(table) = tables.last_mut() {
+                        table.cell = Some((Vec::new(), repeat_attribute(&e, b"number-columns-repeated")));
+                    }
+                },
+                b"page" if kind == OdfKind::Presentation => {
+                    slide_count += 1;
+                    section = OdfSection {
+                        number: Some(slide_count),
+                        ..OdfSection::default()
+                    };
+                },
+                b"notes" => in_notes = true,
+                b"frame" => frame_class = ooxml::attribute(&e, b"class"),
+                _ => {},
+            },
+            Event::Empty(e) => match e.local_name().as_ref() {
+                // Diapositive vide : conservée pour garder la numérotation
+                b"page" if kind == OdfKind::Presentation => {
+                    slide_count += 1;
+                    sections.push(OdfSection {
+                        number: Some(slide_count),
+                        ..OdfSection::default()
+                    });
+                },
+                b"s" => {
+                    if let Some((text, _)) = paragraphs.last_mut() {
+                        let count = ooxml::attribute(&e, b"c").and_then(|c| c.parse().ok()).unwrap_or(1usize);
+                        text.push_str(&" ".repeat(count.min(MAX_REPEAT)));
+                    }
+                },
+                b"tab" => {
+                    if let Some((text, _)) = paragraphs.last_mut() {
+                        text.push('\t');
+                    }
+                },
+                b"line-break" => {
+                    if let Some((text, _)) = paragraphs.last_mut() {
+                        text.push('\n');
+                    }
+                },
+                b"table-cell" | b"covered-table-cell" => {
+                    if let Some(table) = tables.last_mut() {
+                        table.push_cell(String::new(), repeat_attribute(&e, b"number-columns-repeated"));
+                    }
+                },
+                _ => {},
+            },
+            Event::Text(t) => {
+                if let Some((text, _)) = paragraphs.last_mut() {
+                    text.push_str(&t.unescape()?);
+                }
+            },
+            Event::End(e) => match e.local_name().as_ref() {
+                b"p" | b"h" => {
+                    let (text, level) = match paragraphs.pop() {
+                        Some(paragraph) => paragraph,
+                        None => continue,
+                    };
+                    let text = text.trim().to_string();
+                    if text.is_empty() {
+                        continue;
+                    }
+
+                    if let Some((cell, _)) = tables.last_mut().and_then(|table| table.cell.as_mut()) {
+                        cell.push(text);
+                    } else if in_notes {
+                        section.notes.push(text);
+                    } else if let (Some(level), OdfKind::Text) = (level, kind) {
+                        // Un titre ODT ouvre une nouvelle section
+                        if !section.is_empty() {
+                            sections.push(std::mem::take(&mut section));
+                        }
+                        while headings.last().is_some_and(|(last_level, _)| *last_level >= level) {
+                            headings.pop();
+                        }
+                        headings.push((level, text.clone()));
+                        section = OdfSection {
+                            title: Some(text),
+                            heading_level: Some(level),
+                            section_path: headings.iter().map(|(_, heading)| heading.clone()).collect(),
+                            ..OdfSection::default()
+                        };
+                    } else if kind == OdfKind::Presentation
+                        && matches!(frame_class.as_deref(), Some("title")) && section.title.is_none()
+                    {
+                        section.title = Some(text);
+                    } else if let Some((outer, _)) = paragraphs.last_mut() {
+                        // Paragraphe imbriqué (note de bas de page) : rattaché au paragraphe englobant
+                        outer.push(' ');
+                        outer.push_str(&text);
+                    } else {
+                        section.blocks.push(text);
+                    }
+                },
+                b"table-cell" | b"covered-table-cell" => {
+                    if let Some(table) = tables.last_mut() {
+                        if let Some((cell, repeat)) = table.cell.take() {
+                            table.push_cell(cell.join(" "), repeat);
+                        }
+                    }
+                },
+                b"table-row" => {
+                    if let Some(table) = tables.last_mut() {
+                        table.finish_row();
+                    }
+                },
+                b"table" => {
+                    let table = match tables.pop() {
+                        Some(table) => table,
+                        None => continue,
+                    };
+                    if table.truncated {
+                        log::warn!("Table {} truncated to {} cells", table.name.as_deref().unwrap_or("(unnamed)"), MAX_TABLE_CELLS);
+                    }
+                    let lines: Vec<String> = table.rows.iter().map(|row| row.join(" | ")).collect();
+
+                    if let Some(outer) = tables.last_mut() {
+                        // Tableau imbriqué : aplati dans la cellule englobante
+                        if let Some((cell, _)) = outer.cell.as_mut() {
+                            cell.extend(lines);
+                        }
+                    } else if kind == OdfKind::Spreadsheet {
+                        // Chaque feuille devient une section
+                        sections.push(OdfSection {
+                            number: Some(sections.len() as u32 + 1),
+                            title: table.name,
+                            blocks: lines,
+                            table_count: 1,
+                            ..OdfSection::default()
+                        });
+                    } else if !lines.is_empty() {
+                        section.blocks.extend(lines);
+                        section.table_count += 1;
+                    }
+                },
+                b"page" if kind == OdfKind::Presentation => {
+                    sections.push(std::mem::take(&mut section));
+                },
+                b"notes" => in_notes = false,
+                b"frame" => frame_class = None,
+                _ => {},
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+    }
+
+    if kind == OdfKind::Text && !section.is_empty() {
+        sections.push(section);
+    }
+
+    Ok(sections)
+}
+
+/// Extrait les sections et les métadonnées d'un document ODF depuis les bytes
+fn extract_from_odf_content(content: &[u8], kind: OdfKind) -> Result<(Vec<OdfSection>, OdfMetadata), DocLoaderError> {
+    let mut archive = ooxml::open_archive(content)?;
+
+    if let Some(mimetype) = ooxml::read_entry(&mut archive, "mimetype")? {
+        if mimetype.trim() != kind.mimetype() {
+            log::warn!("Unexpected OpenDocument mimetype '{}' (expected '{}')", mimetype.trim(), kind.mimetype());
+        }
+    }
+
+    let content_xml = ooxml::read_entry(&mut archive, "content.xml")?
+        .ok_or_else(|| DocLoaderError::InvalidFormat(
+            "Not an OpenDocument file: content.xml is missing".to_string()
+        ))?;
+    let sections = parse_content_xml(&content_xml, kind)?;
+
+    let metadata = match ooxml::read_entry(&mut archive, "meta.xml")? {
+        Some(meta_xml) => OdfMetadata::parse(&meta_xml)?,
+        None => OdfMetadata::default(),
+    };
+
+    Ok((sections, metadata))
+}
+
+/// Construit la sortie universelle à partir des sections extraites
+fn build_output(
+    kind: OdfKind,
+    sections: &[OdfSection],
+    odf_meta: &OdfMetadata,
+    document_metadata: impl FnOnce(Option<String>, Value) -> DocumentMetadata,
+    params: &ProcessingParams,
+    start_time: std::time::Instant,
+) -> UniversalOutput {
+    let mut chunks = Vec::new();
+    let mut full_text = String::new();
+
+    for section in sections {
+        let raw_text = section.to_text(kind);
+
+        // Nettoyer le texte si demandé
+        let section_text = if params.text_cleaning {
+            clean_text(&raw_text)
+        } else {
+            raw_text
+        };
+        if section_text.is_empty() {
+            continue;
+        }
+
+        if !full_text.is_empty() {
+            full_text.push_str("\n\n");
+        }
+        let section_offset = full_text.len();
+        full_text.push_str(&section_text);
+
+        // Découper chaque section séparément pour conserver titre et numéro de page
+        for chunk_text in chunk_text(&section_text, params.max_chunk_size, params.chunk_overlap) {
+            let text_meta = extract_text_metadata(&chunk_text);
+            let start_offset = section_offset + section_text.find(&chunk_text).unwrap_or(0);
+            let index = chunks.len();
+
+            let mut format_specific = json!({
+                "word_count": text_meta.word_count,
+                "estimated_tokens": text_meta.estimated_tokens,
+                "table_count": section.table_count
+            });
+            match kind {
+                OdfKind::Text => {
+                    format_specific["heading"] = json!(section.title);
+                    format_specific["heading_level"] = json!(section.heading_level);
+                    format_specific["section_path"] = json!(section.section_path);
+                },
+                OdfKind::Spreadsheet => {
+                    format_specific["sheet_number"] = json!(section.number);
+                    format_specific["sheet_name"] = json!(section.title);
+                },
+                OdfKind::Presentation => {
+                    format_specific["slide_number"] = json!(section.number);
+                    format_specific["slide_title"] = json!(section.title);
+                    format_specific["has_notes"] = json!(!section.notes.is_empty());
+                },
+            }
+
+            chunks.push(DocumentChunk {
+                id: format!("{}_chunk_{}", kind.extension(), index),
+                content: chunk_text.clone(),
+                chunk_index: index,
+                position: ChunkPosition {
+                    page: section.number,
+                    line: None,
+                    start_offset: Some(start_offset),
+                    end_offset: Some(start_offset + chunk_text.len()),
+                },
+                metadata: ChunkMetadata {
+                    size: chunk_text.len(),
+                    language: if params.language_detection { text_meta.detected_language } else { None },
+                    confidence: Some(1.0), // Le texte est lu directement depuis content.xml
+                    format_specific: Some(format_specific),
+                },
+            });
+        }
+    }
+
+    let text_meta = extract_text_metadata(&full_text);
+    let title = odf_meta.title.clone().or_else(|| match kind {
+        OdfKind::Spreadsheet => None,
+        _ => sections.iter().find_map(|section| section.title.clone()),
+    });
+
+    let section_key = match kind {
+        OdfKind::Text => "headings",
+        OdfKind::Spreadsheet => "sheet_names",
+        OdfKind::Presentation => "slide_titles",
+    };
+    let format_metadata = json!({
+        "odf_metadata": {
+            "mimetype": kind.mimetype(),
+            "subject": odf_meta.subject,
+            "description": odf_meta.description,
+            "creator": odf_meta.creator,
+            "keywords": odf_meta.keywords,
+            "creation_date": odf_meta.creation_date,
+            "date": odf_meta.date,
+            "generator": odf_meta.generator,
+            "statistics": odf_meta.statistics,
+            "section_count": sections.len(),
+            "table_count": sections.iter().map(|section| section.table_count).sum::<usize>()
+        },
+        (section_key): sections.iter()
+            .filter(|section| kind != OdfKind::Text || section.title.is_some())
+            .map(|section| section.title.clone())
+            .collect::<Vec<_>>(),
+        "extracted_text_metadata": {
+            "total_words": text_meta.word_count,
+            "total_characters": text_meta.character_count,
+            "total_lines": text_meta.line_count,
+            "estimated_tokens": text_meta.estimated_tokens,
+            "detected_language": text_meta.detected_language
+        }
+    });
+
+    let processing_time = start_time.elapsed();
+    let processing_info = ProcessingInfo {
+        processor: kind.processor_name().to_string(),
+        processor_version: "1.0.0".to_string(),
+        processed_at: Utc::now(),
+        processing_time_ms: processing_time.as_millis() as u64,
+        total_chunks: chunks.len(),
+        total_content_size: full_text.len(),
+        processing_params: params.clone(),
+        warnings: Vec::new(),
+    };
+
+    UniversalOutput {
+        document_metadata: document_metadata(title, format_metadata),
+        chunks,
+        processing_info,
+    }
+}
+
+/// Traitement commun d'un fichier ODF sur disque
+fn process_odf_file(kind: OdfKind, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+    let start_time = std::time::Instant::now();
+
+    // Vérifier que le fichier existe
+    if !file_path.exists() {
+        return Err(DocLoaderError::FileNotFound(
+            format!("File not found: {}", file_path.display())
+        ));
+    }
+
+    let file_content = fs::read(file_path)?;
+    let (sections, odf_meta) = extract_from_odf_content(&file_content, kind)?;
+
+    // Métadonnées du fichier
+    let file_metadata = fs::metadata(file_path)?;
+
+    Ok(build_output(kind, &sections, &odf_meta, |title, format_metadata| DocumentMetadata {
+        filename: file_path.file_name()
+            .and_then(|name| name.to_str())
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("unknown.{}", kind.extension())),
+        filepath: file_path.to_string_lossy().to_string(),
+        document_type: kind.document_type(),
+        file_size: file_metadata.len(),
+        created_at: OdfMetadata::parse_date(&odf_meta.creation_date)
+            .or_else(|| file_metadata.created().ok().map(|t| t.into())),
+        modified_at: OdfMetadata::parse_date(&odf_meta.date)
+            .or_else(|| file_metadata.modified().ok().map(|t| t.into())),
+        title,
+        author: odf_meta.author(),
+        format_metadata,
+    }, params, start_time))
+}
+
+/// Traitement commun d'un document ODF en mémoire
+fn process_odf_content(kind: OdfKind, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+    let start_time = std::time::Instant::now();
+
+    let (sections, odf_meta) = extract_from_odf_content(content, kind)?;
+
+    Ok(build_output(kind, &sections, &odf_meta, |title, format_metadata| DocumentMetadata {
+        filename: filename.to_string(),
+        filepath: format!("memory://{}", filename),
+        document_type: kind.document_type(),
+        file_size: content.len() as u64,
+        created_at: OdfMetadata::parse_date(&odf_meta.creation_date).or_else(|| Some(Utc::now())),
+        modified_at: OdfMetadata::parse_date(&odf_meta.date).or_else(|| Some(Utc::now())),
+        title,
+        author: odf_meta.author(),
+        format_metadata,
+    }, params, start_time))
+}
+
+/// Processeur de documents texte OpenDocument (.odt)
+pub struct OdtProcessor;
+
+/// Processeur de classeurs OpenDocument (.ods)
+pub struct OdsProcessor;
+
+/// Processeur de présentations OpenDocument (.odp)
+pub struct OdpProcessor;
+
+macro_rules! impl_odf_processor {
+    ($processor:ident, $kind:expr, $doc_type:expr) => {
+        impl $processor {
+            pub fn new() -> Self {
+                Self
+            }
+        }
+
+        impl DocumentProcessor for $processor {
+            fn supported_type(&self) -> DocumentType {
+                $doc_type
+            }
+
+            fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+                process_odf_file($kind, file_path, params)
+            }
+
+            fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+                process_odf_content($kind, content, filename, params)
+            }
+        }
+
+        impl Default for $processor {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_odf_processor!(OdtProcessor, OdfKind::Text, DocumentType::ODT);
+impl_odf_processor!(OdsProcessor, OdfKind::Spreadsheet, DocumentType::ODS);
+impl_odf_processor!(OdpProcessor, OdfKind::Presentation, DocumentType::ODP);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    const META_XML: &str = r#"<office:document-meta xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:meta="urn:oasis:names:tc:opendocument:xmlns:meta:1.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+        <office:meta>
+            <dc:title>Design Notes</dc:title>
+            <meta:initial-creator>Alice Martin</meta:initial-creator>
+            <meta:creation-date>2024-03-01T09:30:00</meta:creation-date>
+            <meta:keyword>design</meta:keyword>
+            <meta:document-statistic meta:table-count="1" meta:word-count="42"/>
+        </office:meta>
+    </office:document-meta>"#;
+
+    fn build_odf(kind: OdfKind, content_xml: &str) -> Vec<u8> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let mut zip = zip::ZipWriter::new(&mut buffer);
+        let options = SimpleFileOptions::default();
+
+        for (name, data) in [("mimetype", kind.mimetype()), ("content.xml", content_xml), ("meta.xml", META_XML)] {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(data.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn test_odf_processor_creation() {
+        assert_eq!(OdtProcessor::new().supported_type().to_string(), "ODT");
+        assert_eq!(OdsProcessor::new().supported_type().to_string(), "ODS");
+        assert_eq!(OdpProcessor::new().supported_type().to_string(), "ODP");
+    }
+
+    #[test]
+    fn test_odt_headings_and_tables() {
+        let content_xml = r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0">
+            <office:body><office:text>
+                <text:h text:outline-level="1">Architecture</text:h>
+                <text:p>The loader is <text:span>modular</text:span>.</text:p>
+                <text:h text:outline-level="2">Processors</text:h>
+                <text:p>One per<text:s text:c="2"/>format.</text:p>
+                <table:table table:name="Formats">
+                    <table:table-row><table:table-cell><text:p>Format</text:p></table:table-cell><table:table-cell><text:p>Status</text:p></table:table-cell></table:table-row>
+                    <table:table-row><table:table-cell><text:p>ODT</text:p></table:table-cell><table:table-cell><text:p>Done</text:p></table:table-cell></table:table-row>
+                </table:table>
+            </office:text></office:body>
+        </office:document-content>"#;
+
+        let sections = parse_content_xml(content_xml, OdfKind::Text).unwrap();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].title.as_deref(), Some("Architecture"));
+        assert_eq!(sections[0].blocks, vec!["The loader is modular."]);
+        assert_eq!(sections[1].section_path, vec!["Architecture", "Processors"]);
+        assert_eq!(sections[1].blocks, vec!["One per  format.", "Format | Status", "ODT | Done"]);
+        assert_eq!(sections[1].table_count, 1);
+
+        let params = ProcessingParams::default();
+        let output = OdtProcessor::new().process_content(&build_odf(OdfKind::Text, content_xml), "notes.odt", &params).unwrap();
+        assert_eq!(output.document_metadata.document_type.to_string(), "ODT");
+        assert_eq!(output.document_metadata.title.as_deref(), Some("Design Notes"));
+        assert_eq!(output.document_metadata.author.as_deref(), Some("Alice Martin"));
+        assert_eq!(output.document_metadata.format_metadata["odf_metadata"]["statistics"]["word-count"], 42);
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[1].metadata.format_specific.as_ref().unwrap()["heading"], "Processors");
+    }
+
+    #[test]
+    fn test_ods_sheets() {
+        let content_xml = r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0">
+            <office:body><office:spreadsheet>
+                <table:table table:name="Sales">
+                    <table:table-row><table:table-cell><text:p>Region</text:p></table:table-cell><table:table-cell><text:p>Total</text:p></table:table-cell><table:table-cell table:number-columns-repeated="1020"/></table:table-row>
+                    <table:table-row><table:table-cell><text:p>EMEA</text:p></table:table-cell><table:table-cell office:value-type="float"><text:p>420</text:p></table:table-cell></table:table-row>
+                    <table:table-row table:number-rows-repeated="1048000"><table:table-cell table:number-columns-repeated="1024"/></table:table-row>
+                </table:table>
+                <table:table table:name="Costs">
+                    <table:table-row><table:table-cell><text:p>Rent</text:p></table:table-cell></table:table-row>
+                </table:table>
+            </office:spreadsheet></office:body>
+        </office:document-content>"#;
+
+        let params = ProcessingParams::default();
+        let output = OdsProcessor::new().process_content(&build_odf(OdfKind::Spreadsheet, content_xml), "budget.ods", &params).unwrap();
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[0].position.page, Some(1));
+        assert_eq!(output.chunks[0].content, "Sheet 1: Sales Region | Total EMEA | 420");
+        assert_eq!(output.chunks[1].metadata.format_specific.as_ref().unwrap()["sheet_name"], "Costs");
+
+        // Lignes et colonnes répétées au maximum : le total de cellules reste plafonné
+        let row = r#"<table:table-row table:number-rows-repeated="1000"><table:table-cell table:number-columns-repeated="1000"><text:p>x</text:p></table:table-cell></table:table-row>"#;
+        let content_xml = format!(
+            r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0"><office:body><office:spreadsheet><table:table table:name="Bomb">{}</table:table></office:spreadsheet></office:body></office:document-content>"#,
+            row.repeat(5)
+        );
+        let sections = parse_content_xml(&content_xml, OdfKind::Spreadsheet).unwrap();
+        assert_eq!(sections[0].blocks.len() * 1000, MAX_TABLE_CELLS);
+    }
+
+    #[test]
+    fn test_odp_slides_and_notes() {
+        let content_xml = r#"<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" xmlns:draw="urn:oasis:names:tc:opendocument:xmlns:drawing:1.0" xmlns:presentation="urn:oasis:names:tc:opendocument:xmlns:presentation:1.0">
+            <office:body><office:presentation>
+                <draw:page draw:name="page1">
+                    <draw:frame presentation:class="title"><draw:text-box><text:p>Roadmap</text:p></draw:text-box></draw:frame>
+                    <draw:frame presentation:class="outline"><draw:text-box><text:list><text:list-item><text:p>Ship ODF support</text:p></text:list-item></text:list></draw:text-box></draw:frame>
+                    <presentation:notes><draw:frame presentation:class="notes"><draw:text-box><text:p>Keep it short</text:p></draw:text-box></draw:frame></presentation:notes>
+                </draw:page>
+                <draw:page draw:name="page2"/>
+                <draw:page draw:name="page3">
+                    <draw:frame presentation:class="title"><draw:text-box><text:p>Questions</text:p></draw:text-box></draw:frame>
+                </draw:page>
+            </office:presentation></office:body>
+        </office:document-content>"#;
+
+        let sections = parse_content_xml(content_xml, OdfKind::Presentation).unwrap();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(sections[0].title.as_deref(), Some("Roadmap"));
+        assert_eq!(sections[0].blocks, vec!["Ship ODF support"]);
+        assert_eq!(sections[0].notes, vec!["Keep it short"]);
+
+        let params = ProcessingParams::default();
+        let output = OdpProcessor::new().process_content(&build_odf(OdfKind::Presentation, content_xml), "deck.odp", &params).unwrap();
+        assert_eq!(output.chunks[0].position.page, Some(1));
+        assert_eq!(output.chunks[1].content, "Slide 2");
+        assert_eq!(output.chunks[2].position.page, Some(3));
+        assert!(output.chunks[2].content.starts_with("Slide 3: Questions"));
+    }
+}
@@ -0,0 +1,218 @@
+//! Utilitaires partagés pour les formats bureautiques basés sur un conteneur ZIP + XML
+//! (Office Open XML : PPTX, DOCX, ...).
+
+use crate::error::DocLoaderError;
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use zip::ZipArchive;
+
+/// Taille maximale (décompressée) d'une entrée lue depuis l'archive
+pub(crate) const MAX_ENTRY_SIZE: u64 = 256 * 1024 * 1024;
+
+pub(crate) type Archive<'a> = ZipArchive<Cursor<&'a [u8]>>;
+
+/// Ouvre une archive ZIP en mémoire
+pub(crate) fn open_archive(content: &[u8]) -> Result<Archive<'_>, DocLoaderError> {
+    ZipArchive::new(Cursor::new(content))
+        .map_err(|e| DocLoaderError::Archive(format!("Failed to open archive: {}", e)))
+}
+
+/// Lit une entrée de l'archive en texte, `None` si elle n'existe pas
+pub(crate) fn read_entry(archive: &mut Archive<'_>, name: &str) -> Result<Option<String>, DocLoaderError> {
+    let entry = match archive.by_name(name) {
+        Ok(entry) => entry,
+        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+        Err(e) => return Err(DocLoaderError::Archive(format!("Failed to read {}: {}", name, e))),
+    };
+
+    if entry.size() > MAX_ENTRY_SIZE {
+        return Err(DocLoaderError::Archive(format!(
+            "Entry {} exceeds the maximum allowed size ({} bytes)", name, entry.size()
+        )));
+    }
+
+    let mut content = String::new();
+    entry.take(MAX_ENTRY_SIZE).read_to_string(&mut content)?;
+    Ok(Some(content))
+}
+
+/// Valeur d'un attribut par nom local (le préfixe d'espace de noms est ignoré)
+pub(crate) fn attribute(element: &BytesStart<'_>, local_name: &[u8]) -> Option<String> {
+    element.attributes()
+        .flatten()
+        .find(|attr| attr.key.local_name().as_ref() == local_name)
+        .and_then(|attr| attr.unescape_value().ok())
+        .map(|value| value.into_owned())
+}
+
+/// Valeur d'un attribut par nom qualifié exact (ex: `r:id`)
+pub(crate) fn qualified_attribute(element: &BytesStart<'_>, name: &[u8]) -> Option<String> {
+    element.attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == name)
+        .and_then(|attr| attr.unescape_value().ok())
+        .map(|value| value.into_owned())
+}
+
+/// Résout la cible d'une relation par rapport au dossier de la partie source
+pub(crate) fn resolve_target(base_dir: &str, target: &str) -> String {
+    if let Some(absolute) = target.strip_prefix('/') {
+        return absolute.to_string();
+    }
+
+    let mut segments: Vec<&str> = base_dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in target.split('/') {
+        match segment {
+            "" | "." => {},
+            ".." => { segments.pop(); },
+            other => segments.push(other),
+        }
+    }
+    segments.join("/")
+}
+
+/// Relation d'un fichier `.rels`
+#[derive(Debug, Clone)]
+pub(crate) struct Relationship {
+    pub rel_type: String,
+    pub target: String,
+}
+
+/// Parse un fichier `_rels/*.rels` en table `Id -> Relationship`
+pub(crate) fn parse_relationships(xml: &str) -> Result<HashMap<String, Relationship>, DocLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut relationships = HashMap::new();
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"Relationship" => {
+                if let (Some(id), Some(target)) = (attribute(&e, b"Id"), attribute(&e, b"Target")) {
+                    relationships.insert(id, Relationship {
+                        rel_type: attribute(&e, b"Type").unwrap_or_default(),
+                        target,
+                    });
+                }
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+    }
+
+    Ok(relationships)
+}
+
+/// Chemin du fichier de relations associé à une partie (`ppt/slides/slide1.xml` -> `ppt/slides/_rels/slide1.xml.rels`)
+pub(crate) fn relationships_path(part: &str) -> String {
+    match part.rsplit_once('/') {
+        Some((dir, file)) => format!("{}/_rels/{}.rels", dir, file),
+        None => format!("_rels/{}.rels", part),
+    }
+}
+
+/// Propriétés du document issues de `docProps/core.xml` et `docProps/app.xml`
+#[derive(Debug, Clone, Default)]
+pub struct CoreProperties {
+    pub title: Option<String>,
+    pub subject: Option<String>,
+    pub creator: Option<String>,
+    pub keywords: Option<String>,
+    pub description: Option<String>,
+    pub last_modified_by: Option<String>,
+    pub created: Option<String>,
+    pub modified: Option<String>,
+    pub company: Option<String>,
+    pub application: Option<String>,
+}
+
+/// Lit les propriétés `docProps` d'un document OOXML (absentes = valeurs vides)
+pub(crate) fn read_core_properties(archive: &mut Archive<'_>) -> Result<CoreProperties, DocLoaderError> {
+    let mut props = CoreProperties::default();
+
+    if let Some(core_xml) = read_entry(archive, "docProps/core.xml")? {
+        for (name, value) in leaf_elements(&core_xml)? {
+            let slot = match name.as_str() {
+                "title" => &mut props.title,
+                "subject" => &mut props.subject,
+                "creator" => &mut props.creator,
+                "keywords" => &mut props.keywords,
+                "description" => &mut props.description,
+                "lastModifiedBy" => &mut props.last_modified_by,
+                "created" => &mut props.created,
+                "modified" => &mut props.modified,
+                _ => continue,
+            };
+            *slot = Some(value);
+        }
+    }
+
+    if let Some(app_xml) = read_entry(archive, "docProps/app.xml")? {
+        for (name, value) in leaf_elements(&app_xml)? {
+            match name.as_str() {
+                "Company" => props.company = Some(value),
+                "Application" => props.application = Some(value),
+                _ => {},
+            }
+        }
+    }
+
+    Ok(props)
+}
+
+/// Liste les éléments feuilles non vides `(nom local, texte)` d'un document XML simple
+pub(crate) fn leaf_elements(xml: &str) -> Result<Vec<(String, String)>, DocLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut elements = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                current = Some((String::from_utf8_lossy(e.local_name().as_ref()).into_owned(), String::new()));
+            },
+            Event::Text(t) => {
+                if let Some((_, text)) = current.as_mut() {
+                    text.push_str(&t.unescape()?);
+                }
+            },
+            Event::End(_) => {
+                if let Some((name, text)) = current.take() {
+                    let text = text.trim();
+                    if !text.is_empty() {
+                        elements.push((name, text.to_string()));
+                    }
+                }
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+    }
+
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_target() {
+        assert_eq!(resolve_target("ppt", "slides/slide1.xml"), "ppt/slides/slide1.xml");
+        assert_eq!(resolve_target("ppt/slides", "../notesSlides/notesSlide1.xml"), "ppt/notesSlides/notesSlide1.xml");
+        assert_eq!(resolve_target("ppt/slides", "/ppt/media/image1.png"), "ppt/media/image1.png");
+    }
+
+    #[test]
+    fn test_parse_relationships() {
+        let xml = r#"<?xml version="1.0"?>
+            <Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+                <Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide1.xml"/>
+            </Relationships>"#;
+
+        let rels = parse_relationships(xml).unwrap();
+        assert_eq!(rels["rId2"].target, "slides/slide1.xml");
+        assert!(rels["rId2"].rel_type.ends_with("/slide"));
+    }
+}
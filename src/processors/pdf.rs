@@ -145,7 +145,7 @@ impl DocumentProcessor for PdfProcessor {
         
         // Métadonnées du fichier
         let file_metadata = fs::metadata(file_path)
-            .map_err(|e| DocLoaderError::Io(e))?;
+            .map_err(DocLoaderError::Io)?;
         
         // Métadonnées PDF spécifiques
         let pdf_metadata = self.extract_pdf_metadata(file_path)?;
@@ -174,6 +174,7 @@ impl DocumentProcessor for PdfProcessor {
             total_chunks: chunks.len(),
             total_content_size: text.len(),
             processing_params: params.clone(),
+            warnings: Vec::new(),
         };
         
         Ok(UniversalOutput {
@@ -0,0 +1,621 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::processors::ooxml::{self, Archive, CoreProperties};
+use crate::utils::{chunk_text, clean_text, extract_text_metadata};
+
+use std::path::Path;
+use std::fs;
+use chrono::{DateTime, Utc};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use serde_json::json;
+
+/// Types de placeholders qui ne portent pas de contenu utile (numéro de slide, date, pied de page...)
+const IGNORED_PLACEHOLDERS: &[&str] = &["sldNum", "dt", "ftr", "hdr", "sldImg"];
+
+pub struct PptxProcessor;
+
+impl PptxProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lit et extrait les slides d'un fichier PPTX
+    fn read_pptx_file(&self, file_path: &Path) -> Result<(Vec<SlideContent>, PptxMetadata), DocLoaderError> {
+        let file_content = fs::read(file_path)?;
+        self.extract_from_pptx_content(&file_content)
+    }
+
+    /// Extrait les slides et les métadonnées d'un PPTX depuis les bytes
+    fn extract_from_pptx_content(&self, content: &[u8]) -> Result<(Vec<SlideContent>, PptxMetadata), DocLoaderError> {
+        let mut archive = ooxml::open_archive(content)?;
+
+        let slide_paths = self.slide_paths(&mut archive)?;
+        if slide_paths.is_empty() && ooxml::read_entry(&mut archive, "ppt/presentation.xml")?.is_none() {
+            return Err(DocLoaderError::InvalidFormat(
+                "Not a PowerPoint presentation: ppt/presentation.xml is missing".to_string()
+            ));
+        }
+
+        let mut slides = Vec::new();
+        for (index, slide_path) in slide_paths.iter().enumerate() {
+            let slide_xml = match ooxml::read_entry(&mut archive, slide_path)? {
+                Some(xml) => xml,
+                None => {
+                    log::warn!("Slide part {} referenced but missing from archive", slide_path);
+                    continue;
+                }
+            };
+            let parts = parse_slide_xml(&slide_xml)?;
+
+            // Les notes de l'orateur sont liées via les relations de la slide
+            let notes = match self.notes_path(&mut archive, slide_path)? {
+                Some(notes_path) => match ooxml::read_entry(&mut archive, &notes_path)? {
+                    Some(notes_xml) => {
+                        let notes_parts = parse_slide_xml(&notes_xml)?;
+                        let text = notes_parts.body.join("\n");
+                        if text.trim().is_empty() { None } else { Some(text) }
+                    },
+                    None => None,
+                },
+                None => None,
+            };
+
+            slides.push(SlideContent {
+                number: (index + 1) as u32,
+                title: parts.title,
+                body: parts.body,
+                tables: parts.tables,
+                notes,
+            });
+        }
+
+        let properties = ooxml::read_core_properties(&mut archive)?;
+        let metadata = PptxMetadata::from_slides(&slides, properties);
+
+        Ok((slides, metadata))
+    }
+
+    /// Chemins des slides dans l'ordre de la présentation
+    fn slide_paths(&self, archive: &mut Archive<'_>) -> Result<Vec<String>, DocLoaderError> {
+        // L'ordre de référence est celui de `p:sldIdLst` dans presentation.xml
+        if let (Some(presentation), Some(rels_xml)) = (
+            ooxml::read_entry(archive, "ppt/presentation.xml")?,
+            ooxml::read_entry(archive, "ppt/_rels/presentation.xml.rels")?,
+        ) {
+            let rels = ooxml::parse_relationships(&rels_xml)?;
+            let mut reader = Reader::from_str(&presentation);
+            let mut paths = Vec::new();
+
+            loop {
+                match reader.read_event()? {
+                    Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"sldId" => {
+                        if let Some(rel) = ooxml::qualified_attribute(&e, b"r:id").and_then(|id| rels.get(&id)) {
+                            paths.push(ooxml::resolve_target("ppt", &rel.target));
+                        }
+                    },
+                    Event::Eof => break,
+                    _ => {},
+                }
+            }
+
+            if !paths.is_empty() {
+                return Ok(paths);
+            }
+        }
+
+        // À défaut, trier les parties `ppt/slides/slideN.xml` par numéro
+        let mut numbered: Vec<(u32, String)> = archive.file_names()
+            .filter_map(|name| {
+                let number = name.strip_prefix("ppt/slides/slide")?.strip_suffix(".xml")?.parse().ok()?;
+                Some((number, name.to_string()))
+            })
+            .collect();
+        numbered.sort();
+
+        Ok(numbered.into_iter().map(|(_, name)| name).collect())
+    }
+
+    /// Chemin de la partie de notes associée à une slide
+    fn notes_path(&self, archive: &mut Archive<'_>, slide_path: &str) -> Result<Option<String>, DocLoaderError> {
+        let rels_xml = match ooxml::read_entry(archive, &ooxml::relationships_path(slide_path))? {
+            Some(xml) => xml,
+            None => return Ok(None),
+        };
+
+        let slide_dir = slide_path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+        Ok(ooxml::parse_relationships(&rels_xml)?
+            .values()
+            .find(|rel| rel.rel_type.ends_with("/notesSlide"))
+            .map(|rel| ooxml::resolve_target(slide_dir, &rel.target)))
+    }
+
+    /// Construit la sortie universelle à partir des slides extraites
+    fn build_output(
+        &self,
+        slides: &[SlideContent],
+        pptx_meta: &PptxMetadata,
+        document_metadata: impl FnOnce(Option<String>, serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let mut chunks = Vec::new();
+        let mut full_text = String::new();
+
+        for slide in slides {
+            let raw_text = slide.to_text();
+
+            // Nettoyer le texte si demandé
+            let slide_text = if params.text_cleaning {
+                clean_text(&raw_text)
+            } else {
+                raw_text
+            };
+
+            if !full_text.is_empty() {
+                full_text.push_str("\n\n");
+            }
+            let slide_offset = full_text.len();
+            full_text.push_str(&slide_text);
+
+            // Découper chaque slide séparément pour que chaque chunk reste rattaché à sa slide
+            for chunk_text in chunk_text(&slide_text, params.max_chunk_size, params.chunk_overlap) {
+                let text_meta = extract_text_metadata(&chunk_text);
+                let start_offset = slide_offset + slide_text.find(&chunk_text).unwrap_or(0);
+                let index = chunks.len();
+
+                chunks.push(DocumentChunk {
+                    id: format!("pptx_chunk_{}", index),
+                    content: chunk_text.clone(),
+                    chunk_index: index,
+                    position: ChunkPosition {
+                        page: Some(slide.number),
+                        line: None,
+                        start_offset: Some(start_offset),
+                        end_offset: Some(start_offset + chunk_text.len()),
+                    },
+                    metadata: ChunkMetadata {
+                        size: chunk_text.len(),
+                        language: if params.language_detection { text_meta.detected_language } else { None },
+                        confidence: Some(1.0), // Le texte est lu directement depuis le XML des slides
+                        format_specific: Some(json!({
+                            "word_count": text_meta.word_count,
+                            "estimated_tokens": text_meta.estimated_tokens,
+                            "slide_number": slide.number,
+                            "slide_title": slide.title,
+                            "table_count": slide.tables.len(),
+                            "has_notes": slide.notes.is_some()
+                        })),
+                    },
+                });
+            }
+        }
+
+        let text_meta = extract_text_metadata(&full_text);
+        let title = pptx_meta.title.clone()
+            .or_else(|| slides.iter().find_map(|slide| slide.title.clone()));
+
+        let format_metadata = json!({
+            "pptx_metadata": {
+                "slide_count": pptx_meta.slide_count,
+                "notes_count": pptx_meta.notes_count,
+                "table_count": pptx_meta.table_count,
+                "subject": pptx_meta.subject,
+                "keywords": pptx_meta.keywords,
+                "company": pptx_meta.company,
+                "last_modified_by": pptx_meta.last_modified_by,
+                "created": pptx_meta.created,
+                "modified": pptx_meta.modified,
+                "application": pptx_meta.application
+            },
+            "slide_titles": slides.iter().map(|slide| slide.title.clone()).collect::<Vec<_>>(),
+            "extracted_text_metadata": {
+                "total_words": text_meta.word_count,
+                "total_characters": text_meta.character_count,
+                "total_lines": text_meta.line_count,
+                "estimated_tokens": text_meta.estimated_tokens,
+                "detected_language": text_meta.detected_language
+            }
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "PptxProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: full_text.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(title, format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+/// Contenu extrait d'une slide
+#[derive(Debug, Clone)]
+pub struct SlideContent {
+    /// Numéro de la slide (à partir de 1, dans l'ordre de la présentation)
+    pub number: u32,
+    pub title: Option<String>,
+    /// Paragraphes des zones de texte (hors titre)
+    pub body: Vec<String>,
+    /// Tableaux, ligne par ligne puis cellule par cellule
+    pub tables: Vec<Vec<Vec<String>>>,
+    /// Notes de l'orateur
+    pub notes: Option<String>,
+}
+
+impl SlideContent {
+    /// Rend la slide en texte : titre, corps, tableaux puis notes
+    pub fn to_text(&self) -> String {
+        let mut text = match &self.title {
+            Some(title) => format!("Slide {}: {}\n", self.number, title),
+            None => format!("Slide {}\n", self.number),
+        };
+
+        for paragraph in &self.body {
+            text.push_str(paragraph);
+            text.push('\n');
+        }
+
+        for table in &self.tables {
+            text.push('\n');
+            for row in table {
+                text.push_str(&row.join(" | "));
+                text.push('\n');
+            }
+        }
+
+        if let Some(notes) = &self.notes {
+            text.push_str("\nNotes: ");
+            text.push_str(notes);
+            text.push('\n');
+        }
+
+        text
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PptxMetadata {
+    pub slide_count: usize,
+    pub notes_count: usize,
+    pub table_count: usize,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub subject: Option<String>,
+    pub keywords: Option<String>,
+    pub company: Option<String>,
+    pub last_modified_by: Option<String>,
+    pub created: Option<String>,
+    pub modified: Option<String>,
+    pub application: Option<String>,
+}
+
+impl PptxMetadata {
+    fn from_slides(slides: &[SlideContent], properties: CoreProperties) -> Self {
+        Self {
+            slide_count: slides.len(),
+            notes_count: slides.iter().filter(|slide| slide.notes.is_some()).count(),
+            table_count: slides.iter().map(|slide| slide.tables.len()).sum(),
+            title: properties.title,
+            author: properties.creator,
+            subject: properties.subject,
+            keywords: properties.keywords,
+            company: properties.company,
+            last_modified_by: properties.last_modified_by,
+            created: properties.created,
+            modified: properties.modified,
+            application: properties.application,
+        }
+    }
+
+    fn parse_date(value: &Option<String>) -> Option<DateTime<Utc>> {
+        value.as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc))
+    }
+}
+
+/// Éléments textuels d'une slide ou d'une page de notes
+#[derive(Debug, Default)]
+struct SlideParts {
+    title: Option<String>,
+    body: Vec<String>,
+    tables: Vec<Vec<Vec<String>>>,
+}
+
+/// Forme (`p:sp`) en cours de lecture
+#[derive(Default)]
+struct ShapeState {
+    placeholder: Option<String>,
+    paragraphs: Vec<String>,
+}
+
+/// Parse le XML d'une slide (`p:sld`) ou d'une page de notes (`p:notes`)
+fn parse_slide_xml(xml: &str) -> Result<SlideParts, DocLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut parts = SlideParts::default();
+
+    let mut shape: Option<ShapeState> = None;
+    let mut paragraph: Option<String> = None;
+    let mut in_text_run = false;
+    let mut table: Option<Vec<Vec<String>>> = None;
+    let mut row: Option<Vec<String>> = None;
+    let mut cell: Option<Vec<String>> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"sp" => shape = Some(ShapeState::default()),
+                b"ph" => {
+                    if let Some(shape) = shape.as_mut() {
+                        shape.placeholder = Some(ooxml::attribute(&e, b"type").unwrap_or_else(|| "body".to_string()));
+                    }
+                },
+                b"p" => paragraph = Some(String::new()),
+                b"t" => in_text_run = true,
+                b"tbl" => table = Some(Vec::new()),
+                b"tr" => row = Some(Vec::new()),
+                b"tc" => cell = Some(Vec::new()),
+                _ => {},
+            },
+            Event::Empty(e) => match e.local_name().as_ref() {
+                b"ph" => {
+                    if let Some(shape) = shape.as_mut() {
+                        shape.placeholder = Some(ooxml::attribute(&e, b"type").unwrap_or_else(|| "body".to_string()));
+                    }
+                },
+                b"br" => {
+                    if let Some(paragraph) = paragraph.as_mut() {
+                        paragraph.push(' ');
+                    }
+                },
+                // Cellule vide : conserver la colonne
+                b"tc" => {
+                    if let Some(row) = row.as_mut() {
+                        row.push(String::new());
+                    }
+                },
+                _ => {},
+            },
+            Event::Text(t) if in_text_run => {
+                if let Some(paragraph) = paragraph.as_mut() {
+                    paragraph.push_str(&t.unescape()?);
+                }
+            },
+            Event::End(e) => match e.local_name().as_ref() {
+                b"t" => in_text_run = false,
+                b"p" => {
+                    let text = paragraph.take().unwrap_or_default().trim().to_string();
+                    if text.is_empty() {
+                        continue;
+                    }
+                    if let Some(cell) = cell.as_mut() {
+                        cell.push(text);
+                    } else if let Some(shape) = shape.as_mut() {
+                        shape.paragraphs.push(text);
+                    } else {
+                        parts.body.push(text);
+                    }
+                },
+                b"tc" => {
+                    if let (Some(row), Some(cell)) = (row.as_mut(), cell.take()) {
+                        row.push(cell.join(" "));
+                    }
+                },
+                b"tr" => {
+                    if let (Some(table), Some(row)) = (table.as_mut(), row.take()) {
+                        table.push(row);
+                    }
+                },
+                b"tbl" => {
+                    if let Some(table) = table.take() {
+                        if !table.is_empty() {
+                            parts.tables.push(table);
+                        }
+                    }
+                },
+                b"sp" => {
+                    if let Some(shape) = shape.take() {
+                        match shape.placeholder.as_deref() {
+                            Some("title") | Some("ctrTitle") => {
+                                if !shape.paragraphs.is_empty() {
+                                    parts.title = Some(shape.paragraphs.join(" "));
+                                }
+                            },
+                            Some(kind) if IGNORED_PLACEHOLDERS.contains(&kind) => {},
+                            _ => parts.body.extend(shape.paragraphs),
+                        }
+                    }
+                },
+                _ => {},
+            },
+            Event::Eof => break,
+            _ => {},
+        }
+    }
+
+    Ok(parts)
+}
+
+impl DocumentProcessor for PptxProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::PPTX
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et extraire les slides
+        let (slides, pptx_meta) = self.read_pptx_file(file_path)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&slides, &pptx_meta, |title, format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.pptx")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::PPTX,
+            file_size: file_metadata.len(),
+            created_at: PptxMetadata::parse_date(&pptx_meta.created)
+                .or_else(|| file_metadata.created().ok().map(|t| t.into())),
+            modified_at: PptxMetadata::parse_date(&pptx_meta.modified)
+                .or_else(|| file_metadata.modified().ok().map(|t| t.into())),
+            title,
+            author: pptx_meta.author.clone(),
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Extraire les slides depuis les bytes
+        let (slides, pptx_meta) = self.extract_from_pptx_content(content)?;
+
+        Ok(self.build_output(&slides, &pptx_meta, |title, format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::PPTX,
+            file_size: content.len() as u64,
+            created_at: PptxMetadata::parse_date(&pptx_meta.created).or_else(|| Some(Utc::now())),
+            modified_at: PptxMetadata::parse_date(&pptx_meta.modified).or_else(|| Some(Utc::now())),
+            title,
+            author: pptx_meta.author.clone(),
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for PptxProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    const SLIDE_1: &str = r#"<p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+        <p:cSld><p:spTree>
+            <p:sp><p:nvSpPr><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr>
+                <p:txBody><a:p><a:r><a:t>Quarterly Results</a:t></a:r></a:p></p:txBody></p:sp>
+            <p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr>
+                <p:txBody><a:p><a:r><a:t>Revenue grew by 12%</a:t></a:r></a:p><a:p><a:r><a:t>Costs were stable</a:t></a:r></a:p></p:txBody></p:sp>
+            <p:sp><p:nvSpPr><p:nvPr><p:ph type="sldNum"/></p:nvPr></p:nvSpPr>
+                <p:txBody><a:p><a:r><a:t>1</a:t></a:r></a:p></p:txBody></p:sp>
+        </p:spTree></p:cSld></p:sld>"#;
+
+    const SLIDE_2: &str = r#"<p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+        <p:cSld><p:spTree>
+            <p:sp><p:nvSpPr><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr>
+                <p:txBody><a:p><a:r><a:t>Regional Breakdown</a:t></a:r></a:p></p:txBody></p:sp>
+            <p:graphicFrame><a:graphic><a:graphicData><a:tbl>
+                <a:tr><a:tc><a:txBody><a:p><a:r><a:t>Region</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>Sales</a:t></a:r></a:p></a:txBody></a:tc></a:tr>
+                <a:tr><a:tc><a:txBody><a:p><a:r><a:t>EMEA</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>420</a:t></a:r></a:p></a:txBody></a:tc></a:tr>
+            </a:tbl></a:graphicData></a:graphic></p:graphicFrame>
+        </p:spTree></p:cSld></p:sld>"#;
+
+    const NOTES_2: &str = r#"<p:notes xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+        <p:cSld><p:spTree>
+            <p:sp><p:nvSpPr><p:nvPr><p:ph type="sldImg"/></p:nvPr></p:nvSpPr></p:sp>
+            <p:sp><p:nvSpPr><p:nvPr><p:ph type="body" idx="1"/></p:nvPr></p:nvSpPr>
+                <p:txBody><a:p><a:r><a:t>Mention the EMEA reorganisation</a:t></a:r></a:p></p:txBody></p:sp>
+        </p:spTree></p:cSld></p:notes>"#;
+
+    /// Construit un PPTX minimal dont l'ordre des slides diffère de leur numérotation
+    fn build_pptx() -> Vec<u8> {
+        let mut buffer = std::io::Cursor::new(Vec::new());
+        let mut zip = zip::ZipWriter::new(&mut buffer);
+        let options = SimpleFileOptions::default();
+
+        let parts = [
+            ("ppt/presentation.xml", r#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:sldIdLst><p:sldId id="256" r:id="rId3"/><p:sldId id="257" r:id="rId2"/></p:sldIdLst></p:presentation>"#),
+            ("ppt/_rels/presentation.xml.rels", r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide1.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide2.xml"/></Relationships>"#),
+            ("ppt/slides/slide2.xml", SLIDE_1),
+            ("ppt/slides/slide1.xml", SLIDE_2),
+            ("ppt/slides/_rels/slide1.xml.rels", r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesSlide" Target="../notesSlides/notesSlide1.xml"/></Relationships>"#),
+            ("ppt/notesSlides/notesSlide1.xml", NOTES_2),
+            ("docProps/core.xml", r#"<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/"><dc:title>Q4 Review</dc:title><dc:creator>Jane Doe</dc:creator><dcterms:created>2024-12-01T10:00:00Z</dcterms:created></cp:coreProperties>"#),
+            ("docProps/app.xml", r#"<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties"><Company>ACME</Company></Properties>"#),
+        ];
+        for (name, xml) in parts {
+            zip.start_file(name, options).unwrap();
+            zip.write_all(xml.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn test_pptx_processor_creation() {
+        let processor = PptxProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "PPTX");
+    }
+
+    #[test]
+    fn test_parse_slide_xml() {
+        let parts = parse_slide_xml(SLIDE_1).unwrap();
+        assert_eq!(parts.title.as_deref(), Some("Quarterly Results"));
+        assert_eq!(parts.body, vec!["Revenue grew by 12%", "Costs were stable"]);
+
+        let parts = parse_slide_xml(SLIDE_2).unwrap();
+        assert_eq!(parts.tables, vec![vec![
+            vec!["Region".to_string(), "Sales".to_string()],
+            vec!["EMEA".to_string(), "420".to_string()],
+        ]]);
+    }
+
+    #[test]
+    fn test_process_content() {
+        let processor = PptxProcessor::new();
+        let params = ProcessingParams::default();
+
+        let output = processor.process_content(&build_pptx(), "deck.pptx", &params).unwrap();
+        assert_eq!(output.document_metadata.document_type.to_string(), "PPTX");
+        assert_eq!(output.document_metadata.title.as_deref(), Some("Q4 Review"));
+        assert_eq!(output.document_metadata.author.as_deref(), Some("Jane Doe"));
+        assert_eq!(output.document_metadata.format_metadata["pptx_metadata"]["company"], "ACME");
+
+        // Les slides suivent l'ordre de sldIdLst, pas le nom des parties
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[0].position.page, Some(1));
+        assert!(output.chunks[0].content.starts_with("Slide 1: Quarterly Results"));
+        assert_eq!(output.chunks[1].position.page, Some(2));
+        assert!(output.chunks[1].content.contains("EMEA | 420"));
+        assert!(output.chunks[1].content.contains("Notes: Mention the EMEA reorganisation"));
+    }
+
+    #[test]
+    fn test_invalid_archive() {
+        let processor = PptxProcessor::new();
+        let result = processor.process_content(b"not a zip", "broken.pptx", &ProcessingParams::default());
+        assert!(result.is_err());
+    }
+}
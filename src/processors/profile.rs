@@ -0,0 +1,586 @@
+//! Profil des colonnes d'un CSV calculé en une seule passe, en mémoire bornée.
+//!
+//! Les valeurs ne sont pas conservées : chaque colonne garde des compteurs par type, les formats
+//! de date encore compatibles, des statistiques numériques incrémentales, les valeurs les plus
+//! fréquentes (Space-Saving) et un estimateur de cardinalité (exact jusqu'à
+//! `EXACT_DISTINCT_LIMIT` valeurs, HyperLogLog au-delà). Le schéma inféré (`CsvSchema`) est
+//! exportable en JSON pour les outils de chargement.
+
+use crate::core::UniversalOutput;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+/// Nombre de valeurs distinctes comptées exactement avant de passer à l'estimation
+const EXACT_DISTINCT_LIMIT: usize = 4096;
+
+/// Précision de l'HyperLogLog : 2^12 registres (4 Kio, erreur type ≈ 1,6 %)
+const HLL_PRECISION: u32 = 12;
+
+/// Compteur de valeurs distinctes : ensemble d'empreintes, puis HyperLogLog une fois la limite atteinte
+#[derive(Debug, Clone)]
+pub(crate) struct DistinctCounter {
+    exact: Option<HashSet<u64>>,
+    registers: Vec<u8>,
+}
+
+impl DistinctCounter {
+    pub(crate) fn new() -> Self {
+        Self { exact: Some(HashSet::new()), registers: Vec::new() }
+    }
+
+    pub(crate) fn insert(&mut self, value: &str) {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        match &mut self.exact {
+            Some(hashes) => {
+                hashes.insert(hash);
+                if hashes.len() > EXACT_DISTINCT_LIMIT {
+                    let hashes = self.exact.take().unwrap_or_default();
+                    self.registers = vec![0; 1 << HLL_PRECISION];
+                    for hash in hashes {
+                        self.add_to_registers(hash);
+                    }
+                }
+            },
+            None => self.add_to_registers(hash),
+        }
+    }
+
+    fn add_to_registers(&mut self, hash: u64) {
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        // Bit sentinelle : le rang est borné même si les bits restants sont nuls
+        let rest = (hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// `false` une fois le comptage passé à l'estimation
+    pub(crate) fn is_exact(&self) -> bool {
+        self.exact.is_some()
+    }
+
+    pub(crate) fn count(&self) -> usize {
+        if let Some(hashes) = &self.exact {
+            return hashes.len();
+        }
+
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self.registers.iter().map(|&rank| 2f64.powi(-(rank as i32))).sum();
+        let estimate = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|&&rank| rank == 0).count();
+        // Correction des petites cardinalités (comptage linéaire)
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+}
+
+/// Nombre de valeurs suivies pour les valeurs les plus fréquentes
+const TOP_VALUES_CAPACITY: usize = 64;
+
+/// Nombre de valeurs les plus fréquentes rapportées
+const TOP_K: usize = 5;
+
+/// Valeurs les plus fréquentes, par l'algorithme Space-Saving : les comptes sont exacts tant que
+/// la colonne a moins de `TOP_VALUES_CAPACITY` valeurs distinctes, surestimés au-delà
+#[derive(Debug, Clone, Default)]
+struct TopValues {
+    counts: HashMap<String, usize>,
+}
+
+impl TopValues {
+    fn insert(&mut self, value: &str) {
+        if let Some(count) = self.counts.get_mut(value) {
+            *count += 1;
+            return;
+        }
+        if self.counts.len() < TOP_VALUES_CAPACITY {
+            self.counts.insert(value.to_string(), 1);
+            return;
+        }
+        // La valeur la moins fréquente cède sa place et lègue son compte
+        let Some((evicted, min_count)) = self.counts.iter()
+            .min_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(value, &count)| (value.clone(), count))
+        else {
+            return;
+        };
+        self.counts.remove(&evicted);
+        self.counts.insert(value.to_string(), min_count + 1);
+    }
+
+    fn top(&self, k: usize) -> Vec<(&str, usize)> {
+        let mut values: Vec<(&str, usize)> = self.counts.iter().map(|(value, &count)| (value.as_str(), count)).collect();
+        values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        values.truncate(k);
+        values
+    }
+}
+
+/// Moyenne et variance incrémentales (Welford), minimum et maximum
+#[derive(Debug, Clone, Default)]
+struct NumericSummary {
+    count: usize,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl NumericSummary {
+    fn push(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        } else {
+            self.min = self.min.min(value);
+            self.max = self.max.max(value);
+        }
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Écart type de la population
+    fn stddev(&self) -> f64 {
+        if self.count > 0 { (self.m2 / self.count as f64).sqrt() } else { 0.0 }
+    }
+}
+
+/// Nature d'un format temporel
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Temporal {
+    Date,
+    DateTime,
+    /// Date et heure avec fuseau (ISO 8601 / RFC 3339)
+    Zoned,
+}
+
+/// Formats temporels reconnus, par ordre de préférence (jour avant mois en cas d'ambiguïté)
+const TEMPORAL_FORMATS: [(&str, Temporal); 16] = [
+    ("%Y-%m-%d", Temporal::Date),
+    ("%Y/%m/%d", Temporal::Date),
+    ("%d/%m/%Y", Temporal::Date),
+    ("%m/%d/%Y", Temporal::Date),
+    ("%d.%m.%Y", Temporal::Date),
+    ("%d-%m-%Y", Temporal::Date),
+    ("%+", Temporal::Zoned),
+    ("%Y-%m-%dT%H:%M:%S%.f", Temporal::DateTime),
+    ("%Y-%m-%d %H:%M:%S%.f", Temporal::DateTime),
+    ("%Y-%m-%dT%H:%M", Temporal::DateTime),
+    ("%Y-%m-%d %H:%M", Temporal::DateTime),
+    ("%d/%m/%Y %H:%M:%S", Temporal::DateTime),
+    ("%m/%d/%Y %H:%M:%S", Temporal::DateTime),
+    ("%d/%m/%Y %H:%M", Temporal::DateTime),
+    ("%m/%d/%Y %H:%M", Temporal::DateTime),
+    ("%d.%m.%Y %H:%M", Temporal::DateTime),
+];
+
+/// Formats temporels de `TEMPORAL_FORMATS` acceptant la valeur, sous forme de masque
+fn temporal_mask(value: &str, candidates: u32) -> u32 {
+    if !value.starts_with(|c: char| c.is_ascii_digit()) {
+        return 0;
+    }
+    TEMPORAL_FORMATS.iter().enumerate()
+        .filter(|(index, _)| candidates & (1 << index) != 0)
+        .filter(|(_, (format, temporal))| match temporal {
+            Temporal::Date => NaiveDate::parse_from_str(value, format).is_ok(),
+            Temporal::DateTime => NaiveDateTime::parse_from_str(value, format).is_ok(),
+            Temporal::Zoned => DateTime::parse_from_str(value, format).is_ok(),
+        })
+        .fold(0, |mask, (index, _)| mask | (1 << index))
+}
+
+/// Entier au sens de `records::typed_value` : les zéros initiaux (`007`) en font un code
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    value.parse::<i64>().is_ok() && !(digits.len() > 1 && digits.starts_with('0'))
+}
+
+/// Nombre décimal fini (`inf`, `NaN` et les codes à zéros initiaux sont du texte)
+fn is_number(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let zero_padded = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    !zero_padded
+        && value.contains(|c: char| c.is_ascii_digit())
+        && value.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+fn is_boolean(value: &str) -> bool {
+    matches!(value.to_lowercase().as_str(), "true" | "false" | "yes" | "no")
+}
+
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !value.contains(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.split('.').count() >= 2
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+fn is_url(value: &str) -> bool {
+    let lower = value.to_lowercase();
+    ["http://", "https://", "ftp://"].iter().any(|scheme| lower.starts_with(scheme) && lower.len() > scheme.len())
+        && !value.contains(char::is_whitespace)
+}
+
+/// UUID sous sa forme canonique `8-4-4-4-12`
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// Nom de colonne désignant un identifiant (`id`, `user_id`, `orderId`, `uuid`)
+fn is_id_name(name: &str) -> bool {
+    let lower = name.trim().to_lowercase();
+    matches!(lower.as_str(), "id" | "uuid" | "guid")
+        || lower.ends_with("_id")
+        || lower.ends_with("-id")
+        || lower.ends_with(" id")
+        || (name.len() > 2 && name.ends_with("Id"))
+}
+
+/// Type inféré d'une colonne
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnType {
+    Integer,
+    Float,
+    Boolean,
+    Date,
+    Datetime,
+    Email,
+    Url,
+    /// Identifiant : UUID, ou valeurs toutes distinctes dans une colonne nommée `id`, `*_id`, ...
+    Id,
+    Categorical,
+    Text,
+    /// Aucune valeur renseignée
+    Empty,
+}
+
+/// Colonne du schéma inféré
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnSchema {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: ColumnType,
+    /// Format `strftime` des dates (`%d/%m/%Y`, `%+` pour ISO 8601 avec fuseau)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// Au moins une cellule vide
+    pub nullable: bool,
+}
+
+/// Schéma inféré d'un CSV, exportable en JSON
+///
+/// ```rust,no_run
+/// # use doc_loader::processors::profile::CsvSchema;
+/// # use doc_loader::UniversalProcessor;
+/// let output = UniversalProcessor::new().process_file(std::path::Path::new("orders.csv"), None)?;
+/// if let Some(schema) = CsvSchema::from_output(&output) {
+///     std::fs::write("orders.schema.json", serde_json::to_string_pretty(&schema)?)?;
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CsvSchema {
+    pub columns: Vec<ColumnSchema>,
+}
+
+impl CsvSchema {
+    /// Schéma placé par le processeur CSV dans `format_metadata["csv_metadata"]["schema"]`
+    pub fn from_output(output: &UniversalOutput) -> Option<Self> {
+        let schema = output.document_metadata.format_metadata.get("csv_metadata")?.get("schema")?;
+        serde_json::from_value(schema.clone()).ok()
+    }
+}
+
+/// Compteurs d'une colonne
+#[derive(Debug, Clone)]
+struct ColumnCounters {
+    non_empty_count: usize,
+    integer_count: usize,
+    number_count: usize,
+    boolean_count: usize,
+    email_count: usize,
+    url_count: usize,
+    uuid_count: usize,
+    /// Formats de `TEMPORAL_FORMATS` compatibles avec toutes les valeurs vues
+    temporal_formats: u32,
+    max_length: usize,
+    numeric: NumericSummary,
+    distinct: DistinctCounter,
+    top_values: TopValues,
+}
+
+impl ColumnCounters {
+    fn new() -> Self {
+        Self {
+            non_empty_count: 0,
+            integer_count: 0,
+            number_count: 0,
+            boolean_count: 0,
+            email_count: 0,
+            url_count: 0,
+            uuid_count: 0,
+            temporal_formats: (1 << TEMPORAL_FORMATS.len()) - 1,
+            max_length: 0,
+            numeric: NumericSummary::default(),
+            distinct: DistinctCounter::new(),
+            top_values: TopValues::default(),
+        }
+    }
+
+    fn push(&mut self, raw: &str) {
+        let value = raw.trim();
+        if value.is_empty() {
+            return;
+        }
+        self.non_empty_count += 1;
+        self.max_length = self.max_length.max(raw.len());
+        self.distinct.insert(raw);
+        self.top_values.insert(raw);
+
+        if is_number(value) {
+            self.number_count += 1;
+            self.integer_count += is_integer(value) as usize;
+            if let Ok(number) = value.parse::<f64>() {
+                self.numeric.push(number);
+            }
+        }
+        self.boolean_count += is_boolean(value) as usize;
+        self.email_count += is_email(value) as usize;
+        self.url_count += is_url(value) as usize;
+        self.uuid_count += is_uuid(value) as usize;
+        if self.temporal_formats != 0 {
+            self.temporal_formats = temporal_mask(value, self.temporal_formats);
+        }
+    }
+
+    /// Type et format éventuel, du plus spécifique au plus général
+    fn infer(&self, name: &str, total_rows: usize) -> (ColumnType, Option<&'static str>) {
+        let all = |count: usize| count == self.non_empty_count;
+        let unique = self.distinct.is_exact() && self.distinct.count() == self.non_empty_count;
+
+        if self.non_empty_count == 0 {
+            return (ColumnType::Empty, None);
+        }
+        if all(self.uuid_count) || (is_id_name(name) && unique && !all(self.boolean_count)) {
+            return (ColumnType::Id, None);
+        }
+        if all(self.boolean_count) {
+            return (ColumnType::Boolean, None);
+        }
+        if all(self.integer_count) {
+            return (ColumnType::Integer, None);
+        }
+        if all(self.number_count) {
+            return (ColumnType::Float, None);
+        }
+        if self.temporal_formats != 0 {
+            let (format, temporal) = TEMPORAL_FORMATS[self.temporal_formats.trailing_zeros() as usize];
+            let data_type = if temporal == Temporal::Date { ColumnType::Date } else { ColumnType::Datetime };
+            return (data_type, Some(format));
+        }
+        if all(self.email_count) {
+            return (ColumnType::Email, None);
+        }
+        if all(self.url_count) {
+            return (ColumnType::Url, None);
+        }
+        if self.distinct.count() <= 10 && total_rows > 10 {
+            return (ColumnType::Categorical, None);
+        }
+        (ColumnType::Text, None)
+    }
+}
+
+/// Statistiques des colonnes d'un CSV, alimentées ligne par ligne
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnStatistics {
+    columns: Vec<ColumnCounters>,
+    total_rows: usize,
+    completeness_sum: f64,
+}
+
+impl ColumnStatistics {
+    pub(crate) fn new(column_count: usize) -> Self {
+        Self {
+            columns: vec![ColumnCounters::new(); column_count],
+            total_rows: 0,
+            completeness_sum: 0.0,
+        }
+    }
+
+    pub(crate) fn push(&mut self, row: &[String]) {
+        self.total_rows += 1;
+        if !self.columns.is_empty() {
+            let filled = row.iter().filter(|cell| !cell.is_empty()).count();
+            self.completeness_sum += filled as f64 / self.columns.len() as f64;
+        }
+
+        for (counters, value) in self.columns.iter_mut().zip(row) {
+            counters.push(value);
+        }
+    }
+
+    pub(crate) fn total_rows(&self) -> usize {
+        self.total_rows
+    }
+
+    /// Part moyenne de cellules remplies par ligne
+    pub(crate) fn data_completeness(&self) -> f64 {
+        if self.total_rows > 0 { self.completeness_sum / self.total_rows as f64 } else { 0.0 }
+    }
+
+    /// Schéma inféré, dans l'ordre des en-têtes
+    pub(crate) fn schema(&self, headers: &[String]) -> CsvSchema {
+        CsvSchema {
+            columns: headers.iter().zip(&self.columns).map(|(header, counters)| {
+                let (data_type, format) = counters.infer(header, self.total_rows);
+                ColumnSchema {
+                    name: header.clone(),
+                    data_type,
+                    format: format.map(str::to_string),
+                    nullable: counters.non_empty_count < self.total_rows,
+                }
+            }).collect(),
+        }
+    }
+
+    /// Statistiques par colonne, dans l'ordre des en-têtes
+    pub(crate) fn to_json(&self, headers: &[String]) -> Vec<Value> {
+        let schema = self.schema(headers);
+        schema.columns.iter().zip(&self.columns).map(|(column, counters)| {
+            let mut statistics = json!({
+                "name": column.name,
+                "data_type": column.data_type,
+                "non_empty_count": counters.non_empty_count,
+                "fill_rate": if self.total_rows > 0 { counters.non_empty_count as f64 / self.total_rows as f64 } else { 0.0 },
+                "unique_values": counters.distinct.count(),
+                "unique_values_approximate": !counters.distinct.is_exact(),
+                "max_length": counters.max_length
+            });
+            if let Some(format) = &column.format {
+                statistics["format"] = json!(format);
+            }
+            if matches!(column.data_type, ColumnType::Integer | ColumnType::Float) {
+                let numeric = &counters.numeric;
+                statistics["min"] = json!(numeric.min);
+                statistics["max"] = json!(numeric.max);
+                statistics["mean"] = json!(numeric.mean);
+                statistics["stddev"] = json!(numeric.stddev());
+            }
+            if matches!(column.data_type, ColumnType::Categorical | ColumnType::Boolean) {
+                statistics["top_values"] = counters.top_values.top(TOP_K).into_iter()
+                    .map(|(value, count)| json!({ "value": value, "count": count }))
+                    .collect();
+            }
+            statistics
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct_counter_exact() {
+        let mut counter = DistinctCounter::new();
+        for value in (0..1000).map(|i| format!("value-{}", i % 50)) {
+            counter.insert(&value);
+        }
+        assert!(counter.is_exact());
+        assert_eq!(counter.count(), 50);
+    }
+
+    #[test]
+    fn test_distinct_counter_estimate() {
+        let mut counter = DistinctCounter::new();
+        for i in 0..100_000 {
+            counter.insert(&format!("user-{}@example.com", i));
+        }
+        assert!(!counter.is_exact());
+        let error = (counter.count() as f64 - 100_000.0).abs() / 100_000.0;
+        assert!(error < 0.05, "estimate {} too far from 100000", counter.count());
+    }
+
+    #[test]
+    fn test_column_statistics() {
+        let mut statistics = ColumnStatistics::new(2);
+        statistics.push(&["1".to_string(), "Ada".to_string()]);
+        statistics.push(&["2".to_string(), String::new()]);
+
+        let headers = ["count".to_string(), "name".to_string()];
+        let columns = statistics.to_json(&headers);
+        assert_eq!(statistics.total_rows(), 2);
+        assert_eq!(statistics.data_completeness(), 0.75);
+        assert_eq!(columns[0]["data_type"], "integer");
+        assert_eq!(columns[0]["mean"], 1.5);
+        assert_eq!(columns[0]["stddev"], 0.5);
+        assert_eq!(columns[1]["fill_rate"], 0.5);
+        assert_eq!(columns[1]["unique_values_approximate"], false);
+    }
+
+    #[test]
+    fn test_type_inference() {
+        let headers: Vec<String> = ["order_id", "ref", "paid", "amount", "day", "shipped_at", "placed_at", "email", "site", "status", "zip"]
+            .iter().map(|header| header.to_string()).collect();
+        let mut statistics = ColumnStatistics::new(headers.len());
+        for i in 0..12 {
+            let row = [
+                format!("{}", 1000 + i),
+                format!("6f1c2a4e-0b7d-4c1e-9a3b-{:012x}", i),
+                if i % 2 == 0 { "true" } else { "False" }.to_string(),
+                format!("{}.{}", i * 3, i),
+                format!("{}/01/2024", 10 + i),
+                format!("2024-03-{:02} 08:30", i + 1),
+                format!("2024-03-{:02}T08:30:00Z", i + 1),
+                format!("user{}@example.org", i),
+                format!("https://example.org/items/{}", i),
+                ["open", "closed", "open", "pending"][i % 4].to_string(),
+                format!("0{}", 7500 + i),
+            ];
+            statistics.push(&row);
+        }
+
+        let schema = statistics.schema(&headers);
+        let types: Vec<ColumnType> = schema.columns.iter().map(|column| column.data_type).collect();
+        assert_eq!(types, vec![
+            ColumnType::Id, ColumnType::Id, ColumnType::Boolean, ColumnType::Float, ColumnType::Date,
+            ColumnType::Datetime, ColumnType::Datetime, ColumnType::Email, ColumnType::Url,
+            ColumnType::Categorical, ColumnType::Text,
+        ]);
+        assert_eq!(schema.columns[4].format.as_deref(), Some("%d/%m/%Y"));
+        assert_eq!(schema.columns[5].format.as_deref(), Some("%Y-%m-%d %H:%M"));
+        assert_eq!(schema.columns[6].format.as_deref(), Some("%+"));
+
+        let columns = statistics.to_json(&headers);
+        assert_eq!(columns[9]["top_values"][0], json!({ "value": "open", "count": 6 }));
+        assert_eq!(columns[3]["min"], 0.0);
+        assert_eq!(columns[3]["max"], 33.11);
+
+        let exported = serde_json::to_value(&schema).unwrap();
+        assert_eq!(exported["columns"][4], json!({ "name": "day", "type": "date", "format": "%d/%m/%Y", "nullable": false }));
+        assert_eq!(serde_json::from_value::<CsvSchema>(exported).unwrap(), schema);
+    }
+}
@@ -0,0 +1,314 @@
+//! Enregistrements structurés (lignes CSV, éléments de tableaux JSON) rendus en chunks autonomes.
+//!
+//! Une partie des champs forme le texte du chunk, éventuellement à travers un gabarit
+//! (`"{title} by {author} ({year})"`) ; les autres sont conservés comme métadonnées typées,
+//! exploitables comme filtres par les bases vectorielles.
+
+use crate::core::ProcessingWarning;
+use crate::utils::clean_text;
+
+use serde_json::{Map, Value};
+
+/// Nombre maximal d'avertissements détaillés ; les suivants sont seulement comptés
+const MAX_WARNINGS: usize = 1000;
+
+/// Champ d'un enregistrement, dans l'ordre de la source
+pub(crate) type Field = (String, Value);
+
+/// Valeur typée d'une cellule texte : nombre, booléen, `null` pour une cellule vide, sinon chaîne
+pub(crate) fn typed_value(raw: &str) -> Value {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Value::Null;
+    }
+    if let Ok(integer) = trimmed.parse::<i64>() {
+        // Les identifiants à zéros initiaux (`007`, codes postaux) restent des chaînes
+        if !(trimmed.len() > 1 && trimmed.starts_with('0')) {
+            return Value::from(integer);
+        }
+    }
+    if trimmed.contains('.') && !trimmed.starts_with('.') && !trimmed.ends_with('.') {
+        if let Some(number) = trimmed.parse::<f64>().ok().and_then(serde_json::Number::from_f64) {
+            return Value::Number(number);
+        }
+    }
+    match trimmed.to_lowercase().as_str() {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+/// Champs retenus : ceux de `include` (tous si la liste est vide), moins ceux de `exclude`
+pub(crate) fn select_fields(fields: Vec<Field>, include: &[String], exclude: &[String]) -> Vec<Field> {
+    fields.into_iter()
+        .filter(|(name, _)| include.is_empty() || include.contains(name))
+        .filter(|(name, _)| !exclude.contains(name))
+        .collect()
+}
+
+/// Sépare les champs du texte (dans l'ordre de `text_fields`, ou tous si la liste est vide)
+/// des champs conservés en métadonnées
+pub(crate) fn split_fields(fields: Vec<Field>, text_fields: &[String]) -> (Vec<Field>, Map<String, Value>) {
+    if text_fields.is_empty() {
+        return (fields, Map::new());
+    }
+
+    let mut metadata = Map::new();
+    let mut text: Vec<Option<Field>> = vec![None; text_fields.len()];
+    for (name, value) in fields {
+        match text_fields.iter().position(|field| *field == name) {
+            Some(position) => text[position] = Some((name, value)),
+            None => {
+                metadata.insert(name, value);
+            },
+        }
+    }
+    (text.into_iter().flatten().collect(), metadata)
+}
+
+/// Rendu d'une valeur dans le texte d'un chunk (chaînes sans guillemets, listes de scalaires
+/// séparées par des virgules)
+pub(crate) fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        Value::Array(items) if items.iter().all(|item| !item.is_array() && !item.is_object()) => {
+            items.iter().map(value_to_text).collect::<Vec<_>>().join(", ")
+        },
+        other => other.to_string(),
+    }
+}
+
+/// Valeur désignée par un chemin pointé (`author.name`, `tags.0`) dans un objet JSON
+pub(crate) fn lookup_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(value, |current, segment| match current {
+        Value::Object(object) => object.get(segment),
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|index| items.get(index)),
+        _ => None,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TemplatePart {
+    Literal(String),
+    Field { name: String, default: Option<String> },
+}
+
+/// Gabarit de rendu d'un enregistrement : `{champ}`, `{champ|valeur par défaut}`, chemins
+/// pointés (`{author.name}`) ; `{{` et `}}` produisent des accolades littérales
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Template {
+    parts: Vec<TemplatePart>,
+}
+
+impl Template {
+    /// Une accolade non refermée est conservée telle quelle
+    pub(crate) fn parse(template: &str) -> Self {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+
+        while let Some(position) = rest.find(['{', '}']) {
+            literal.push_str(&rest[..position]);
+            let brace = &rest[position..];
+            if brace.starts_with("{{") || brace.starts_with("}}") {
+                literal.push_str(&brace[..1]);
+                rest = &brace[2..];
+                continue;
+            }
+            match (brace.starts_with('{'), brace.find('}')) {
+                (true, Some(end)) => {
+                    let (name, default) = match brace[1..end].split_once('|') {
+                        Some((name, default)) => (name, Some(default.to_string())),
+                        None => (&brace[1..end], None),
+                    };
+                    if !literal.is_empty() {
+                        parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(TemplatePart::Field { name: name.trim().to_string(), default });
+                    rest = &brace[end + 1..];
+                },
+                _ => {
+                    literal.push_str(&brace[..1]);
+                    rest = &brace[1..];
+                },
+            }
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(literal));
+        }
+        Self { parts }
+    }
+
+    /// Premier segment des champs référencés (`author` pour `{author.name}`)
+    pub(crate) fn fields(&self) -> Vec<&str> {
+        self.parts.iter()
+            .filter_map(|part| match part {
+                TemplatePart::Field { name, .. } => name.split('.').next(),
+                TemplatePart::Literal(_) => None,
+            })
+            .collect()
+    }
+
+    /// Rend le gabarit ; un champ absent ou vide prend sa valeur par défaut, sinon `missing_value`
+    pub(crate) fn render(&self, lookup: impl Fn(&str) -> Option<String>, missing_value: &str) -> String {
+        self.parts.iter()
+            .map(|part| match part {
+                TemplatePart::Literal(text) => text.clone(),
+                TemplatePart::Field { name, default } => lookup(name)
+                    .filter(|value| !value.is_empty())
+                    .or_else(|| default.clone())
+                    .unwrap_or_else(|| missing_value.to_string()),
+            })
+            .collect()
+    }
+}
+
+/// Texte et métadonnées d'un enregistrement. Avec un gabarit, les champs qu'il référence
+/// (ou ceux de `text_fields` s'ils sont précisés) sont exclus des métadonnées.
+pub(crate) fn render_record(
+    fields: Vec<Field>,
+    text_fields: &[String],
+    template: Option<&Template>,
+    lookup: impl Fn(&str) -> Option<String>,
+    missing_value: &str,
+    clean: bool,
+) -> (String, Map<String, Value>) {
+    let Some(template) = template else {
+        let (text_fields, metadata) = split_fields(fields, text_fields);
+        return (fields_to_text(&text_fields, clean), metadata);
+    };
+
+    let text = template.render(lookup, missing_value);
+    let used: Vec<&str> = if text_fields.is_empty() {
+        template.fields()
+    } else {
+        text_fields.iter().map(String::as_str).collect()
+    };
+    let metadata = fields.into_iter()
+        .filter(|(name, _)| !used.contains(&name.as_str()))
+        .collect();
+    (if clean { clean_text(&text) } else { text }, metadata)
+}
+
+/// Une ligne `champ: valeur` par champ non vide
+pub(crate) fn fields_to_text(fields: &[Field], clean: bool) -> String {
+    fields.iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(name, value)| {
+            let text = value_to_text(value);
+            format!("{}: {}", name, if clean { clean_text(&text) } else { text })
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Avertissements d'une lecture tolérante (enregistrements ignorés ou réparés, avec leur ligne)
+#[derive(Debug, Default)]
+pub(crate) struct RecordWarnings {
+    warnings: Vec<ProcessingWarning>,
+    skipped: usize,
+    repaired: usize,
+}
+
+impl RecordWarnings {
+    pub(crate) fn skipped(&mut self, line: u64, reason: String) {
+        self.skipped += 1;
+        self.push(line, format!("Record skipped: {}", reason));
+    }
+
+    pub(crate) fn repaired(&mut self, line: u64, reason: String) {
+        self.repaired += 1;
+        self.push(line, format!("Record repaired: {}", reason));
+    }
+
+    fn push(&mut self, line: u64, message: String) {
+        if self.warnings.len() < MAX_WARNINGS {
+            self.warnings.push(ProcessingWarning { line: Some(line), message });
+        }
+    }
+
+    pub(crate) fn skipped_count(&self) -> usize {
+        self.skipped
+    }
+
+    pub(crate) fn repaired_count(&self) -> usize {
+        self.repaired
+    }
+
+    /// Avertissements détaillés, suivis du nombre de problèmes non listés au-delà de `MAX_WARNINGS`
+    pub(crate) fn into_warnings(mut self) -> Vec<ProcessingWarning> {
+        let omitted = self.skipped + self.repaired - self.warnings.len();
+        if omitted > 0 {
+            self.warnings.push(ProcessingWarning { line: None, message: format!("{} more problems not listed", omitted) });
+        }
+        self.warnings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_typed_value() {
+        assert_eq!(typed_value("42"), json!(42));
+        assert_eq!(typed_value("54.99"), json!(54.99));
+        assert_eq!(typed_value("TRUE"), json!(true));
+        assert_eq!(typed_value("  "), Value::Null);
+        assert_eq!(typed_value("00742"), json!("00742"));
+        assert_eq!(typed_value("23.5 x 18.7 cm"), json!("23.5 x 18.7 cm"));
+    }
+
+    #[test]
+    fn test_split_fields() {
+        let fields = vec![
+            ("isbn".to_string(), json!("9780134685991")),
+            ("title".to_string(), json!("Effective Java")),
+            ("price".to_string(), json!(54.99)),
+            ("author".to_string(), json!("Joshua Bloch")),
+        ];
+
+        let (all, metadata) = split_fields(fields.clone(), &[]);
+        assert_eq!(all.len(), 4);
+        assert!(metadata.is_empty());
+
+        let (text, metadata) = split_fields(fields, &["author".to_string(), "title".to_string(), "missing".to_string()]);
+        assert_eq!(fields_to_text(&text, true), "author: Joshua Bloch\ntitle: Effective Java");
+        assert_eq!(Value::Object(metadata), json!({ "isbn": "9780134685991", "price": 54.99 }));
+    }
+
+    #[test]
+    fn test_template() {
+        let template = Template::parse("{title} by {author.name} ({year|n.d.}){{{edition}}}: {summary");
+        assert_eq!(template.fields(), vec!["title", "author", "year", "edition"]);
+
+        let record = json!({ "title": "Clean Code", "author": { "name": "Robert C. Martin" }, "year": "" });
+        let text = template.render(|name| lookup_path(&record, name).map(value_to_text), "?");
+        assert_eq!(text, "Clean Code by Robert C. Martin (n.d.){?}: {summary");
+    }
+
+    #[test]
+    fn test_render_record() {
+        let fields = vec![
+            ("title".to_string(), json!("Effective Java")),
+            ("tags".to_string(), json!(["java", "jvm"])),
+            ("price".to_string(), json!(54.99)),
+        ];
+        let fields = select_fields(fields, &[], &["price".to_string()]);
+        let template = Template::parse("{title} [{tags}]");
+        let record: Map<String, Value> = fields.iter().cloned().collect();
+
+        let lookup = |name: &str| record.get(name).map(value_to_text);
+        let (text, metadata) = render_record(fields.clone(), &[], Some(&template), lookup, "", true);
+        assert_eq!(text, "Effective Java [java, jvm]");
+        assert!(metadata.is_empty());
+
+        let (text, metadata) = render_record(fields, &["title".to_string()], None, lookup, "", true);
+        assert_eq!(text, "title: Effective Java");
+        assert_eq!(Value::Object(metadata), json!({ "tags": ["java", "jvm"] }));
+    }
+}
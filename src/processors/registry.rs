@@ -0,0 +1,297 @@
+//! Registre des processeurs : associe chaque type de document à son processeur, et les
+//! extensions, types MIME et sniffers à un type de document.
+//!
+//! Les processeurs intégrés sont enregistrés par `ProcessorRegistry::with_builtins` ; une
+//! application peut y ajouter ses propres formats (`DocumentType::Custom`) ou remplacer un
+//! processeur intégré en enregistrant un processeur du même type.
+
+use crate::core::DocumentType;
+use crate::error::DocLoaderError;
+use crate::processors::*;
+
+use std::collections::HashMap;
+
+/// Reconnaît un format à partir des premiers octets du contenu
+pub type Sniffer = Box<dyn Fn(&[u8]) -> bool + Send + Sync>;
+
+/// Registre des processeurs de documents
+pub struct ProcessorRegistry {
+    processors: HashMap<DocumentType, Box<dyn DocumentProcessor + Send + Sync>>,
+    extensions: HashMap<String, DocumentType>,
+    mime_types: HashMap<String, DocumentType>,
+    sniffers: Vec<(DocumentType, Sniffer)>,
+}
+
+impl ProcessorRegistry {
+    /// Registre vide
+    pub fn new() -> Self {
+        Self {
+            processors: HashMap::new(),
+            extensions: HashMap::new(),
+            mime_types: HashMap::new(),
+            sniffers: Vec::new(),
+        }
+    }
+
+    /// Registre contenant tous les processeurs intégrés
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register(pdf::PdfProcessor::new()).extensions(&["pdf"]).mime_types(&["application/pdf"]);
+        registry.register(txt::TxtProcessor::new()).extensions(&["txt"]).mime_types(&["text/plain"]);
+        registry.register(json::JsonProcessor::new())
+            .extensions(&["json", "jsonl", "ndjson"])
+            .mime_types(&["application/json", "text/json", "application/x-ndjson", "application/jsonl"]);
+        registry.register(csv::CsvProcessor::new())
+            .extensions(&["csv", "tsv"])
+            .mime_types(&["text/csv", "application/csv", "text/tab-separated-values"]);
+        registry.register(docx::DocxProcessor::new())
+            .extensions(&["docx"])
+            .mime_types(&["application/vnd.openxmlformats-officedocument.wordprocessingml.document"]);
+        registry.register(pptx::PptxProcessor::new())
+            .extensions(&["pptx"])
+            .mime_types(&["application/vnd.openxmlformats-officedocument.presentationml.presentation"]);
+        registry.register(odf::OdtProcessor::new()).extensions(&["odt"]).mime_types(&["application/vnd.oasis.opendocument.text"]);
+        registry.register(odf::OdsProcessor::new()).extensions(&["ods"]).mime_types(&["application/vnd.oasis.opendocument.spreadsheet"]);
+        registry.register(odf::OdpProcessor::new()).extensions(&["odp"]).mime_types(&["application/vnd.oasis.opendocument.presentation"]);
+        registry.register(epub::EpubProcessor::new()).extensions(&["epub"]).mime_types(&["application/epub+zip"]);
+        registry.register(rtf::RtfProcessor::new()).extensions(&["rtf"]).mime_types(&["application/rtf", "text/rtf"]);
+        registry.register(email::EmlProcessor::new()).extensions(&["eml"]).mime_types(&["message/rfc822"]);
+        registry.register(email::MboxProcessor::new()).extensions(&["mbox"]).mime_types(&["application/mbox"]);
+        registry.register(xml::XmlProcessor::new()).extensions(&["xml"]).mime_types(&["application/xml", "text/xml"]);
+        registry.register(config::YamlProcessor::new())
+            .extensions(&["yaml", "yml"])
+            .mime_types(&["application/yaml", "application/x-yaml", "text/yaml", "text/x-yaml"]);
+        registry.register(config::TomlProcessor::new()).extensions(&["toml"]).mime_types(&["application/toml"]);
+        registry.register(code::CodeProcessor::new())
+            .extensions(&["rs", "py", "pyw", "js", "jsx", "mjs", "cjs", "ts", "tsx", "go", "java", "kt", "kts", "scala", "swift",
+                "c", "h", "cpp", "cc", "cxx", "hpp", "hh", "hxx", "cs", "php", "rb", "sh", "bash"])
+            .mime_types(&["text/x-rust", "text/x-python", "text/javascript", "application/javascript", "text/x-c", "text/x-java"]);
+        registry.register(notebook::NotebookProcessor::new()).extensions(&["ipynb"]).mime_types(&["application/x-ipynb+json"]);
+        registry.register(subtitle::SrtProcessor::new()).extensions(&["srt"]).mime_types(&["application/x-subrip"]);
+        registry.register(subtitle::VttProcessor::new()).extensions(&["vtt"]).mime_types(&["text/vtt"]);
+        registry.register(latex::LatexProcessor::new()).extensions(&["tex", "ltx"]).mime_types(&["application/x-tex", "text/x-tex"]);
+        registry
+    }
+
+    /// Enregistre un processeur pour son `supported_type()`, en remplaçant le processeur existant.
+    ///
+    /// ```rust
+    /// # use doc_loader::processors::registry::ProcessorRegistry;
+    /// # use doc_loader::processors::txt::TxtProcessor;
+    /// let mut registry = ProcessorRegistry::with_builtins();
+    /// registry.register(TxtProcessor::new())
+    ///     .extensions(&["log", "md"])
+    ///     .mime_types(&["text/markdown"]);
+    /// ```
+    pub fn register<P>(&mut self, processor: P) -> Registration<'_>
+    where
+        P: DocumentProcessor + Send + Sync + 'static,
+    {
+        let doc_type = processor.supported_type();
+        self.processors.insert(doc_type.clone(), Box::new(processor));
+        Registration { registry: self, doc_type }
+    }
+
+    /// Processeur enregistré pour un type de document
+    pub fn processor(&self, doc_type: &DocumentType) -> Result<&dyn DocumentProcessor, DocLoaderError> {
+        self.processors.get(doc_type)
+            .map(|processor| processor.as_ref() as &dyn DocumentProcessor)
+            .ok_or_else(|| DocLoaderError::UnsupportedFormat(format!("No processor registered for {}", doc_type.to_string())))
+    }
+
+    /// Type de document associé à une extension (sans le point, insensible à la casse)
+    pub fn type_for_extension(&self, extension: &str) -> Option<DocumentType> {
+        self.extensions.get(&extension.to_lowercase()).cloned()
+    }
+
+    /// Type de document associé à un type MIME (les paramètres `; charset=...` sont ignorés)
+    pub fn type_for_mime_type(&self, mime_type: &str) -> Option<DocumentType> {
+        let essence = mime_type.split(';').next().unwrap_or_default().trim().to_lowercase();
+        self.mime_types.get(&essence).cloned()
+    }
+
+    /// Type reconnu par le premier sniffer qui accepte le contenu (les derniers enregistrés d'abord)
+    pub fn sniff(&self, head: &[u8]) -> Option<DocumentType> {
+        self.sniffers.iter()
+            .rev()
+            .find(|(_, sniffer)| sniffer(head))
+            .map(|(doc_type, _)| doc_type.clone())
+    }
+
+    /// Extensions enregistrées, triées
+    pub fn extensions(&self) -> Vec<String> {
+        let mut extensions: Vec<String> = self.extensions.keys().cloned().collect();
+        extensions.sort();
+        extensions
+    }
+}
+
+impl Default for ProcessorRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+/// Associe des extensions, types MIME et sniffers au processeur qui vient d'être enregistré
+pub struct Registration<'a> {
+    registry: &'a mut ProcessorRegistry,
+    doc_type: DocumentType,
+}
+
+impl Registration<'_> {
+    pub fn extensions(self, extensions: &[&str]) -> Self {
+        for extension in extensions {
+            let extension = extension.trim_start_matches('.').to_lowercase();
+            self.registry.extensions.insert(extension, self.doc_type.clone());
+        }
+        self
+    }
+
+    pub fn mime_types(self, mime_types: &[&str]) -> Self {
+        for mime_type in mime_types {
+            self.registry.mime_types.insert(mime_type.to_lowercase(), self.doc_type.clone());
+        }
+        self
+    }
+
+    /// Le sniffer reçoit les premiers octets du contenu (au plus 8 Kio pour un fichier)
+    pub fn sniffer(self, sniffer: impl Fn(&[u8]) -> bool + Send + Sync + 'static) -> Self {
+        self.registry.sniffers.push((self.doc_type.clone(), Box::new(sniffer)));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{DocumentMetadata, ProcessingInfo, ProcessingParams, UniversalOutput};
+    use chrono::Utc;
+    use std::path::Path;
+
+    /// Processeur minimal renvoyant le nom du format dans ses métadonnées
+    struct AcmeProcessor {
+        doc_type: DocumentType,
+    }
+
+    impl DocumentProcessor for AcmeProcessor {
+        fn supported_type(&self) -> DocumentType {
+            self.doc_type.clone()
+        }
+
+        fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+            let content = std::fs::read(file_path)?;
+            self.process_content(&content, &file_path.to_string_lossy(), params)
+        }
+
+        fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+            Ok(UniversalOutput {
+                document_metadata: DocumentMetadata {
+                    filename: filename.to_string(),
+                    filepath: format!("memory://{}", filename),
+                    document_type: self.doc_type.clone(),
+                    file_size: content.len() as u64,
+                    created_at: None,
+                    modified_at: None,
+                    title: None,
+                    author: None,
+                    format_metadata: serde_json::json!({}),
+                },
+                chunks: Vec::new(),
+                processing_info: ProcessingInfo {
+                    processor: "AcmeProcessor".to_string(),
+                    processor_version: self.version().to_string(),
+                    processed_at: Utc::now(),
+                    processing_time_ms: 0,
+                    total_chunks: 0,
+                    total_content_size: content.len(),
+                    processing_params: params.clone(),
+                    warnings: Vec::new(),
+                },
+            })
+        }
+    }
+
+    fn acme() -> AcmeProcessor {
+        AcmeProcessor { doc_type: DocumentType::Custom("ACME".to_string()) }
+    }
+
+    #[test]
+    fn test_builtins() {
+        let registry = ProcessorRegistry::with_builtins();
+        assert_eq!(registry.type_for_extension("PDF"), Some(DocumentType::PDF));
+        assert_eq!(registry.type_for_extension("yml"), Some(DocumentType::YAML));
+        assert_eq!(registry.type_for_mime_type("text/csv; charset=utf-8"), Some(DocumentType::CSV));
+        assert_eq!(registry.processor(&DocumentType::TEX).unwrap().supported_type(), DocumentType::TEX);
+        assert!(registry.processor(&DocumentType::Custom("ACME".to_string())).is_err());
+        for extension in registry.extensions() {
+            assert_eq!(registry.type_for_extension(&extension), DocumentType::from_extension(&extension), "{}", extension);
+        }
+    }
+
+    #[test]
+    fn test_register_custom_format() {
+        let mut registry = ProcessorRegistry::with_builtins();
+        registry.register(acme())
+            .extensions(&[".acme"])
+            .mime_types(&["application/x-acme"])
+            .sniffer(|head| head.starts_with(b"ACME1"));
+
+        let acme_type = DocumentType::Custom("ACME".to_string());
+        assert_eq!(registry.type_for_extension("acme"), Some(acme_type.clone()));
+        assert_eq!(registry.type_for_mime_type("application/x-acme"), Some(acme_type.clone()));
+        assert_eq!(registry.sniff(b"ACME1 payload"), Some(acme_type.clone()));
+        assert_eq!(registry.sniff(b"%PDF-1.7"), None);
+        assert_eq!(registry.processor(&acme_type).unwrap().supported_type().to_string(), "ACME");
+    }
+
+    #[test]
+    fn test_universal_processor_dispatch() {
+        let dir = std::env::temp_dir().join(format!("doc_loader_registry_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let by_extension = dir.join("report.acme");
+        let by_signature = dir.join("export");
+        std::fs::write(&by_extension, "plain text").unwrap();
+        std::fs::write(&by_signature, "ACME1 payload").unwrap();
+
+        let mut processor = UniversalProcessor::new();
+        processor.register(acme())
+            .extensions(&["acme"])
+            .sniffer(|head| head.starts_with(b"ACME1"));
+
+        let outputs = [
+            processor.process_file(&by_extension, None),
+            processor.process_file(&by_signature, None),
+            processor.process_content(b"ACME1 in memory", "upload.bin", None),
+        ];
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        for output in outputs {
+            assert_eq!(output.unwrap().document_metadata.document_type, DocumentType::Custom("ACME".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_override_builtin() {
+        let mut processor = UniversalProcessor::new();
+        processor.register(AcmeProcessor { doc_type: DocumentType::TXT });
+
+        let output = processor.process_content(b"hello", "notes.txt", None).unwrap();
+        assert_eq!(output.processing_info.processor, "AcmeProcessor");
+    }
+
+    #[test]
+    fn test_declared_mime_type() {
+        let processor = UniversalProcessor::new();
+        let content = b"id;name\n1;Ada\n";
+
+        // Le type MIME déclaré l'emporte sur l'extension
+        let by_extension = processor.process_content(content, "upload.txt", None).unwrap();
+        assert_eq!(by_extension.document_metadata.document_type, DocumentType::TXT);
+        let declared = processor.process_content_with_mime(content, "upload.txt", "text/csv; charset=utf-8", None).unwrap();
+        assert_eq!(declared.document_metadata.document_type, DocumentType::CSV);
+
+        // Un type MIME inconnu laisse la détection habituelle décider
+        let unknown = processor.process_content_with_mime(content, "upload.txt", "application/x-unknown", None).unwrap();
+        assert_eq!(unknown.document_metadata.document_type, DocumentType::TXT);
+    }
+}
@@ -0,0 +1,672 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata, normalize_line_breaks};
+
+use std::path::Path;
+use std::fs;
+use chrono::{DateTime, NaiveDate, Utc};
+use encoding_rs::{Encoding, WINDOWS_1252};
+use serde_json::{json, Map, Value};
+
+/// Profondeur maximale d'imbrication des groupes
+const MAX_GROUP_DEPTH: usize = 1024;
+
+/// Destinations dont le contenu n'est pas du texte du document
+const SKIPPED_DESTINATIONS: &[&str] = &[
+    "fonttbl", "colortbl", "stylesheet", "listtable", "listoverridetable", "revtbl", "rsidtbl",
+    "filetbl", "pict", "object", "objdata", "themedata", "colorschememapping", "datastore",
+    "latentstyles", "generator", "xmlnstbl", "mmathPr", "fldinst", "pgdsctbl", "header", "headerl",
+    "headerr", "headerf", "footer", "footerl", "footerr", "footerf", "bkmkstart", "bkmkend",
+];
+
+/// Champs textuels du groupe `\info`
+const INFO_FIELDS: &[&str] = &[
+    "title", "subject", "author", "manager", "company", "operator", "category", "keywords",
+    "comment", "doccomm", "hlinkbase",
+];
+
+/// Champs date du groupe `\info`
+const INFO_DATES: &[&str] = &["creatim", "revtim", "printim", "buptim"];
+
+/// Compteurs numériques du groupe `\info`
+const INFO_STATISTICS: &[&str] = &["version", "edmins", "nofpages", "nofwords", "nofchars", "nofcharsws"];
+
+pub struct RtfProcessor;
+
+impl RtfProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lit et convertit un fichier RTF
+    fn read_rtf_file(&self, file_path: &Path) -> Result<RtfDocument, DocLoaderError> {
+        let bytes = fs::read(file_path)?;
+        parse_rtf(&bytes)
+    }
+
+    /// Construit la sortie universelle à partir du document RTF
+    fn build_output(
+        &self,
+        rtf: &RtfDocument,
+        document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let raw_text = normalize_line_breaks(&rtf.text);
+
+        // Nettoyer le texte si demandé
+        let text = if params.text_cleaning {
+            clean_text(&raw_text)
+        } else {
+            raw_text
+        };
+
+        // Découper en chunks
+        let chunks_text = chunk_text(&text, params.max_chunk_size, params.chunk_overlap);
+
+        // Créer les chunks avec métadonnées
+        let mut chunks = Vec::new();
+        let mut current_line = 0;
+
+        for (index, chunk_text) in chunks_text.iter().enumerate() {
+            let text_meta = extract_text_metadata(chunk_text);
+            let chunk_lines = chunk_text.lines().count();
+
+            let start_offset = if index == 0 {
+                0
+            } else {
+                text.find(chunk_text).unwrap_or(0)
+            };
+
+            chunks.push(DocumentChunk {
+                id: format!("rtf_chunk_{}", index),
+                content: chunk_text.clone(),
+                chunk_index: index,
+                position: ChunkPosition {
+                    page: None,
+                    line: Some(current_line as u32),
+                    start_offset: Some(start_offset),
+                    end_offset: Some(start_offset + chunk_text.len()),
+                },
+                metadata: ChunkMetadata {
+                    size: chunk_text.len(),
+                    language: if params.language_detection { text_meta.detected_language } else { None },
+                    confidence: Some(0.95), // Les objets incorporés (images, OLE) sont ignorés
+                    format_specific: Some(json!({
+                        "word_count": text_meta.word_count,
+                        "line_count": chunk_lines,
+                        "estimated_tokens": text_meta.estimated_tokens
+                    })),
+                },
+            });
+            current_line += chunk_lines;
+        }
+
+        let text_meta = extract_text_metadata(&text);
+        let info = &rtf.info;
+        let format_metadata = json!({
+            "rtf_metadata": {
+                "codepage": rtf.codepage,
+                "encoding": rtf.encoding.name(),
+                "subject": info.fields.get("subject"),
+                "keywords": info.fields.get("keywords"),
+                "company": info.fields.get("company"),
+                "operator": info.fields.get("operator"),
+                "comment": info.fields.get("comment").or_else(|| info.fields.get("doccomm")),
+                "created": info.created.map(|date| date.to_rfc3339()),
+                "revised": info.revised.map(|date| date.to_rfc3339()),
+                "printed": info.printed.map(|date| date.to_rfc3339()),
+                "statistics": info.statistics
+            },
+            "extracted_text_metadata": {
+                "total_words": text_meta.word_count,
+                "total_characters": text_meta.character_count,
+                "total_lines": text_meta.line_count,
+                "estimated_tokens": text_meta.estimated_tokens,
+                "detected_language": text_meta.detected_language
+            }
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "RtfProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: text.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+/// Document RTF converti
+#[derive(Debug, Clone)]
+pub struct RtfDocument {
+    pub text: String,
+    pub info: RtfInfo,
+    /// Page de code déclarée par `\ansicpgN` (1252 par défaut)
+    pub codepage: u32,
+    pub encoding: &'static Encoding,
+}
+
+/// Contenu du groupe `\info`
+#[derive(Debug, Clone, Default)]
+pub struct RtfInfo {
+    pub fields: Map<String, Value>,
+    pub created: Option<DateTime<Utc>>,
+    pub revised: Option<DateTime<Utc>>,
+    pub printed: Option<DateTime<Utc>>,
+    pub statistics: Map<String, Value>,
+}
+
+impl RtfInfo {
+    fn field(&self, name: &str) -> Option<String> {
+        self.fields.get(name).and_then(|value| value.as_str()).map(|value| value.to_string())
+    }
+}
+
+/// Jeton lexical RTF
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    GroupStart,
+    GroupEnd,
+    ControlWord(String, Option<i32>),
+    ControlSymbol(char),
+    /// Octet `\'hh`, à décoder avec la page de code
+    Hex(u8),
+    /// Texte littéral (octets hors caractères spéciaux)
+    Text(Vec<u8>),
+}
+
+/// Découpe un flux RTF en jetons
+fn tokenize(input: &[u8]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < input.len() {
+        match input[i] {
+            b'{' => {
+                tokens.push(Token::GroupStart);
+                i += 1;
+            },
+            b'}' => {
+                tokens.push(Token::GroupEnd);
+                i += 1;
+            },
+            b'\r' | b'\n' => i += 1,
+            b'\\' => {
+                i += 1;
+                let Some(&next) = input.get(i) else { break };
+
+                if next.is_ascii_alphabetic() {
+                    let start = i;
+                    while i < input.len() && input[i].is_ascii_alphabetic() && i - start < 32 {
+                        i += 1;
+                    }
+                    let name = String::from_utf8_lossy(&input[start..i]).into_owned();
+
+                    let param_start = i;
+                    if input.get(i) == Some(&b'-') {
+                        i += 1;
+                    }
+                    while i < input.len() && input[i].is_ascii_digit() && i - param_start < 11 {
+                        i += 1;
+                    }
+                    let param = std::str::from_utf8(&input[param_start..i]).ok().and_then(|p| p.parse::<i32>().ok());
+                    if param.is_none() {
+                        i = param_start;
+                    }
+
+                    // Un espace après un mot de contrôle est un délimiteur
+                    if input.get(i) == Some(&b' ') {
+                        i += 1;
+                    }
+
+                    // `\binN` est suivi de N octets bruts
+                    if name == "bin" {
+                        i += param.unwrap_or(0).max(0) as usize;
+                        continue;
+                    }
+
+                    tokens.push(Token::ControlWord(name, param));
+                } else if next == b'\'' {
+                    let byte = input.get(i + 1..i + 3)
+                        .and_then(|hex| std::str::from_utf8(hex).ok())
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                    match byte {
+                        Some(byte) => {
+                            tokens.push(Token::Hex(byte));
+                            i += 3;
+                        },
+                        None => i += 1,
+                    }
+                } else if next == b'\r' || next == b'\n' {
+                    // Un saut de ligne échappé équivaut à `\par`
+                    tokens.push(Token::ControlWord("par".to_string(), None));
+                    i += 1;
+                } else {
+                    tokens.push(Token::ControlSymbol(next as char));
+                    i += 1;
+                }
+            },
+            _ => {
+                let start = i;
+                while i < input.len() && !matches!(input[i], b'{' | b'}' | b'\\' | b'\r' | b'\n') {
+                    i += 1;
+                }
+                tokens.push(Token::Text(input[start..i].to_vec()));
+            },
+        }
+    }
+
+    tokens
+}
+
+/// Destination courante d'un groupe
+#[derive(Debug, Clone, PartialEq)]
+enum Destination {
+    Text,
+    Skip,
+    /// Groupe `\info` lui-même
+    Info,
+    /// Champ texte du groupe `\info` (`\title`, `\author`, ...)
+    InfoField(String),
+    /// Champ date du groupe `\info` (`\creatim`, ...)
+    InfoDate(String),
+}
+
+#[derive(Debug, Clone)]
+struct GroupState {
+    destination: Destination,
+    /// Nombre de caractères de repli après `\uN` (`\ucN`)
+    unicode_skip: usize,
+}
+
+/// Composants d'une date `\yr \mo \dy \hr \min \sec`
+#[derive(Debug, Default)]
+struct DateParts {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+impl DateParts {
+    fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        NaiveDate::from_ymd_opt(self.year, self.month, self.day)?
+            .and_hms_opt(self.hour, self.minute, self.second)
+            .map(|date| date.and_utc())
+    }
+}
+
+/// Encodage correspondant à une page de code Windows
+fn encoding_for_codepage(codepage: u32) -> &'static Encoding {
+    let label = match codepage {
+        65001 => "utf-8".to_string(),
+        932 => "shift_jis".to_string(),
+        936 => "gbk".to_string(),
+        949 => "euc-kr".to_string(),
+        950 => "big5".to_string(),
+        10000 => "macintosh".to_string(),
+        866 => "ibm866".to_string(),
+        874 => "windows-874".to_string(),
+        1250..=1258 => format!("windows-{}", codepage),
+        _ => return WINDOWS_1252,
+    };
+    Encoding::for_label(label.as_bytes()).unwrap_or(WINDOWS_1252)
+}
+
+/// Interprète les jetons RTF et produit le texte et les métadonnées du document
+fn parse_rtf(input: &[u8]) -> Result<RtfDocument, DocLoaderError> {
+    let start = input.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(0);
+    if !input[start..].starts_with(b"{\\rtf") {
+        return Err(DocLoaderError::InvalidFormat("Not an RTF document: missing {\\rtf header".to_string()));
+    }
+
+    let mut codepage = 1252;
+    let mut encoding = WINDOWS_1252;
+    let mut text = String::new();
+    let mut info = RtfInfo::default();
+
+    let mut stack: Vec<GroupState> = Vec::new();
+    let mut state = GroupState { destination: Destination::Text, unicode_skip: 1 };
+    let mut group_start = false;
+    let mut ignorable = false;
+
+    let mut pending: Vec<u8> = Vec::new();
+    let mut field_value = String::new();
+    let mut date = DateParts::default();
+    let mut fallback_to_skip = 0usize;
+    let mut high_surrogate: Option<u16> = None;
+
+    // Décode les octets en attente vers la destination courante
+    let flush = |pending: &mut Vec<u8>, target: &mut String, encoding: &'static Encoding| {
+        if !pending.is_empty() {
+            let (decoded, _, _) = encoding.decode(pending);
+            target.push_str(&decoded);
+            pending.clear();
+        }
+    };
+
+    for token in tokenize(input) {
+        let is_byte_token = matches!(token, Token::Hex(_) | Token::Text(_));
+        if !is_byte_token {
+            match &state.destination {
+                Destination::Text => flush(&mut pending, &mut text, encoding),
+                Destination::InfoField(_) => flush(&mut pending, &mut field_value, encoding),
+                _ => pending.clear(),
+            }
+        }
+
+        // Le premier mot de contrôle d'un groupe détermine sa destination
+        let at_group_start = group_start;
+        group_start = false;
+
+        match token {
+            Token::GroupStart => {
+                if stack.len() >= MAX_GROUP_DEPTH {
+                    return Err(DocLoaderError::InvalidFormat("RTF groups are nested too deeply".to_string()));
+                }
+                stack.push(state.clone());
+                group_start = true;
+                ignorable = false;
+            },
+            Token::GroupEnd => {
+                match &state.destination {
+                    Destination::InfoField(name) => {
+                        let value = field_value.trim().to_string();
+                        if !value.is_empty() {
+                            info.fields.insert(name.clone(), Value::String(value));
+                        }
+                        field_value.clear();
+                    },
+                    Destination::InfoDate(name) => {
+                        let parsed = date.to_datetime();
+                        match name.as_str() {
+                            "creatim" => info.created = parsed,
+                            "revtim" => info.revised = parsed,
+                            "printim" => info.printed = parsed,
+                            _ => {},
+                        }
+                        date = DateParts::default();
+                    },
+                    _ => {},
+                }
+                state = match stack.pop() {
+                    Some(previous) => previous,
+                    None => break,
+                };
+                fallback_to_skip = 0;
+            },
+            Token::ControlSymbol('*') if at_group_start => {
+                ignorable = true;
+                group_start = true;
+            },
+            Token::ControlWord(name, param) => {
+                let name = name.as_str();
+
+                if at_group_start && state.destination != Destination::Skip {
+                    if name == "info" {
+                        state.destination = Destination::Info;
+                        continue;
+                    }
+                    if state.destination == Destination::Info {
+                        if INFO_FIELDS.contains(&name) {
+                            state.destination = Destination::InfoField(name.to_string());
+                            field_value.clear();
+                            continue;
+                        }
+                        if INFO_DATES.contains(&name) {
+                            state.destination = Destination::InfoDate(name.to_string());
+                            continue;
+                        }
+                    }
+                    if SKIPPED_DESTINATIONS.contains(&name) || ignorable {
+                        state.destination = Destination::Skip;
+                        continue;
+                    }
+                }
+
+                match (&state.destination, name) {
+                    (Destination::Skip, _) => {},
+                    (_, "ansicpg") => {
+                        if let Some(cp) = param.filter(|cp| *cp > 0) {
+                            codepage = cp as u32;
+                            encoding = encoding_for_codepage(codepage);
+                        }
+                    },
+                    (_, "mac") => {
+                        codepage = 10000;
+                        encoding = encoding_for_codepage(codepage);
+                    },
+                    (_, "uc") => state.unicode_skip = param.unwrap_or(1).max(0) as usize,
+                    (Destination::Info, stat) if INFO_STATISTICS.contains(&stat) => {
+                        info.statistics.insert(stat.to_string(), json!(param.unwrap_or(0)));
+                    },
+                    (Destination::InfoDate(_), part) => {
+                        let value = param.unwrap_or(0).max(0);
+                        match part {
+                            "yr" => date.year = value,
+                            "mo" => date.month = value as u32,
+                            "dy" => date.day = value as u32,
+                            "hr" => date.hour = value as u32,
+                            "min" => date.minute = value as u32,
+                            "sec" => date.second = value as u32,
+                            _ => {},
+                        }
+                    },
+                    (Destination::Text | Destination::InfoField(_), "u") => {
+                        let code = param.unwrap_or(0);
+                        let code = if code < 0 { (code + 65536) as u32 } else { code as u32 };
+                        let target = if state.destination == Destination::Text { &mut text } else { &mut field_value };
+
+                        match code {
+                            0xD800..=0xDBFF => high_surrogate = Some(code as u16),
+                            0xDC00..=0xDFFF => {
+                                if let Some(high) = high_surrogate.take() {
+                                    let combined = 0x10000 + ((high as u32 - 0xD800) << 10) + (code - 0xDC00);
+                                    target.extend(char::from_u32(combined));
+                                }
+                            },
+                            _ => target.extend(char::from_u32(code)),
+                        }
+                        fallback_to_skip = state.unicode_skip;
+                    },
+                    (Destination::Text, word) => {
+                        let replacement = match word {
+                            "par" | "line" | "sect" | "page" => "\n",
+                            "tab" => "\t",
+                            "cell" | "nestcell" => " | ",
+                            "row" | "nestrow" => {
+                                while text.ends_with(" | ") {
+                                    text.truncate(text.len() - 3);
+                                }
+                                "\n"
+                            },
+                            "emdash" => "—",
+                            "endash" => "–",
+                            "bullet" => "•",
+                            "lquote" => "‘",
+                            "rquote" => "’",
+                            "ldblquote" => "“",
+                            "rdblquote" => "”",
+                            "emspace" | "enspace" | "qmspace" => " ",
+                            _ => "",
+                        };
+                        text.push_str(replacement);
+                    },
+                    _ => {},
+                }
+            },
+            Token::ControlSymbol(symbol) => {
+                let literal = match symbol {
+                    '\\' | '{' | '}' => Some(symbol),
+                    '~' => Some(' '),
+                    '_' => Some('-'),
+                    _ => None,
+                };
+                if let Some(c) = literal {
+                    match &state.destination {
+                        Destination::Text => text.push(c),
+                        Destination::InfoField(_) => field_value.push(c),
+                        _ => {},
+                    }
+                }
+            },
+            Token::Hex(byte) => {
+                if fallback_to_skip > 0 {
+                    fallback_to_skip -= 1;
+                } else if matches!(state.destination, Destination::Text | Destination::InfoField(_)) {
+                    pending.push(byte);
+                }
+            },
+            Token::Text(bytes) => {
+                let skip = fallback_to_skip.min(bytes.len());
+                fallback_to_skip -= skip;
+                if matches!(state.destination, Destination::Text | Destination::InfoField(_)) {
+                    pending.extend_from_slice(&bytes[skip..]);
+                }
+            },
+        }
+    }
+    flush(&mut pending, &mut text, encoding);
+
+    Ok(RtfDocument {
+        text: text.trim().to_string(),
+        info,
+        codepage,
+        encoding,
+    })
+}
+
+impl DocumentProcessor for RtfProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::RTF
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et convertir le RTF
+        let rtf = self.read_rtf_file(file_path)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&rtf, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.rtf")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::RTF,
+            file_size: file_metadata.len(),
+            created_at: rtf.info.created.or_else(|| file_metadata.created().ok().map(|t| t.into())),
+            modified_at: rtf.info.revised.or_else(|| file_metadata.modified().ok().map(|t| t.into())),
+            title: rtf.info.field("title"),
+            author: rtf.info.field("author"),
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Convertir le RTF depuis les bytes
+        let rtf = parse_rtf(content)?;
+
+        Ok(self.build_output(&rtf, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::RTF,
+            file_size: content.len() as u64,
+            created_at: rtf.info.created.or_else(|| Some(Utc::now())),
+            modified_at: rtf.info.revised.or_else(|| Some(Utc::now())),
+            title: rtf.info.field("title"),
+            author: rtf.info.field("author"),
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for RtfProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = br#"{\rtf1\ansi\ansicpg1252\deff0
+{\fonttbl{\f0\fswiss Helvetica;}{\f1\froman Times;}}
+{\colortbl;\red255\green0\blue0;}
+{\info{\title Release Notes}{\author Jos\'e9 Dupont}{\creatim\yr2024\mo2\dy14\hr9\min30}{\nofwords12}}
+{\*\generator Riched20 10.0;}
+\pard\f0 Caf\'e9 menu\par
+Price: 5\'80\par
+{\*\unknowndest should not appear}
+\trowd\cellx1000\cellx2000 Tea\cell Coffee\cell\row
+Done\{ok\}}"#;
+
+    #[test]
+    fn test_rtf_processor_creation() {
+        let processor = RtfProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "RTF");
+    }
+
+    #[test]
+    fn test_parse_rtf() {
+        let rtf = parse_rtf(SAMPLE).unwrap();
+        assert_eq!(rtf.text, "Café menu\nPrice: 5€\nTea | Coffee\nDone{ok}");
+        assert_eq!(rtf.codepage, 1252);
+        assert_eq!(rtf.info.field("title").as_deref(), Some("Release Notes"));
+        assert_eq!(rtf.info.field("author").as_deref(), Some("José Dupont"));
+        assert_eq!(rtf.info.created.unwrap().to_rfc3339(), "2024-02-14T09:30:00+00:00");
+        assert_eq!(rtf.info.statistics["nofwords"], 12);
+    }
+
+    #[test]
+    fn test_declared_codepage() {
+        // 0xC0 vaut « А » en windows-1251
+        let rtf = parse_rtf(br"{\rtf1\ansi\ansicpg1251 \'c0\'e1\'e2}").unwrap();
+        assert_eq!(rtf.text, "Абв");
+        assert_eq!(rtf.encoding.name(), "windows-1251");
+    }
+
+    #[test]
+    fn test_process_content() {
+        let processor = RtfProcessor::new();
+        let params = ProcessingParams::default();
+
+        let output = processor.process_content(SAMPLE, "notes.rtf", &params).unwrap();
+        assert_eq!(output.document_metadata.document_type.to_string(), "RTF");
+        assert_eq!(output.document_metadata.title.as_deref(), Some("Release Notes"));
+        assert!(!output.chunks.is_empty());
+        assert!(!output.chunks[0].content.contains("Helvetica"));
+
+        assert!(processor.process_content(b"plain text", "fake.rtf", &params).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Sélecteurs JSONPath (`$.articles[*].abstract`) et JSON Pointer (`/articles/0/abstract`).
+//!
+//! Seul le sous-ensemble utile à la sélection de champs est pris en charge : clés (`.name`,
+//! `['name']`), indices (`[0]`, `[-1]` depuis la fin) et jokers (`[*]`, `.*`). Chaque valeur
+//! désignée est renvoyée avec son JSON Pointer (RFC 6901).
+
+use crate::error::DocLoaderError;
+
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    /// Clé d'objet ; désigne aussi un élément de tableau si elle est numérique (JSON Pointer)
+    Key(String),
+    Index(i64),
+    Wildcard,
+}
+
+/// Chemin vers une ou plusieurs valeurs d'un document JSON
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Selector {
+    segments: Vec<Segment>,
+}
+
+impl Selector {
+    /// JSONPath s'il commence par `$`, JSON Pointer s'il est vide ou commence par `/`
+    pub(crate) fn parse(expression: &str) -> Result<Self, DocLoaderError> {
+        let trimmed = expression.trim();
+        let segments = if trimmed.is_empty() || trimmed.starts_with('/') {
+            Ok(parse_pointer(trimmed))
+        } else if let Some(path) = trimmed.strip_prefix('$') {
+            parse_path(path)
+        } else {
+            Err("expected a JSONPath ($.field) or a JSON Pointer (/field)".to_string())
+        };
+        segments
+            .map(|segments| Self { segments })
+            .map_err(|reason| DocLoaderError::Processing(format!("Invalid JSON selector '{}': {}", expression, reason)))
+    }
+
+    /// Valeurs désignées, dans l'ordre du document, avec leur JSON Pointer relatif à `value`
+    pub(crate) fn select<'a>(&self, value: &'a Value) -> Vec<(String, &'a Value)> {
+        let mut matches = Vec::new();
+        collect(&self.segments, value, String::new(), &mut matches);
+        matches
+    }
+
+    /// Valeur désignée (un tableau des correspondances si le sélecteur contient un joker)
+    /// et les pointeurs de ses sources ; `None` si rien ne correspond
+    pub(crate) fn select_value(&self, value: &Value) -> Option<(Value, Vec<String>)> {
+        let matches = self.select(value);
+        let (pointers, values): (Vec<String>, Vec<Value>) = matches.into_iter()
+            .map(|(pointer, value)| (pointer, value.clone()))
+            .unzip();
+        match (self.is_single(), values.len()) {
+            (_, 0) => None,
+            (true, _) => values.into_iter().next().map(|value| (value, pointers)),
+            (false, _) => Some((Value::Array(values), pointers)),
+        }
+    }
+
+    /// Vrai si le sélecteur désigne au plus une valeur (aucun joker)
+    pub(crate) fn is_single(&self) -> bool {
+        !self.segments.contains(&Segment::Wildcard)
+    }
+
+    /// Première clé du chemin (`author` pour `$.author.name`)
+    pub(crate) fn first_key(&self) -> Option<&str> {
+        match self.segments.first() {
+            Some(Segment::Key(key)) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Préfixe jusqu'au dernier joker inclus (`$.articles[*]` pour `$.articles[*].abstract`)
+    pub(crate) fn wildcard_prefix(&self) -> Option<Self> {
+        let end = self.segments.iter().rposition(|segment| *segment == Segment::Wildcard)?;
+        Some(Self { segments: self.segments[..=end].to_vec() })
+    }
+
+    /// Reste du chemin après `prefix`, si le sélecteur commence par lui
+    pub(crate) fn strip_prefix(&self, prefix: &Selector) -> Option<Self> {
+        self.segments.strip_prefix(prefix.segments.as_slice())
+            .map(|segments| Self { segments: segments.to_vec() })
+    }
+
+    /// Nom pointé du chemin (`author.name`, `tags[0]`, `authors[*].name`), utilisé comme nom de champ
+    pub(crate) fn name(&self) -> String {
+        let mut name = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Key(key) if name.is_empty() => name.push_str(key),
+                Segment::Key(key) => {
+                    name.push('.');
+                    name.push_str(key);
+                },
+                Segment::Index(index) => name.push_str(&format!("[{}]", index)),
+                Segment::Wildcard => name.push_str("[*]"),
+            }
+        }
+        if name.is_empty() { "value".to_string() } else { name }
+    }
+}
+
+fn parse_pointer(pointer: &str) -> Vec<Segment> {
+    pointer.split('/')
+        .skip(1)
+        .map(|token| Segment::Key(token.replace("~1", "/").replace("~0", "~")))
+        .collect()
+}
+
+fn parse_path(mut rest: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            if after.starts_with('.') {
+                return Err("recursive descent (..) is not supported".to_string());
+            }
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            segments.push(match &after[..end] {
+                "" => return Err("empty field name".to_string()),
+                "*" => Segment::Wildcard,
+                name => Segment::Key(name.to_string()),
+            });
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            if let Some(quote) = after.chars().next().filter(|c| *c == '\'' || *c == '"') {
+                let end = after[1..].find(quote).ok_or("unclosed quote")? + 1;
+                segments.push(Segment::Key(after[1..end].to_string()));
+                rest = after[end + 1..].strip_prefix(']').ok_or("expected ']' after a quoted name")?;
+            } else {
+                let end = after.find(']').ok_or("unclosed bracket")?;
+                let inner = after[..end].trim();
+                segments.push(match inner {
+                    "*" => Segment::Wildcard,
+                    index => Segment::Index(index.parse().map_err(|_| format!("invalid index '{}'", index))?),
+                });
+                rest = &after[end + 1..];
+            }
+        } else {
+            return Err(format!("unexpected '{}'", rest));
+        }
+    }
+    Ok(segments)
+}
+
+/// Pointeur d'une clé enfant, avec l'échappement `~0` / `~1` de la RFC 6901
+fn child_pointer(pointer: &str, key: &str) -> String {
+    format!("{}/{}", pointer, key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn collect<'a>(segments: &[Segment], value: &'a Value, pointer: String, matches: &mut Vec<(String, &'a Value)>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        matches.push((pointer, value));
+        return;
+    };
+    match (segment, value) {
+        (Segment::Key(key), Value::Object(object)) => {
+            if let Some(child) = object.get(key) {
+                collect(rest, child, child_pointer(&pointer, key), matches);
+            }
+        },
+        (Segment::Key(key), Value::Array(items)) => {
+            if let Some(child) = key.parse::<usize>().ok().and_then(|index| items.get(index)) {
+                collect(rest, child, child_pointer(&pointer, key), matches);
+            }
+        },
+        (Segment::Index(index), Value::Array(items)) => {
+            let position = if *index < 0 { items.len() as i64 + index } else { *index };
+            if let Some((position, child)) = usize::try_from(position).ok().and_then(|p| items.get(p).map(|child| (p, child))) {
+                collect(rest, child, format!("{}/{}", pointer, position), matches);
+            }
+        },
+        (Segment::Wildcard, Value::Object(object)) => {
+            for (key, child) in object {
+                collect(rest, child, child_pointer(&pointer, key), matches);
+            }
+        },
+        (Segment::Wildcard, Value::Array(items)) => {
+            for (index, child) in items.iter().enumerate() {
+                collect(rest, child, format!("{}/{}", pointer, index), matches);
+            }
+        },
+        _ => {},
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn library() -> Value {
+        json!({
+            "articles": [
+                { "doi": "10.1000/a", "abstract": "First", "authors": [{ "name": "Ada" }, { "name": "Alan" }] },
+                { "doi": "10.1000/b", "abstract": "Second", "authors": [] }
+            ],
+            "a/b": { "~tilde": 1 }
+        })
+    }
+
+    #[test]
+    fn test_json_path() {
+        let document = library();
+        let matches = Selector::parse("$.articles[*].abstract").unwrap().select(&document);
+        assert_eq!(matches, vec![
+            ("/articles/0/abstract".to_string(), &json!("First")),
+            ("/articles/1/abstract".to_string(), &json!("Second")),
+        ]);
+
+        let last = Selector::parse("$['articles'][-1].doi").unwrap();
+        assert_eq!(last.select(&document), vec![("/articles/1/doi".to_string(), &json!("10.1000/b"))]);
+        assert_eq!(Selector::parse("$.articles[5].doi").unwrap().select(&document), vec![]);
+        assert_eq!(Selector::parse("$").unwrap().select(&document).len(), 1);
+
+        // Le joker parcourt les clés dans l'ordre du document
+        let pointers: Vec<String> = Selector::parse("$.articles[0].*").unwrap()
+            .select(&document).into_iter().map(|(pointer, _)| pointer).collect();
+        assert_eq!(pointers, ["/articles/0/doi", "/articles/0/abstract", "/articles/0/authors"]);
+    }
+
+    #[test]
+    fn test_json_pointer() {
+        let document = library();
+        let selector = Selector::parse("/articles/0/authors/1/name").unwrap();
+        assert_eq!(selector.select(&document), vec![("/articles/0/authors/1/name".to_string(), &json!("Alan"))]);
+
+        // Les caractères `/` et `~` des clés sont échappés dans les deux sens
+        let escaped = Selector::parse("/a~1b/~0tilde").unwrap();
+        assert_eq!(escaped.select(&document), vec![("/a~1b/~0tilde".to_string(), &json!(1))]);
+        assert_eq!(Selector::parse("$['a/b'].*").unwrap().select(&document)[0].0, "/a~1b/~0tilde");
+    }
+
+    #[test]
+    fn test_select_value() {
+        let document = library();
+        let names = Selector::parse("$.articles[0].authors[*].name").unwrap();
+        assert_eq!(names.name(), "articles[0].authors[*].name");
+        assert_eq!(names.select_value(&document).unwrap().0, json!(["Ada", "Alan"]));
+        assert_eq!(Selector::parse("$.articles[1].authors[*]").unwrap().select_value(&document), None);
+
+        let abstracts = Selector::parse("$.articles[*].abstract").unwrap();
+        let records = abstracts.wildcard_prefix().unwrap();
+        assert_eq!(records, Selector::parse("$.articles.*").unwrap());
+        let relative = abstracts.strip_prefix(&records).unwrap();
+        assert_eq!(relative.first_key(), Some("abstract"));
+        assert!(relative.is_single());
+    }
+
+    #[test]
+    fn test_invalid_selectors() {
+        for expression in ["articles.doi", "$..doi", "$.articles[", "$.articles[x]", "$['doi", "$.a..b"] {
+            let error = Selector::parse(expression).unwrap_err();
+            assert!(error.to_string().contains("Invalid JSON selector"), "{}", expression);
+        }
+    }
+}
@@ -0,0 +1,524 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::utils::{chunk_text, clean_text, decode_html_entities, extract_text_metadata, normalize_line_breaks};
+
+use std::path::Path;
+use std::fs;
+use std::sync::LazyLock;
+use chrono::Utc;
+use regex::Regex;
+use serde_json::{json, Value};
+
+/// Format de sous-titres
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubtitleFormat {
+    Srt,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    fn name(&self) -> &'static str {
+        match self {
+            SubtitleFormat::Srt => "srt",
+            SubtitleFormat::Vtt => "vtt",
+        }
+    }
+}
+
+/// Réplique horodatée
+#[derive(Debug, Clone)]
+pub struct Cue {
+    /// Identifiant optionnel (numéro SRT, identifiant de réplique VTT)
+    pub identifier: Option<String>,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    /// Locuteurs des balises VTT `<v Nom>`, dans l'ordre d'apparition
+    pub speakers: Vec<String>,
+    pub text: String,
+    /// Ligne (à partir de 1) de l'horodatage dans le fichier source
+    pub line: usize,
+}
+
+/// Fichier de sous-titres analysé
+#[derive(Debug, Clone, Default)]
+pub struct Subtitles {
+    pub cues: Vec<Cue>,
+    /// Texte suivant `WEBVTT` dans l'en-tête
+    pub title: Option<String>,
+    /// Blocs ignorés car sans horodatage valide
+    pub skipped_blocks: usize,
+}
+
+static VOICE_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<v(?:\.[^\s>]*)?\s+([^>]+)>").unwrap());
+static MARKUP_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>|\{\\[^}]*\}").unwrap());
+static TIMESTAMP: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?:(\d+):)?(\d{1,2}):(\d{2})[,.](\d{1,3})$").unwrap());
+
+/// Convertit un horodatage `HH:MM:SS,mmm` (SRT) ou `[HH:]MM:SS.mmm` (VTT) en millisecondes
+fn parse_timestamp(timestamp: &str) -> Option<u64> {
+    let caps = TIMESTAMP.captures(timestamp.trim())?;
+    let hours: u64 = caps.get(1).map_or(Ok(0), |h| h.as_str().parse()).ok()?;
+    let minutes: u64 = caps[2].parse().ok()?;
+    let seconds: u64 = caps[3].parse().ok()?;
+    // `,5` vaut 500 ms
+    let fraction = &caps[4];
+    let millis: u64 = format!("{:0<3}", fraction).parse().ok()?;
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+/// Formate des millisecondes en `HH:MM:SS.mmm`
+pub fn format_timestamp(ms: u64) -> String {
+    format!("{:02}:{:02}:{:02}.{:03}", ms / 3_600_000, ms / 60_000 % 60, ms / 1000 % 60, ms % 1000)
+}
+
+/// Texte brut d'une réplique et locuteurs des balises `<v>`
+fn cue_text(lines: &[&str]) -> (String, Vec<String>) {
+    let mut speakers: Vec<String> = Vec::new();
+    let text = lines.iter().map(|line| {
+        for caps in VOICE_TAG.captures_iter(line) {
+            let speaker = caps[1].trim().to_string();
+            if !speakers.contains(&speaker) {
+                speakers.push(speaker);
+            }
+        }
+        decode_html_entities(MARKUP_TAG.replace_all(line, "").trim())
+    }).filter(|line| !line.is_empty()).collect::<Vec<_>>().join("\n");
+    (text, speakers)
+}
+
+/// Analyse un fichier SRT ou WebVTT en répliques
+fn parse_subtitles(content: &[u8], format: SubtitleFormat) -> Result<Subtitles, DocLoaderError> {
+    let text = normalize_line_breaks(&String::from_utf8_lossy(content));
+    let text = text.trim_start_matches('\u{feff}');
+    let lines: Vec<&str> = text.lines().collect();
+
+    let mut subtitles = Subtitles::default();
+    let mut i = 0;
+    let mut first_block = true;
+
+    while i < lines.len() {
+        if lines[i].trim().is_empty() {
+            i += 1;
+            continue;
+        }
+
+        // Bloc : lignes non vides consécutives
+        let block_start = i;
+        while i < lines.len() && !lines[i].trim().is_empty() {
+            i += 1;
+        }
+        let block = &lines[block_start..i];
+        let is_first = std::mem::replace(&mut first_block, false);
+
+        if format == SubtitleFormat::Vtt {
+            if is_first {
+                let Some(header) = block[0].strip_prefix("WEBVTT") else {
+                    return Err(DocLoaderError::InvalidFormat("Missing WEBVTT header".to_string()));
+                };
+                let title = header.trim_start_matches([' ', '\t', '-']).trim();
+                if !title.is_empty() {
+                    subtitles.title = Some(title.to_string());
+                }
+                continue;
+            }
+            if ["NOTE", "STYLE", "REGION"].iter().any(|keyword| block[0].starts_with(keyword)) {
+                continue;
+            }
+        }
+
+        let Some(timing_index) = block.iter().position(|line| line.contains("-->")) else {
+            subtitles.skipped_blocks += 1;
+            continue;
+        };
+
+        // `00:00:01.000 --> 00:00:04.000 align:start` : les réglages de position sont ignorés
+        let (start, end) = block[timing_index].split_once("-->").unwrap_or_default();
+        let end = end.split_whitespace().next().unwrap_or_default();
+        let (Some(start_ms), Some(end_ms)) = (parse_timestamp(start), parse_timestamp(end)) else {
+            subtitles.skipped_blocks += 1;
+            continue;
+        };
+
+        let identifier = block[..timing_index].join(" ");
+        let (text, speakers) = cue_text(&block[timing_index + 1..]);
+        if text.is_empty() {
+            continue;
+        }
+
+        subtitles.cues.push(Cue {
+            identifier: if identifier.trim().is_empty() { None } else { Some(identifier.trim().to_string()) },
+            start_ms,
+            end_ms,
+            speakers,
+            text,
+            line: block_start + timing_index + 1,
+        });
+    }
+
+    Ok(subtitles)
+}
+
+/// Construit la sortie universelle : répliques consécutives regroupées jusqu'à `max_chunk_size`
+fn build_output(
+    processor: &dyn DocumentProcessor,
+    processor_name: &str,
+    format: SubtitleFormat,
+    subtitles: &Subtitles,
+    document_metadata: impl FnOnce(Value) -> DocumentMetadata,
+    params: &ProcessingParams,
+    start_time: std::time::Instant,
+) -> UniversalOutput {
+    // Texte de chaque réplique, préfixé du locuteur lorsqu'il change
+    let mut previous_speakers: &[String] = &[];
+    let rendered: Vec<String> = subtitles.cues.iter().map(|cue| {
+        let text = if !cue.speakers.is_empty() && cue.speakers != previous_speakers {
+            format!("{}: {}", cue.speakers.join(", "), cue.text)
+        } else {
+            cue.text.clone()
+        };
+        if !cue.speakers.is_empty() {
+            previous_speakers = &cue.speakers;
+        }
+        text
+    }).collect();
+
+    // Regrouper les répliques sans dépasser la taille maximale (une réplique n'est jamais coupée entre deux groupes)
+    let mut groups: Vec<std::ops::Range<usize>> = Vec::new();
+    let mut group_start = 0;
+    let mut group_len = 0;
+    for (index, text) in rendered.iter().enumerate() {
+        if index > group_start && group_len + 1 + text.len() > params.max_chunk_size {
+            groups.push(group_start..index);
+            group_start = index;
+            group_len = 0;
+        }
+        group_len += if group_len == 0 { text.len() } else { text.len() + 1 };
+    }
+    if group_start < rendered.len() {
+        groups.push(group_start..rendered.len());
+    }
+
+    let mut chunks = Vec::new();
+    let mut full_text = String::new();
+
+    for group in groups {
+        let cues = &subtitles.cues[group.clone()];
+        let raw_text = rendered[group.clone()].join("\n");
+        let group_text = if params.text_cleaning { clean_text(&raw_text) } else { raw_text };
+
+        if !full_text.is_empty() {
+            full_text.push_str("\n\n");
+        }
+        let group_offset = full_text.len();
+        full_text.push_str(&group_text);
+
+        let mut speakers: Vec<&String> = Vec::new();
+        for speaker in cues.iter().flat_map(|cue| &cue.speakers) {
+            if !speakers.contains(&speaker) {
+                speakers.push(speaker);
+            }
+        }
+        let start_ms = cues[0].start_ms;
+        let end_ms = cues.iter().map(|cue| cue.end_ms).max().unwrap_or(start_ms);
+
+        // Seule une réplique isolée plus longue que la taille maximale est redécoupée
+        for chunk_text in chunk_text(&group_text, params.max_chunk_size, params.chunk_overlap) {
+            let text_meta = extract_text_metadata(&chunk_text);
+            let start_offset = group_offset + group_text.find(&chunk_text).unwrap_or(0);
+            let index = chunks.len();
+
+            chunks.push(DocumentChunk {
+                id: format!("{}_chunk_{}", format.name(), index),
+                content: chunk_text.clone(),
+                chunk_index: index,
+                position: ChunkPosition {
+                    page: None,
+                    line: Some(cues[0].line as u32),
+                    start_offset: Some(start_offset),
+                    end_offset: Some(start_offset + chunk_text.len()),
+                },
+                metadata: ChunkMetadata {
+                    size: chunk_text.len(),
+                    language: if params.language_detection { text_meta.detected_language } else { None },
+                    confidence: Some(1.0), // Répliques lues directement, horodatages exacts
+                    format_specific: Some(json!({
+                        "start_time": format_timestamp(start_ms),
+                        "end_time": format_timestamp(end_ms),
+                        "start_ms": start_ms,
+                        "end_ms": end_ms,
+                        "speakers": speakers,
+                        "cue_count": cues.len(),
+                        "first_cue": group.start,
+                        "last_cue": group.end - 1,
+                        "word_count": text_meta.word_count,
+                        "estimated_tokens": text_meta.estimated_tokens
+                    })),
+                },
+            });
+        }
+    }
+
+    let mut speakers: Vec<&String> = Vec::new();
+    for speaker in subtitles.cues.iter().flat_map(|cue| &cue.speakers) {
+        if !speakers.contains(&speaker) {
+            speakers.push(speaker);
+        }
+    }
+    let duration_ms = subtitles.cues.iter().map(|cue| cue.end_ms).max().unwrap_or(0);
+
+    let text_meta = extract_text_metadata(&full_text);
+    let format_metadata = json!({
+        "subtitle_metadata": {
+            "format": format.name(),
+            "cue_count": subtitles.cues.len(),
+            "duration_ms": duration_ms,
+            "duration": format_timestamp(duration_ms),
+            "speakers": speakers,
+            "skipped_blocks": subtitles.skipped_blocks
+        },
+        "total_words": text_meta.word_count,
+        "total_characters": text_meta.character_count,
+        "estimated_tokens": text_meta.estimated_tokens,
+        "detected_language": text_meta.detected_language
+    });
+
+    let processing_time = start_time.elapsed();
+    let processing_info = ProcessingInfo {
+        processor: processor_name.to_string(),
+        processor_version: processor.version().to_string(),
+        processed_at: Utc::now(),
+        processing_time_ms: processing_time.as_millis() as u64,
+        total_chunks: chunks.len(),
+        total_content_size: full_text.len(),
+        processing_params: params.clone(),
+        warnings: Vec::new(),
+    };
+
+    UniversalOutput {
+        document_metadata: document_metadata(format_metadata),
+        chunks,
+        processing_info,
+    }
+}
+
+pub struct SrtProcessor;
+
+impl SrtProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DocumentProcessor for SrtProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::SRT
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et analyser les répliques
+        let content = fs::read(file_path)?;
+        let subtitles = parse_subtitles(&content, SubtitleFormat::Srt)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(build_output(self, "SrtProcessor", SubtitleFormat::Srt, &subtitles, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.srt")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::SRT,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Analyser les répliques depuis les bytes
+        let subtitles = parse_subtitles(content, SubtitleFormat::Srt)?;
+
+        Ok(build_output(self, "SrtProcessor", SubtitleFormat::Srt, &subtitles, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::SRT,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for SrtProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct VttProcessor;
+
+impl VttProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl DocumentProcessor for VttProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::VTT
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et analyser les répliques
+        let content = fs::read(file_path)?;
+        let subtitles = parse_subtitles(&content, SubtitleFormat::Vtt)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(build_output(self, "VttProcessor", SubtitleFormat::Vtt, &subtitles, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.vtt")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::VTT,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: subtitles.title.clone(),
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Analyser les répliques depuis les bytes
+        let subtitles = parse_subtitles(content, SubtitleFormat::Vtt)?;
+
+        Ok(build_output(self, "VttProcessor", SubtitleFormat::Vtt, &subtitles, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::VTT,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: subtitles.title.clone(),
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for VttProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SRT: &str = "1\r\n00:00:01,000 --> 00:00:03,500\r\nHello <i>everyone</i>.\r\n\r\n2\r\n00:00:04,000 --> 00:00:06,000\r\nWelcome to the\r\nweekly meeting.\r\n\r\n3\r\nbroken timing\r\nignored\r\n";
+
+    const VTT: &str = "WEBVTT - Weekly sync
+
+NOTE recorded automatically
+
+intro
+00:01.000 --> 00:04.000 align:start
+<v Alice>Let's start with the roadmap.</v>
+
+00:04.500 --> 00:08.000
+<v Alice>Q3 is on track.
+
+00:01:08.000 --> 00:01:12.250
+<v.loud Bob>I have a question &amp; a concern.
+";
+
+    #[test]
+    fn test_subtitle_processor_creation() {
+        assert_eq!(SrtProcessor::new().supported_type().to_string(), "SRT");
+        assert_eq!(VttProcessor::new().supported_type().to_string(), "VTT");
+        assert_eq!(parse_timestamp("01:02:03,4"), Some(3_723_400));
+        assert_eq!(format_timestamp(3_723_400), "01:02:03.400");
+    }
+
+    #[test]
+    fn test_parse_srt() {
+        let subtitles = parse_subtitles(SRT.as_bytes(), SubtitleFormat::Srt).unwrap();
+
+        assert_eq!(subtitles.cues.len(), 2);
+        assert_eq!(subtitles.skipped_blocks, 1);
+        assert_eq!(subtitles.cues[0].text, "Hello everyone.");
+        assert_eq!((subtitles.cues[1].start_ms, subtitles.cues[1].end_ms), (4000, 6000));
+        assert_eq!(subtitles.cues[1].text, "Welcome to the\nweekly meeting.");
+        assert_eq!(subtitles.cues[1].line, 6);
+    }
+
+    #[test]
+    fn test_parse_vtt_speakers() {
+        let subtitles = parse_subtitles(VTT.as_bytes(), SubtitleFormat::Vtt).unwrap();
+
+        assert_eq!(subtitles.title.as_deref(), Some("Weekly sync"));
+        assert_eq!(subtitles.cues.len(), 3);
+        assert_eq!(subtitles.cues[0].identifier.as_deref(), Some("intro"));
+        assert_eq!(subtitles.cues[2].speakers, vec!["Bob"]);
+        assert_eq!(subtitles.cues[2].text, "I have a question & a concern.");
+        assert_eq!(subtitles.cues[2].start_ms, 68_000);
+
+        assert!(parse_subtitles(b"00:01.000 --> 00:02.000\nhi", SubtitleFormat::Vtt).is_err());
+    }
+
+    #[test]
+    fn test_cues_merged_up_to_chunk_size() {
+        let processor = VttProcessor::new();
+        let params = ProcessingParams::default().with_chunk_size(80);
+        let output = processor.process_content(VTT.as_bytes(), "sync.vtt", &params).unwrap();
+
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[0].content, "Alice: Let's start with the roadmap. Q3 is on track.");
+        let first = output.chunks[0].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(first["start_time"], "00:00:01.000");
+        assert_eq!(first["end_time"], "00:00:08.000");
+        assert_eq!(first["speakers"], json!(["Alice"]));
+
+        let second = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(second["start_ms"], 68_000);
+        assert_eq!(output.chunks[1].content, "Bob: I have a question & a concern.");
+        assert_eq!(output.document_metadata.format_metadata["subtitle_metadata"]["speakers"], json!(["Alice", "Bob"]));
+    }
+}
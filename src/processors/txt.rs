@@ -139,6 +139,7 @@ impl DocumentProcessor for TxtProcessor {
             total_chunks: chunks.len(),
             total_content_size: text.len(),
             processing_params: params.clone(),
+            warnings: Vec::new(),
         };
         
         Ok(UniversalOutput {
@@ -240,6 +241,7 @@ impl DocumentProcessor for TxtProcessor {
             total_chunks: chunks.len(),
             total_content_size: text.len(),
             processing_params: params.clone(),
+            warnings: Vec::new(),
         };
         
         Ok(UniversalOutput {
@@ -0,0 +1,535 @@
+use crate::core::{
+    UniversalOutput, DocumentType, ProcessingParams, DocumentMetadata,
+    DocumentChunk, ChunkPosition, ChunkMetadata, ProcessingInfo
+};
+use crate::error::DocLoaderError;
+use crate::processors::DocumentProcessor;
+use crate::utils::{chunk_text, clean_text, extract_text_metadata};
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::fs;
+use chrono::Utc;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::Deserialize;
+use serde_json::json;
+
+/// Plafond de `max_depth` : l'arbre est parcouru récursivement, une profondeur arbitraire
+/// épuiserait la pile
+const DEPTH_LIMIT: usize = 1024;
+
+/// Options du processeur XML, lues depuis `params.format_specific["xml"]`
+///
+/// ```json
+/// { "xml": { "record_elements": ["record"], "include_attributes": true } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct XmlOptions {
+    /// Éléments (nom local) dont chaque occurrence forme un enregistrement découpé séparément
+    pub record_elements: Vec<String>,
+    /// Émettre les attributs sous la forme `path@attr="value"`
+    pub include_attributes: bool,
+    /// Profondeur d'imbrication maximale acceptée, plafonnée à 1024
+    pub max_depth: usize,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        Self {
+            record_elements: Vec::new(),
+            include_attributes: true,
+            max_depth: 256,
+        }
+    }
+}
+
+impl XmlOptions {
+    pub fn from_params(params: &ProcessingParams) -> Result<Self, DocLoaderError> {
+        params.format_options("xml")
+    }
+}
+
+/// Élément XML (nom local, attributs, texte direct et enfants)
+#[derive(Debug, Clone, Default)]
+pub struct XmlElement {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub text: String,
+    pub children: Vec<XmlElement>,
+}
+
+impl XmlElement {
+    fn from_start(e: &BytesStart<'_>) -> Self {
+        Self {
+            name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+            attributes: e.attributes()
+                .flatten()
+                .map(|attr| (
+                    String::from_utf8_lossy(attr.key.as_ref()).into_owned(),
+                    attr.unescape_value()
+                        .map(|value| value.into_owned())
+                        .unwrap_or_else(|_| String::from_utf8_lossy(&attr.value).into_owned()),
+                ))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Chemins des enfants, indexés quand plusieurs frères portent le même nom
+    fn child_paths(&self, path: &str) -> Vec<String> {
+        let mut totals: HashMap<&str, usize> = HashMap::new();
+        for child in &self.children {
+            *totals.entry(child.name.as_str()).or_default() += 1;
+        }
+
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        self.children.iter()
+            .map(|child| {
+                let position = seen.entry(child.name.as_str()).or_default();
+                let child_path = if totals[child.name.as_str()] > 1 {
+                    format!("{}.{}[{}]", path, child.name, position)
+                } else {
+                    format!("{}.{}", path, child.name)
+                };
+                *position += 1;
+                child_path
+            })
+            .collect()
+    }
+}
+
+/// Statistiques collectées pendant l'analyse
+#[derive(Debug, Clone, Default)]
+pub struct XmlStats {
+    pub element_count: usize,
+    pub attribute_count: usize,
+    pub max_depth: usize,
+    /// Déclarations `xmlns` (préfixe -> URI)
+    pub namespaces: BTreeMap<String, String>,
+    /// Présence d'une DTD (jamais interprétée : les entités déclarées ne sont pas développées)
+    pub has_doctype: bool,
+    /// Références d'entités inconnues laissées telles quelles
+    pub unresolved_entities: usize,
+}
+
+pub struct XmlProcessor;
+
+impl XmlProcessor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Lit et parse un fichier XML
+    fn read_xml_file(&self, file_path: &Path, options: &XmlOptions) -> Result<(XmlElement, XmlStats), DocLoaderError> {
+        let content = fs::read(file_path)?;
+        self.parse_xml(&content, options)
+    }
+
+    /// Construit l'arbre des éléments.
+    ///
+    /// Seules les entités prédéfinies et les références numériques sont décodées :
+    /// les entités déclarées dans une DTD ne sont jamais développées, ce qui protège
+    /// contre les attaques par expansion d'entités (« billion laughs »).
+    fn parse_xml(&self, content: &[u8], options: &XmlOptions) -> Result<(XmlElement, XmlStats), DocLoaderError> {
+        let content = String::from_utf8_lossy(content);
+        let mut reader = Reader::from_str(content.trim_start_matches('\u{feff}'));
+
+        let mut stats = XmlStats::default();
+        let mut stack: Vec<XmlElement> = Vec::new();
+        let mut root: Option<XmlElement> = None;
+        let max_depth = options.max_depth.min(DEPTH_LIMIT);
+
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) => {
+                    if stack.len() >= max_depth {
+                        return Err(DocLoaderError::InvalidFormat(format!(
+                            "XML nesting exceeds the maximum depth of {}", max_depth
+                        )));
+                    }
+                    stack.push(self.open_element(&e, &mut stats));
+                    stats.max_depth = stats.max_depth.max(stack.len());
+                },
+                Event::Empty(e) => {
+                    let element = self.open_element(&e, &mut stats);
+                    stats.max_depth = stats.max_depth.max(stack.len() + 1);
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => root = root.or(Some(element)),
+                    }
+                },
+                Event::End(_) => {
+                    if let Some(element) = stack.pop() {
+                        match stack.last_mut() {
+                            Some(parent) => parent.children.push(element),
+                            None => root = root.or(Some(element)),
+                        }
+                    }
+                },
+                Event::Text(t) => {
+                    if let Some(element) = stack.last_mut() {
+                        let text = match t.unescape() {
+                            Ok(text) => text.into_owned(),
+                            Err(_) => {
+                                stats.unresolved_entities += 1;
+                                String::from_utf8_lossy(&t).into_owned()
+                            }
+                        };
+                        element.text.push_str(&text);
+                        element.text.push(' ');
+                    }
+                },
+                Event::CData(data) => {
+                    if let Some(element) = stack.last_mut() {
+                        element.text.push_str(&String::from_utf8_lossy(&data));
+                        element.text.push(' ');
+                    }
+                },
+                Event::DocType(_) => stats.has_doctype = true,
+                Event::Eof => break,
+                _ => {},
+            }
+        }
+
+        if !stack.is_empty() {
+            return Err(DocLoaderError::XmlParsing(format!("Unclosed element <{}>", stack[stack.len() - 1].name)));
+        }
+        let root = root.ok_or_else(|| DocLoaderError::InvalidFormat("XML document has no root element".to_string()))?;
+
+        Ok((root, stats))
+    }
+
+    fn open_element(&self, e: &BytesStart<'_>, stats: &mut XmlStats) -> XmlElement {
+        let mut element = XmlElement::from_start(e);
+        stats.element_count += 1;
+
+        element.attributes.retain(|(key, value)| {
+            if key == "xmlns" || key.starts_with("xmlns:") {
+                let prefix = key.strip_prefix("xmlns:").unwrap_or("");
+                stats.namespaces.insert(prefix.to_string(), value.clone());
+                false
+            } else {
+                true
+            }
+        });
+        stats.attribute_count += element.attributes.len();
+        element
+    }
+
+    /// Convertit un élément en lignes `path=value`, comme `json_to_text` pour le JSON
+    fn xml_to_text(&self, element: &XmlElement, path: &str, options: &XmlOptions, lines: &mut Vec<String>) {
+        if options.include_attributes {
+            for (key, value) in &element.attributes {
+                lines.push(format!("{}@{}=\"{}\"", path, key, value));
+            }
+        }
+
+        let text = element.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !text.is_empty() {
+            lines.push(format!("{}=\"{}\"", path, text));
+        }
+
+        for (child, child_path) in element.children.iter().zip(element.child_paths(path)) {
+            self.xml_to_text(child, &child_path, options, lines);
+        }
+    }
+
+    /// Recherche les enregistrements (les plus externes) et leur chemin
+    fn find_records<'a>(&self, element: &'a XmlElement, path: &str, options: &XmlOptions, records: &mut Vec<(String, &'a XmlElement)>) {
+        if options.record_elements.iter().any(|name| name == &element.name) {
+            records.push((path.to_string(), element));
+            return;
+        }
+        for (child, child_path) in element.children.iter().zip(element.child_paths(path)) {
+            self.find_records(child, &child_path, options, records);
+        }
+    }
+
+    /// Sections à découper : un enregistrement par section, ou le document entier
+    fn sections(&self, root: &XmlElement, options: &XmlOptions) -> Vec<(Option<String>, String)> {
+        let mut records = Vec::new();
+        if !options.record_elements.is_empty() {
+            self.find_records(root, &root.name, options, &mut records);
+            if records.is_empty() {
+                log::warn!("No record element {:?} found, processing the whole XML document", options.record_elements);
+            }
+        }
+
+        if records.is_empty() {
+            let mut lines = Vec::new();
+            self.xml_to_text(root, &root.name, options, &mut lines);
+            return vec![(None, lines.join("\n"))];
+        }
+
+        records.into_iter()
+            .map(|(record_path, record)| {
+                let mut lines = Vec::new();
+                self.xml_to_text(record, &record.name, options, &mut lines);
+                (Some(record_path), lines.join("\n"))
+            })
+            .collect()
+    }
+
+    /// Construit la sortie universelle à partir de l'arbre XML
+    fn build_output(
+        &self,
+        root: &XmlElement,
+        stats: &XmlStats,
+        options: &XmlOptions,
+        document_metadata: impl FnOnce(serde_json::Value) -> DocumentMetadata,
+        params: &ProcessingParams,
+        start_time: std::time::Instant,
+    ) -> UniversalOutput {
+        let sections = self.sections(root, options);
+        let record_count = sections.iter().filter(|(record_path, _)| record_path.is_some()).count();
+
+        let mut chunks = Vec::new();
+        let mut full_text = String::new();
+
+        for (record_index, (record_path, raw_text)) in sections.iter().enumerate() {
+            // Nettoyer le texte si demandé
+            let section_text = if params.text_cleaning {
+                clean_text(raw_text)
+            } else {
+                raw_text.clone()
+            };
+
+            if !full_text.is_empty() {
+                full_text.push_str("\n\n");
+            }
+            let section_offset = full_text.len();
+            full_text.push_str(&section_text);
+
+            // Un chunk ne chevauche jamais deux enregistrements
+            for chunk_text in chunk_text(&section_text, params.max_chunk_size, params.chunk_overlap) {
+                let text_meta = extract_text_metadata(&chunk_text);
+                let start_offset = section_offset + section_text.find(&chunk_text).unwrap_or(0);
+                let index = chunks.len();
+
+                let mut format_specific = json!({
+                    "word_count": text_meta.word_count,
+                    "estimated_tokens": text_meta.estimated_tokens,
+                    "root_element": root.name
+                });
+                if let Some(record_path) = record_path {
+                    format_specific["record_index"] = json!(record_index);
+                    format_specific["record_path"] = json!(record_path);
+                }
+
+                chunks.push(DocumentChunk {
+                    id: format!("xml_chunk_{}", index),
+                    content: chunk_text.clone(),
+                    chunk_index: index,
+                    position: ChunkPosition {
+                        page: None,
+                        line: None,
+                        start_offset: Some(start_offset),
+                        end_offset: Some(start_offset + chunk_text.len()),
+                    },
+                    metadata: ChunkMetadata {
+                        size: chunk_text.len(),
+                        language: if params.language_detection { text_meta.detected_language } else { None },
+                        confidence: Some(1.0), // Confiance maximale pour l'extraction XML
+                        format_specific: Some(format_specific),
+                    },
+                });
+            }
+        }
+
+        let text_meta = extract_text_metadata(&full_text);
+        let format_metadata = json!({
+            "xml_metadata": {
+                "root_element": root.name,
+                "element_count": stats.element_count,
+                "attribute_count": stats.attribute_count,
+                "max_depth": stats.max_depth,
+                "namespaces": stats.namespaces,
+                "has_doctype": stats.has_doctype,
+                "entities_expanded": false,
+                "unresolved_entities": stats.unresolved_entities,
+                "record_elements": options.record_elements,
+                "record_count": record_count
+            },
+            "total_words": text_meta.word_count,
+            "total_characters": text_meta.character_count,
+            "estimated_tokens": text_meta.estimated_tokens,
+            "detected_language": text_meta.detected_language
+        });
+
+        let processing_time = start_time.elapsed();
+        let processing_info = ProcessingInfo {
+            processor: "XmlProcessor".to_string(),
+            processor_version: self.version().to_string(),
+            processed_at: Utc::now(),
+            processing_time_ms: processing_time.as_millis() as u64,
+            total_chunks: chunks.len(),
+            total_content_size: full_text.len(),
+            processing_params: params.clone(),
+            warnings: Vec::new(),
+        };
+
+        UniversalOutput {
+            document_metadata: document_metadata(format_metadata),
+            chunks,
+            processing_info,
+        }
+    }
+}
+
+impl DocumentProcessor for XmlProcessor {
+    fn supported_type(&self) -> DocumentType {
+        DocumentType::XML
+    }
+
+    fn process_file(&self, file_path: &Path, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Vérifier que le fichier existe
+        if !file_path.exists() {
+            return Err(DocLoaderError::FileNotFound(
+                format!("File not found: {}", file_path.display())
+            ));
+        }
+
+        // Lire et parser le XML
+        let options = XmlOptions::from_params(params)?;
+        let (root, stats) = self.read_xml_file(file_path, &options)?;
+
+        // Métadonnées du fichier
+        let file_metadata = fs::metadata(file_path)?;
+
+        Ok(self.build_output(&root, &stats, &options, |format_metadata| DocumentMetadata {
+            filename: file_path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown.xml")
+                .to_string(),
+            filepath: file_path.to_string_lossy().to_string(),
+            document_type: DocumentType::XML,
+            file_size: file_metadata.len(),
+            created_at: file_metadata.created().ok().map(|t| t.into()),
+            modified_at: file_metadata.modified().ok().map(|t| t.into()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+
+    fn process_content(&self, content: &[u8], filename: &str, params: &ProcessingParams) -> Result<UniversalOutput, DocLoaderError> {
+        let start_time = std::time::Instant::now();
+
+        // Parser le XML depuis les bytes
+        let options = XmlOptions::from_params(params)?;
+        let (root, stats) = self.parse_xml(content, &options)?;
+
+        Ok(self.build_output(&root, &stats, &options, |format_metadata| DocumentMetadata {
+            filename: filename.to_string(),
+            filepath: format!("memory://{}", filename),
+            document_type: DocumentType::XML,
+            file_size: content.len() as u64,
+            created_at: Some(Utc::now()),
+            modified_at: Some(Utc::now()),
+            title: None,
+            author: None,
+            format_metadata,
+        }, params, start_time))
+    }
+}
+
+impl Default for XmlProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<export xmlns:dc="http://purl.org/dc/elements/1.1/" version="2">
+    <record id="1"><title>First &amp; best</title><tag>a</tag><tag>b</tag></record>
+    <record id="2"><title><![CDATA[Second <raw>]]></title></record>
+</export>"#;
+
+    #[test]
+    fn test_xml_processor_creation() {
+        let processor = XmlProcessor::new();
+        assert_eq!(processor.supported_type().to_string(), "XML");
+    }
+
+    #[test]
+    fn test_xml_to_text() {
+        let processor = XmlProcessor::new();
+        let options = XmlOptions::default();
+        let (root, stats) = processor.parse_xml(EXPORT.as_bytes(), &options).unwrap();
+
+        let mut lines = Vec::new();
+        processor.xml_to_text(&root, &root.name, &options, &mut lines);
+        assert_eq!(lines, vec![
+            "export@version=\"2\"",
+            "export.record[0]@id=\"1\"",
+            "export.record[0].title=\"First & best\"",
+            "export.record[0].tag[0]=\"a\"",
+            "export.record[0].tag[1]=\"b\"",
+            "export.record[1]@id=\"2\"",
+            "export.record[1].title=\"Second <raw>\"",
+        ]);
+        assert_eq!(stats.element_count, 7);
+        assert_eq!(stats.namespaces["dc"], "http://purl.org/dc/elements/1.1/");
+    }
+
+    #[test]
+    fn test_record_elements() {
+        let processor = XmlProcessor::new();
+        let params = ProcessingParams::default()
+            .with_text_cleaning(false)
+            .with_format_specific(json!({ "xml": { "record_elements": ["record"] } }));
+
+        let output = processor.process_content(EXPORT.as_bytes(), "export.xml", &params).unwrap();
+        assert_eq!(output.chunks.len(), 2);
+        assert_eq!(output.chunks[1].content, "record@id=\"2\"\nrecord.title=\"Second <raw>\"");
+
+        let chunk_meta = output.chunks[1].metadata.format_specific.as_ref().unwrap();
+        assert_eq!(chunk_meta["record_path"], "export.record[1]");
+        assert_eq!(output.document_metadata.format_metadata["xml_metadata"]["record_count"], 2);
+
+        // Une option mal typée est signalée au lieu d'être ignorée
+        let params = ProcessingParams::default().with_format_specific(json!({ "xml": { "record_elements": "record" } }));
+        let result = processor.process_content(EXPORT.as_bytes(), "export.xml", &params);
+        assert!(matches!(result, Err(DocLoaderError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_entities_are_not_expanded() {
+        let bomb = r#"<?xml version="1.0"?>
+<!DOCTYPE lolz [
+  <!ENTITY lol "lol">
+  <!ENTITY lol2 "&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;">
+]>
+<lolz>&lol2;</lolz>"#;
+
+        let processor = XmlProcessor::new();
+        let (root, stats) = processor.parse_xml(bomb.as_bytes(), &XmlOptions::default()).unwrap();
+        assert!(stats.has_doctype);
+        assert_eq!(stats.unresolved_entities, 1);
+        assert_eq!(root.text.trim(), "&lol2;");
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let nested = |depth: usize| format!("{}text{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        let processor = XmlProcessor::new();
+        let params = ProcessingParams::default().with_format_specific(json!({ "xml": { "max_depth": usize::MAX } }));
+
+        // Une `max_depth` démesurée reste plafonnée
+        let output = processor.process_content(nested(DEPTH_LIMIT).as_bytes(), "deep.xml", &params).unwrap();
+        assert_eq!(output.document_metadata.format_metadata["xml_metadata"]["max_depth"], DEPTH_LIMIT);
+        let error = processor.process_content(nested(DEPTH_LIMIT + 1).as_bytes(), "deep.xml", &params).unwrap_err();
+        assert!(error.to_string().contains("maximum depth of 1024"));
+
+        let params = ProcessingParams::default().with_format_specific(json!({ "xml": { "max_depth": 3 } }));
+        assert!(processor.process_content(nested(4).as_bytes(), "deep.xml", &params).is_err());
+    }
+}
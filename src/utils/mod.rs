@@ -1,6 +1,11 @@
 use regex::Regex;
 use unicode_segmentation::UnicodeSegmentation;
 
+use std::sync::LazyLock;
+
+static WHITESPACE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\s+").unwrap());
+static EMPTY_LINES: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\n\s*\n").unwrap());
+
 /// Nettoie le texte en supprimant les caractères indésirables
 pub fn clean_text(text: &str) -> String {
     let mut cleaned = text.to_string();
@@ -11,8 +16,7 @@ pub fn clean_text(text: &str) -> String {
         .collect();
     
     // Normaliser les espaces
-    let whitespace_regex = Regex::new(r"\s+").unwrap();
-    cleaned = whitespace_regex.replace_all(&cleaned, " ").to_string();
+    cleaned = WHITESPACE.replace_all(&cleaned, " ").to_string();
     
     // Supprimer les espaces en début et fin
     cleaned.trim().to_string()
@@ -101,8 +105,191 @@ pub fn normalize_line_breaks(text: &str) -> String {
 
 /// Supprime les lignes vides consécutives
 pub fn remove_empty_lines(text: &str) -> String {
-    let empty_lines_regex = Regex::new(r"\n\s*\n").unwrap();
-    empty_lines_regex.replace_all(text, "\n\n").to_string()
+    EMPTY_LINES.replace_all(text, "\n\n").to_string()
+}
+
+/// Éléments HTML dont le contenu n'est pas du texte lisible
+const HTML_SKIPPED_TAGS: &[&str] = &["script", "style", "head", "noscript", "template", "svg"];
+
+/// Éléments HTML de niveau bloc, rendus comme des retours à la ligne
+const HTML_BLOCK_TAGS: &[&str] = &[
+    "p", "div", "br", "hr", "li", "ul", "ol", "dl", "dt", "dd", "tr", "table", "thead", "tbody",
+    "h1", "h2", "h3", "h4", "h5", "h6", "section", "article", "aside", "header", "footer", "nav",
+    "blockquote", "pre", "figure", "figcaption", "address", "body", "html", "main",
+];
+
+/// Convertit du HTML (ou XHTML) en texte brut
+///
+/// Tolérant aux documents mal formés : les balises sont retirées, les blocs deviennent des
+/// retours à la ligne, les cellules de tableau sont séparées par ` | ` et les entités décodées.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        push_html_text(&mut text, &rest[..tag_start]);
+        rest = &rest[tag_start..];
+
+        // Commentaires, CDATA, doctype et instructions de traitement
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map(|end| &after[end + 3..]).unwrap_or("");
+            continue;
+        }
+        if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").unwrap_or(after.len());
+            push_html_text(&mut text, &after[..end]);
+            rest = after.get(end + 3..).unwrap_or("");
+            continue;
+        }
+
+        let tag_end = match rest.find('>') {
+            Some(end) => end,
+            None => break,
+        };
+        let tag = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        if tag.starts_with('!') || tag.starts_with('?') {
+            continue;
+        }
+
+        let closing = tag.starts_with('/');
+        let name: String = tag.trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let self_closing = tag.ends_with('/');
+
+        if !closing && !self_closing && HTML_SKIPPED_TAGS.contains(&name.as_str()) {
+            // Sauter jusqu'à la balise fermante correspondante
+            let closing_tag = format!("</{}", name);
+            rest = match find_ignore_ascii_case(rest, &closing_tag) {
+                Some(end) => rest[end..].find('>').map(|gt| &rest[end + gt + 1..]).unwrap_or(""),
+                None => "",
+            };
+            continue;
+        }
+
+        match name.as_str() {
+            "td" | "th" if !closing => {
+                let line_start = text.rfind('\n').map(|i| i + 1).unwrap_or(0);
+                if !text[line_start..].trim().is_empty() {
+                    text.push_str(" | ");
+                }
+            },
+            "li" if !closing => text.push_str("\n- "),
+            name if HTML_BLOCK_TAGS.contains(&name) => text.push('\n'),
+            _ => {},
+        }
+    }
+    push_html_text(&mut text, rest);
+
+    // Nettoyer chaque ligne et supprimer les lignes vides
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && *line != "-")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Position de `needle` (ASCII, commençant par un caractère ASCII) dans `haystack`, sans tenir
+/// compte de la casse et sans copier le texte
+fn find_ignore_ascii_case(haystack: &str, needle: &str) -> Option<usize> {
+    haystack.as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+/// Ajoute un nœud texte HTML en décodant les entités et en réduisant les espaces
+fn push_html_text(output: &mut String, raw: &str) {
+    let decoded = decode_html_entities(raw);
+    let mut last_was_space = output.ends_with(|c: char| c.is_whitespace()) || output.is_empty();
+    for c in decoded.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !last_was_space {
+                output.push(' ');
+                last_was_space = true;
+            }
+        } else {
+            output.push(if c == '\u{a0}' { ' ' } else { c });
+            last_was_space = false;
+        }
+    }
+}
+
+/// Décode les entités HTML nommées courantes et les références numériques
+pub fn decode_html_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let entity = rest[1..].find(';')
+            .filter(|end| *end <= 10)
+            .map(|end| &rest[1..end + 1]);
+        let replacement = entity.and_then(|entity| {
+            if let Some(number) = entity.strip_prefix('#') {
+                let code = match number.strip_prefix(['x', 'X']) {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => number.parse().ok(),
+                };
+                return code.and_then(char::from_u32);
+            }
+            Some(match entity {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => '\u{a0}',
+                "ndash" => '–',
+                "mdash" => '—',
+                "hellip" => '…',
+                "lsquo" => '‘',
+                "rsquo" => '’',
+                "ldquo" => '“',
+                "rdquo" => '”',
+                "laquo" => '«',
+                "raquo" => '»',
+                "copy" => '©',
+                "reg" => '®',
+                "trade" => '™',
+                "deg" => '°',
+                "euro" => '€',
+                "eacute" => 'é',
+                "egrave" => 'è',
+                "ecirc" => 'ê',
+                "agrave" => 'à',
+                "acirc" => 'â',
+                "ccedil" => 'ç',
+                "ocirc" => 'ô',
+                "ugrave" => 'ù',
+                "icirc" => 'î',
+                _ => return None,
+            })
+        });
+
+        match (entity, replacement) {
+            (Some(entity), Some(c)) => {
+                decoded.push(c);
+                rest = &rest[entity.len() + 2..];
+            },
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
 }
 
 /// Extrait les métadonnées basiques d'un texte
@@ -150,6 +337,20 @@ mod tests {
         assert_eq!(count_words(text), 6);
     }
 
+    #[test]
+    fn test_html_to_text() {
+        let html = "<html><head><title>Ignored</title><style>p { color: red; }</style></head>\
+            <body><h1>Chapter&nbsp;1</h1><p>Fish &amp; chips,<br/>served <em>hot</em>.</p>\
+            <table><tr><th>Item</th><th>Price</th></tr><tr><td>Tea</td><td>&#163;2</td></tr></table></body></html>";
+
+        let text = html_to_text(html);
+        assert_eq!(text, "Chapter 1\nFish & chips,\nserved hot.\nItem | Price\nTea | £2");
+
+        // Balises ignorées en majuscules, refermées avec une autre casse
+        let text = html_to_text("<P>Before</P><SCRIPT>var é = '<p>';</Script ><p>After</p>");
+        assert_eq!(text, "Before\nAfter");
+    }
+
     #[test]
     fn test_language_detection() {
         let french_text = "Bonjour le monde, ceci est un test";